@@ -3,6 +3,7 @@ mod unit_test;
 use std::fmt;
 
 use crate::note::Note;
+use crate::MusicError;
 
 /// Implements the flat symbol ('♭').
 #[derive(Debug, Clone, Copy)]
@@ -22,8 +23,20 @@ impl Flat {
     /// println!("{flat}");
     /// ```
     pub fn init(number: u8) -> Self {
-        assert!(number > 0);
-        Self { number }
+        Self::try_init(number).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates flat symbols concatenated a given number of times,
+    /// failing when the number of symbols is set to 0.
+    /// ```
+    /// use music::{Flat, MusicError};
+    /// assert_eq!(Flat::try_init(2).unwrap().number(), 2);
+    /// assert_eq!(Flat::try_init(0).unwrap_err(), MusicError::NoAccidental);
+    /// ```
+    pub fn try_init(number: u8) -> Result<Self, MusicError> {
+        if number == 0 {
+            return Err(MusicError::NoAccidental);
+        }
+        Ok(Self { number })
     }
     /// Gives the number of flats.
     /// ```
@@ -34,6 +47,15 @@ impl Flat {
     pub fn number(&self) -> usize {
         self.number as usize
     }
+    /// Gives the transposition (in half tones) produced by the flats.
+    /// ```
+    /// use music::Flat;
+    /// let flat = Flat::init(3);
+    /// assert_eq!(flat.semitones(), -3);
+    /// ```
+    pub fn semitones(&self) -> isize {
+        -(self.number() as isize)
+    }
     /// Converts flats to string.
     /// ```
     /// use music::Flat;
@@ -80,8 +102,20 @@ impl Sharp {
     /// println!("{sharp}");
     /// ```
     pub fn init(number: u8) -> Self {
-        assert!(number > 0);
-        Self { number }
+        Self::try_init(number).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates sharp symbols concatenated a given number of times,
+    /// failing when the number of symbols is set to 0.
+    /// ```
+    /// use music::{Sharp, MusicError};
+    /// assert_eq!(Sharp::try_init(2).unwrap().number(), 2);
+    /// assert_eq!(Sharp::try_init(0).unwrap_err(), MusicError::NoAccidental);
+    /// ```
+    pub fn try_init(number: u8) -> Result<Self, MusicError> {
+        if number == 0 {
+            return Err(MusicError::NoAccidental);
+        }
+        Ok(Self { number })
     }
     /// Gives the number of sharps.
     /// ```
@@ -92,6 +126,15 @@ impl Sharp {
     pub fn number(&self) -> usize {
         self.number as usize
     }
+    /// Gives the transposition (in half tones) produced by the sharps.
+    /// ```
+    /// use music::Sharp;
+    /// let sharp = Sharp::init(2);
+    /// assert_eq!(sharp.semitones(), 2);
+    /// ```
+    pub fn semitones(&self) -> isize {
+        self.number() as isize
+    }
     /// Converts sharps to string.
     /// ```
     /// use music::Sharp;
@@ -132,8 +175,20 @@ impl Natural {
     /// println!("{natural}");
     /// ```
     pub fn init(number: u8) -> Self {
-        assert!(number > 0);
-        Self { number }
+        Self::try_init(number).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates natural symbols concatenated a given number of times,
+    /// failing when the number of symbols is set to 0.
+    /// ```
+    /// use music::{Natural, MusicError};
+    /// assert_eq!(Natural::try_init(2).unwrap().number(), 2);
+    /// assert_eq!(Natural::try_init(0).unwrap_err(), MusicError::NoAccidental);
+    /// ```
+    pub fn try_init(number: u8) -> Result<Self, MusicError> {
+        if number == 0 {
+            return Err(MusicError::NoAccidental);
+        }
+        Ok(Self { number })
     }
     /// Gives the number of naturals.
     /// ```
@@ -144,6 +199,16 @@ impl Natural {
     pub fn number(&self) -> usize {
         self.number as usize
     }
+    /// Gives the transposition (in half tones) produced by the naturals,
+    /// that is 0 since naturals do not move sounds.
+    /// ```
+    /// use music::Natural;
+    /// let natural = Natural::init(2);
+    /// assert_eq!(natural.semitones(), 0);
+    /// ```
+    pub fn semitones(&self) -> isize {
+        0
+    }
     /// Converts naturals to string.
     /// ```
     /// use music::Natural;
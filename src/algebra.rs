@@ -1,10 +1,4 @@
-use crate::{Chord, Flat, Interval, Natural, Note, Sharp, Sound};
-
-use std::any::type_name;
-
-fn type_of<T>(_: &T) -> &'static str {
-    type_name::<T>()
-}
+use crate::{Chord, Flat, Interval, MusicError, Natural, Note, Sharp, Sound, Transpose};
 
 macro_rules! operation {
     ($($Accidental:ty)*) => ($(
@@ -32,27 +26,27 @@ macro_rules! operation {
                 note + self // commutative operation
             }
         }
+        impl $Accidental {
+            /// Adds the accidental to a `Sound`,
+            /// failing when the result is lower than the lowest C.
+            pub fn try_add_sound(&self, sound: &Sound) -> Result<Sound, MusicError> {
+                sound.try_transpose(self.semitones())
+            }
+            /// Adds the accidental to both sounds of an `Interval`,
+            /// failing when a sound is lower than the lowest C.
+            pub fn try_add_interval(&self, interval: &Interval) -> Result<Interval, MusicError> {
+                interval.try_transpose(self.semitones())
+            }
+            /// Adds the accidental to all the sounds of a `Chord`,
+            /// failing when a sound is lower than the lowest C.
+            pub fn try_add_chord(&self, chord: &Chord) -> Result<Chord, MusicError> {
+                chord.try_transpose(self.semitones())
+            }
+        }
         impl std::ops::Add<$Accidental> for Sound {
             type Output = Sound;
             fn add(self, accidental: $Accidental) -> Self::Output {
-                let expected_range = match type_of(&accidental) {
-                    "music::accidental::Sharp" => {
-                        self.range() + accidental.number()
-                    },
-                    "music::accidental::Flat" => {
-                        if self.range() < accidental.number() {
-                            panic!("Too much Flat to add.")
-                        } else {
-                            self.range() - accidental.number()
-                        }
-                    }
-                    "music::accidental::Natural" => self.range(),
-                    _ => panic!("Not implemented"),
-
-                };
-                let note = Note::from_usize(expected_range % 12);
-                let octave = 1 + (expected_range / 12);
-                Sound::init(note, octave)
+                accidental.try_add_sound(&self).unwrap_or_else(|error| panic!("{error}"))
             }
         }
         impl std::ops::Add<Sound> for $Accidental {
@@ -77,9 +71,7 @@ macro_rules! operation {
             type Output = Interval;
             fn add(self, accidental: $Accidental) -> Self::Output {
                 // Translating accidents to the sounds of the interval.
-                let expected_sound_one = self.first_sound() + accidental;
-                let expected_sound_two = self.second_sound() + accidental;
-                Interval::init(expected_sound_one, expected_sound_two)
+                accidental.try_add_interval(&self).unwrap_or_else(|error| panic!("{error}"))
             }
         }
         impl std::ops::Add<Interval> for $Accidental {
@@ -105,11 +97,7 @@ macro_rules! operation {
             type Output = Chord;
             fn add(self, accidental: $Accidental) -> Self::Output {
                 // Translating accidents to the sounds of the Chord.
-                let expected_sounds = self.sounds()
-                                        .iter()
-                                        .map(|sound| sound + &accidental)
-                                        .collect::<Vec<Sound>>();
-                Chord::from_vec(expected_sounds)
+                accidental.try_add_chord(&self).unwrap_or_else(|error| panic!("{error}"))
             }
         }
         impl std::ops::Add<Chord> for $Accidental {
@@ -122,11 +110,7 @@ macro_rules! operation {
             type Output = Chord;
             fn add(self, accidental: &$Accidental) -> Self::Output {
                 // Translating accidents to the sounds of the interval.
-                let expected_sounds = self.sounds()
-                                        .iter()
-                                        .map(|sound| sound + accidental)
-                                        .collect::<Vec<Sound>>();
-                Chord::from_vec(expected_sounds)
+                accidental.try_add_chord(self).unwrap_or_else(|error| panic!("{error}"))
             }
         }
         impl std::ops::Add<&Chord> for &$Accidental {
@@ -139,3 +123,76 @@ macro_rules! operation {
     )*);
 }
 operation! {Sharp Flat Natural}
+
+impl std::ops::Add<Interval> for Sound {
+    type Output = Sound;
+    /// Adds an interval to a sound, spelling the result from the letters of the interval.
+    fn add(self, interval: Interval) -> Self::Output {
+        interval
+            .try_add_sound(&self)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl std::ops::Add<&Interval> for &Sound {
+    type Output = Sound;
+    fn add(self, interval: &Interval) -> Self::Output {
+        *self + *interval
+    }
+}
+impl std::ops::Sub<Interval> for Sound {
+    type Output = Sound;
+    /// Subtracts an interval from a sound, spelling the result from the letters of the interval.
+    fn sub(self, interval: Interval) -> Self::Output {
+        interval
+            .try_sub_sound(&self)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl std::ops::Sub<&Interval> for &Sound {
+    type Output = Sound;
+    fn sub(self, interval: &Interval) -> Self::Output {
+        *self - *interval
+    }
+}
+impl std::ops::Sub<Sound> for Sound {
+    type Output = Interval;
+    /// Gives the interval from the right-hand sound to the left-hand one,
+    /// so that `b + (a - b)` is `a`.
+    fn sub(self, sound: Sound) -> Self::Output {
+        Interval::init(sound, self)
+    }
+}
+impl std::ops::Sub<&Sound> for &Sound {
+    type Output = Interval;
+    fn sub(self, sound: &Sound) -> Self::Output {
+        *self - *sound
+    }
+}
+impl std::ops::Add<Interval> for Interval {
+    type Output = Interval;
+    /// Composes two intervals, the second sound being moved by the right-hand interval.
+    fn add(self, interval: Interval) -> Self::Output {
+        self.try_add_interval(&interval)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl std::ops::Add<&Interval> for &Interval {
+    type Output = Interval;
+    fn add(self, interval: &Interval) -> Self::Output {
+        *self + *interval
+    }
+}
+impl std::ops::Sub<Interval> for Interval {
+    type Output = Interval;
+    /// Subtracts two intervals, the second sound being moved back by the right-hand interval.
+    fn sub(self, interval: Interval) -> Self::Output {
+        self.try_sub_interval(&interval)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl std::ops::Sub<&Interval> for &Interval {
+    type Output = Interval;
+    fn sub(self, interval: &Interval) -> Self::Output {
+        *self - *interval
+    }
+}
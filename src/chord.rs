@@ -1,6 +1,16 @@
+mod identification;
+mod quality;
+mod symbol;
 #[cfg(test)]
 mod unit_test;
-use crate::Sound;
+mod voicing;
+use std::str::FromStr;
+
+pub use identification::ChordCandidate;
+pub use quality::ChordQuality;
+pub use symbol::ChordSymbol;
+
+use crate::{MusicError, ParseError, ParseErrorKind, Sound, SpelledNote};
 
 /// Implementation of a chord (generalizing intervals).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -61,6 +71,36 @@ impl Chord {
     pub fn sounds(&self) -> &Vec<Sound> {
         &self.sounds
     }
+    /// Gives the spelled notes of the sounds in the chord.
+    /// ```
+    /// use music::{Chord, Letter, Note, Sound, SpelledNote};
+    /// let chord = Chord::from_vec(vec![Sound::init(Note::E, 3), Sound::init(Note::Gs, 3)]);
+    /// let expected_notes = vec![SpelledNote::init(Letter::E, 0), SpelledNote::init(Letter::G, 1)];
+    /// assert_eq!(chord.spelled_notes(), expected_notes);
+    /// ```
+    pub fn spelled_notes(&self) -> Vec<SpelledNote> {
+        self.sounds
+            .iter()
+            .map(|sound| sound.spelled_note())
+            .collect()
+    }
+    /// Tests whether or not two chords are made of sounds with the same spelling
+    /// and octave, contrary to `==` which only compares pitches.
+    /// ```
+    /// use music::{Chord, Note, Sound};
+    /// let chord = Chord::from_vec(vec![Sound::init(Note::E, 3), Sound::init(Note::Gs, 3)]);
+    /// let other = Chord::from_vec(vec![Sound::init(Note::E, 3), Sound::init(Note::Ab, 3)]);
+    /// assert_eq!(chord, other);
+    /// assert!(!chord.strict_eq(&other));
+    /// ```
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        (self.len() == other.len())
+            & self
+                .sounds
+                .iter()
+                .zip(other.sounds.iter())
+                .all(|(sound, other_sound)| sound.strict_eq(other_sound))
+    }
     /// Sorts the sounds of the chord from lower to higher pitch.
     /// ```
     /// use music::{Chord, Sound, Note};
@@ -103,7 +143,7 @@ impl Chord {
         self.sounds.push(sound);
     }
     /// Gives the interval length (in half tone) between the lowest and highest pitch
-    /// sound of the chord. It clones the sounds container to perform the computation.
+    /// sound of the chord.
     /// # Panics
     /// It panics when the chord container is empty.
     /// ```
@@ -115,12 +155,51 @@ impl Chord {
     /// assert_eq!(chord.range(), 8);
     /// ```
     pub fn range(&self) -> usize {
-        if !self.sounds.is_empty() {
-            let mut sounds = self.sounds.clone();
-            sounds.sort();
-            sounds[sounds.len() - 1].range() - sounds[0].range()
-        } else {
-            panic!("Chord is empty.");
+        self.try_range().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the interval length (in half tone) between the lowest and highest pitch
+    /// sound of the chord, failing when the chord container is empty.
+    /// ```
+    /// use music::{Chord, MusicError, Sound, Note};
+    /// let chord = Chord::from_vec(vec![Sound::init(Note::C, 1), Sound::init(Note::G, 1)]);
+    /// assert_eq!(chord.try_range(), Ok(7));
+    /// assert_eq!(Chord::init(3).try_range(), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_range(&self) -> Result<usize, MusicError> {
+        let lowest = self.sounds.iter().min().ok_or(MusicError::EmptyChord)?;
+        let highest = self.sounds.iter().max().ok_or(MusicError::EmptyChord)?;
+        Ok((highest.range() - lowest.range()) as usize)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ParseError;
+    /// Parses a whitespace separated list of sounds, e.g. "C4 E4 G4".
+    /// ```
+    /// use music::{Chord, Note, Sound};
+    /// let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let expected_sounds = vec![
+    ///     Sound::init(Note::C, 4),
+    ///     Sound::init(Note::E, 4),
+    ///     Sound::init(Note::G, 4),
+    /// ];
+    /// assert_eq!(chord, Chord::from_vec(expected_sounds));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chord = Self::init(0);
+        let mut position = 0;
+        for token in s.split_whitespace() {
+            let offset = position + s[position..].find(token).unwrap_or(0);
+            let start = s[..offset].chars().count();
+            let sound = token
+                .parse::<Sound>()
+                .map_err(|error| error.within(s, start))?;
+            chord.add_sound(sound);
+            position = offset + token.len();
+        }
+        if chord.is_empty() {
+            return Err(ParseError::init(s, 0, ParseErrorKind::Empty));
         }
+        Ok(chord)
     }
 }
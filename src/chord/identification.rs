@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::{Chord, ChordQuality, SpelledNote};
+
+/// Possible reading of a chord: a root, a quality and the note in the bass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordCandidate {
+    root: SpelledNote,
+    quality: ChordQuality,
+    bass: SpelledNote,
+    inversion: usize,
+    missing: Vec<usize>,
+}
+
+impl ChordCandidate {
+    /// Gives the root of the chord.
+    /// ```
+    /// use music::{Chord, Letter, SpelledNote};
+    /// let chord = "E3 G3 C4".parse::<Chord>().unwrap();
+    /// assert_eq!(chord.identify()[0].root(), SpelledNote::init(Letter::C, 0));
+    /// ```
+    pub fn root(&self) -> SpelledNote {
+        self.root
+    }
+    /// Gives the quality of the chord.
+    /// ```
+    /// use music::{Chord, ChordQuality};
+    /// let chord = "D4 F4 A4 C5".parse::<Chord>().unwrap();
+    /// assert_eq!(chord.identify()[0].quality(), ChordQuality::MinorSeventh);
+    /// ```
+    pub fn quality(&self) -> ChordQuality {
+        self.quality
+    }
+    /// Gives the note of the lowest sound of the chord.
+    /// ```
+    /// use music::{Chord, Letter, SpelledNote};
+    /// let chord = "E3 G3 C4".parse::<Chord>().unwrap();
+    /// assert_eq!(chord.identify()[0].bass(), SpelledNote::init(Letter::E, 0));
+    /// ```
+    pub fn bass(&self) -> SpelledNote {
+        self.bass
+    }
+    /// Gives the inversion of the chord: 0 when the root is in the bass,
+    /// 1 when it is the first tone above the root (usually the third), and so on.
+    /// ```
+    /// use music::Chord;
+    /// let inversion = |s: &str| s.parse::<Chord>().unwrap().identify()[0].inversion();
+    /// assert_eq!(inversion("C4 E4 G4"), 0);
+    /// assert_eq!(inversion("E3 G3 C4"), 1);
+    /// assert_eq!(inversion("G3 C4 E4"), 2);
+    /// assert_eq!(inversion("F3 G3 B3 D4"), 3);
+    /// ```
+    pub fn inversion(&self) -> usize {
+        self.inversion
+    }
+    /// Gives the interval numbers of the tones of the quality that the chord leaves out.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "C4 E4 B♭4".parse::<Chord>().unwrap();
+    /// assert_eq!(chord.identify()[0].missing(), &[5]);
+    /// ```
+    pub fn missing(&self) -> &[usize] {
+        &self.missing
+    }
+    /// Gives the chord symbol, with the bass after a slash when it is not the root.
+    /// ```
+    /// use music::Chord;
+    /// let symbol = |s: &str| s.parse::<Chord>().unwrap().identify()[0].symbol();
+    /// assert_eq!(symbol("C4 E4 G4"), "C");
+    /// assert_eq!(symbol("E3 G3 B♭3 C4"), "C7/E");
+    /// assert_eq!(symbol("B3 D4 F4 A4"), "Bm7♭5");
+    /// ```
+    pub fn symbol(&self) -> String {
+        if self.inversion == 0 {
+            format!("{}{}", self.root, self.quality.suffix())
+        } else {
+            format!("{}{}/{}", self.root, self.quality.suffix(), self.bass)
+        }
+    }
+}
+
+impl fmt::Display for ChordCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl Chord {
+    /// Gives the possible readings of the chord, from the most likely to the least likely.
+    ///
+    /// Doubled notes are ignored, as are the tones that chords commonly leave out
+    /// (see [`ChordQuality::is_optional`]). Candidates are ranked by number of missing tones,
+    /// then by how well the spelling of the notes fits the quality,
+    /// then with the root position first.
+    /// ```
+    /// use music::{Chord, ChordQuality};
+    /// let chord = "C3 G3 C4 E4 A4".parse::<Chord>().unwrap();
+    /// let symbols: Vec<String> = chord.identify().iter().map(|c| c.symbol()).collect();
+    /// assert_eq!(symbols, vec!["C6", "Am7/C"]);
+    /// let chord = "G2 F3 B3".parse::<Chord>().unwrap();
+    /// assert_eq!(chord.identify()[0].quality(), ChordQuality::DominantSeventh);
+    /// assert!("C4 D♭4 D4".parse::<Chord>().unwrap().identify().is_empty());
+    /// ```
+    pub fn identify(&self) -> Vec<ChordCandidate> {
+        let sorted = self.clone().to_sorted();
+        let Some(bass) = sorted.sounds().first().map(|sound| sound.spelled_note()) else {
+            return Vec::new();
+        };
+        let mut notes: Vec<SpelledNote> = Vec::new();
+        for sound in sorted.sounds() {
+            let note = sound.spelled_note();
+            if notes
+                .iter()
+                .all(|other| other.to_usize() != note.to_usize())
+            {
+                notes.push(note);
+            }
+        }
+        let mut ranked = Vec::new();
+        for root in notes.iter() {
+            let offset = |note: &SpelledNote| (note.semitones() - root.semitones()).rem_euclid(12);
+            for (index, quality) in ChordQuality::ALL.iter().enumerate() {
+                let tones = quality.tones();
+                let position = |note: &SpelledNote| {
+                    tones
+                        .iter()
+                        .position(|(_, semitones)| semitones % 12 == offset(note))
+                };
+                let mut mismatches = 0;
+                let mut is_match = true;
+                for note in notes.iter().filter(|note| *note != root) {
+                    match position(note) {
+                        Some(position) => {
+                            let number = tones[position].0;
+                            let letter = (root.letter().index() + number - 1) % 7;
+                            if note.letter().index() != letter {
+                                mismatches += 1;
+                            }
+                        }
+                        None => is_match = false,
+                    }
+                }
+                let missing: Vec<usize> = tones
+                    .iter()
+                    .filter(|(_, semitones)| {
+                        notes.iter().all(|note| offset(note) != semitones % 12)
+                    })
+                    .map(|(number, _)| *number)
+                    .collect();
+                if !is_match || missing.iter().any(|number| !quality.is_optional(*number)) {
+                    continue;
+                }
+                let inversion = position(&bass).map_or(0, |position| position + 1);
+                let candidate = ChordCandidate {
+                    root: *root,
+                    quality: *quality,
+                    bass,
+                    inversion,
+                    missing,
+                };
+                let rank = (candidate.missing.len(), mismatches, inversion != 0, index);
+                ranked.push((rank, candidate));
+            }
+        }
+        ranked.sort_by_key(|(rank, _)| *rank);
+        ranked.into_iter().map(|(_, candidate)| candidate).collect()
+    }
+}
@@ -0,0 +1,365 @@
+use std::fmt;
+
+/// Quality of a chord, given by the tones stacked above its root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    SuspendedSecond,
+    SuspendedFourth,
+    Power,
+    MajorFlatFifth,
+    Sixth,
+    MinorSixth,
+    SixNine,
+    DominantSeventh,
+    MajorSeventh,
+    MinorSeventh,
+    MinorMajorSeventh,
+    HalfDiminishedSeventh,
+    DiminishedSeventh,
+    AugmentedSeventh,
+    AugmentedMajorSeventh,
+    SeventhSuspendedFourth,
+    SeventhFlatFifth,
+    AddNinth,
+    MinorAddNinth,
+    AddEleventh,
+    DominantNinth,
+    MajorNinth,
+    MinorNinth,
+    DominantEleventh,
+    MinorEleventh,
+    MajorEleventh,
+    DominantThirteenth,
+    MajorThirteenth,
+    MinorThirteenth,
+    SeventhFlatNinth,
+    SeventhSharpNinth,
+    SeventhSharpEleventh,
+    SeventhFlatThirteenth,
+}
+
+/// Description of a chord quality: its name, its suffix in chord symbols,
+/// the other accepted suffixes, and its tones above the root
+/// as (interval number, half tones) pairs.
+struct Description {
+    name: &'static str,
+    suffix: &'static str,
+    aliases: &'static [&'static str],
+    tones: &'static [(usize, isize)],
+}
+
+impl ChordQuality {
+    /// All the chord qualities, from the most common to the least common.
+    pub const ALL: [ChordQuality; 37] = [
+        ChordQuality::Major,
+        ChordQuality::Minor,
+        ChordQuality::Diminished,
+        ChordQuality::Augmented,
+        ChordQuality::SuspendedSecond,
+        ChordQuality::SuspendedFourth,
+        ChordQuality::Power,
+        ChordQuality::MajorFlatFifth,
+        ChordQuality::Sixth,
+        ChordQuality::MinorSixth,
+        ChordQuality::SixNine,
+        ChordQuality::DominantSeventh,
+        ChordQuality::MajorSeventh,
+        ChordQuality::MinorSeventh,
+        ChordQuality::MinorMajorSeventh,
+        ChordQuality::HalfDiminishedSeventh,
+        ChordQuality::DiminishedSeventh,
+        ChordQuality::AugmentedSeventh,
+        ChordQuality::AugmentedMajorSeventh,
+        ChordQuality::SeventhSuspendedFourth,
+        ChordQuality::SeventhFlatFifth,
+        ChordQuality::AddNinth,
+        ChordQuality::MinorAddNinth,
+        ChordQuality::AddEleventh,
+        ChordQuality::DominantNinth,
+        ChordQuality::MajorNinth,
+        ChordQuality::MinorNinth,
+        ChordQuality::DominantEleventh,
+        ChordQuality::MinorEleventh,
+        ChordQuality::MajorEleventh,
+        ChordQuality::DominantThirteenth,
+        ChordQuality::MajorThirteenth,
+        ChordQuality::MinorThirteenth,
+        ChordQuality::SeventhFlatNinth,
+        ChordQuality::SeventhSharpNinth,
+        ChordQuality::SeventhSharpEleventh,
+        ChordQuality::SeventhFlatThirteenth,
+    ];
+
+    fn description(&self) -> Description {
+        let (name, suffix, aliases, tones): (_, _, &[&str], &[(usize, isize)]) = match self {
+            ChordQuality::Major => ("major", "", &["maj", "M"], &[(3, 4), (5, 7)]),
+            ChordQuality::Minor => ("minor", "m", &["min", "-"], &[(3, 3), (5, 7)]),
+            ChordQuality::Diminished => ("diminished", "dim", &["°", "o"], &[(3, 3), (5, 6)]),
+            ChordQuality::Augmented => ("augmented", "aug", &["+"], &[(3, 4), (5, 8)]),
+            ChordQuality::SuspendedSecond => ("suspended second", "sus2", &[], &[(2, 2), (5, 7)]),
+            ChordQuality::SuspendedFourth => {
+                ("suspended fourth", "sus4", &["sus"], &[(4, 5), (5, 7)])
+            }
+            ChordQuality::Power => ("power", "5", &[], &[(5, 7)]),
+            ChordQuality::MajorFlatFifth => (
+                "major flat fifth",
+                "(♭5)",
+                &["(b5)", "♭5", "b5"],
+                &[(3, 4), (5, 6)],
+            ),
+            ChordQuality::Sixth => ("sixth", "6", &["maj6", "M6"], &[(3, 4), (5, 7), (6, 9)]),
+            ChordQuality::MinorSixth => (
+                "minor sixth",
+                "m6",
+                &["min6", "-6"],
+                &[(3, 3), (5, 7), (6, 9)],
+            ),
+            ChordQuality::SixNine => (
+                "six nine",
+                "6/9",
+                &["69", "6add9"],
+                &[(3, 4), (5, 7), (6, 9), (9, 14)],
+            ),
+            ChordQuality::DominantSeventh => (
+                "dominant seventh",
+                "7",
+                &["dom7"],
+                &[(3, 4), (5, 7), (7, 10)],
+            ),
+            ChordQuality::MajorSeventh => (
+                "major seventh",
+                "maj7",
+                &["M7", "Δ7", "Δ"],
+                &[(3, 4), (5, 7), (7, 11)],
+            ),
+            ChordQuality::MinorSeventh => (
+                "minor seventh",
+                "m7",
+                &["min7", "-7"],
+                &[(3, 3), (5, 7), (7, 10)],
+            ),
+            ChordQuality::MinorMajorSeventh => (
+                "minor major seventh",
+                "m(maj7)",
+                &["mM7", "mmaj7", "-Δ7"],
+                &[(3, 3), (5, 7), (7, 11)],
+            ),
+            ChordQuality::HalfDiminishedSeventh => (
+                "half-diminished seventh",
+                "m7♭5",
+                &["m7b5", "ø", "ø7", "-7b5"],
+                &[(3, 3), (5, 6), (7, 10)],
+            ),
+            ChordQuality::DiminishedSeventh => (
+                "diminished seventh",
+                "dim7",
+                &["°7", "o7"],
+                &[(3, 3), (5, 6), (7, 9)],
+            ),
+            ChordQuality::AugmentedSeventh => (
+                "augmented seventh",
+                "aug7",
+                &["+7", "7#5", "7+5"],
+                &[(3, 4), (5, 8), (7, 10)],
+            ),
+            ChordQuality::AugmentedMajorSeventh => (
+                "augmented major seventh",
+                "maj7#5",
+                &["+M7", "augmaj7", "+maj7"],
+                &[(3, 4), (5, 8), (7, 11)],
+            ),
+            ChordQuality::SeventhSuspendedFourth => (
+                "seventh suspended fourth",
+                "7sus4",
+                &["7sus"],
+                &[(4, 5), (5, 7), (7, 10)],
+            ),
+            ChordQuality::SeventhFlatFifth => (
+                "seventh flat fifth",
+                "7♭5",
+                &["7b5"],
+                &[(3, 4), (5, 6), (7, 10)],
+            ),
+            ChordQuality::AddNinth => ("add ninth", "add9", &["add2"], &[(3, 4), (5, 7), (9, 14)]),
+            ChordQuality::MinorAddNinth => (
+                "minor add ninth",
+                "m(add9)",
+                &["madd9", "madd2"],
+                &[(3, 3), (5, 7), (9, 14)],
+            ),
+            ChordQuality::AddEleventh => (
+                "add eleventh",
+                "add11",
+                &["add4"],
+                &[(3, 4), (5, 7), (11, 17)],
+            ),
+            ChordQuality::DominantNinth => (
+                "dominant ninth",
+                "9",
+                &["dom9"],
+                &[(3, 4), (5, 7), (7, 10), (9, 14)],
+            ),
+            ChordQuality::MajorNinth => (
+                "major ninth",
+                "maj9",
+                &["M9", "Δ9"],
+                &[(3, 4), (5, 7), (7, 11), (9, 14)],
+            ),
+            ChordQuality::MinorNinth => (
+                "minor ninth",
+                "m9",
+                &["min9", "-9"],
+                &[(3, 3), (5, 7), (7, 10), (9, 14)],
+            ),
+            ChordQuality::DominantEleventh => (
+                "dominant eleventh",
+                "11",
+                &["dom11"],
+                &[(3, 4), (5, 7), (7, 10), (9, 14), (11, 17)],
+            ),
+            ChordQuality::MinorEleventh => (
+                "minor eleventh",
+                "m11",
+                &["min11", "-11"],
+                &[(3, 3), (5, 7), (7, 10), (9, 14), (11, 17)],
+            ),
+            ChordQuality::MajorEleventh => (
+                "major eleventh",
+                "maj11",
+                &["M11", "Δ11"],
+                &[(3, 4), (5, 7), (7, 11), (9, 14), (11, 17)],
+            ),
+            ChordQuality::DominantThirteenth => (
+                "dominant thirteenth",
+                "13",
+                &["dom13"],
+                &[(3, 4), (5, 7), (7, 10), (9, 14), (13, 21)],
+            ),
+            ChordQuality::MajorThirteenth => (
+                "major thirteenth",
+                "maj13",
+                &["M13", "Δ13"],
+                &[(3, 4), (5, 7), (7, 11), (9, 14), (13, 21)],
+            ),
+            ChordQuality::MinorThirteenth => (
+                "minor thirteenth",
+                "m13",
+                &["min13", "-13"],
+                &[(3, 3), (5, 7), (7, 10), (9, 14), (13, 21)],
+            ),
+            ChordQuality::SeventhFlatNinth => (
+                "seventh flat ninth",
+                "7♭9",
+                &["7b9"],
+                &[(3, 4), (5, 7), (7, 10), (9, 13)],
+            ),
+            ChordQuality::SeventhSharpNinth => (
+                "seventh sharp ninth",
+                "7#9",
+                &["7+9"],
+                &[(3, 4), (5, 7), (7, 10), (9, 15)],
+            ),
+            ChordQuality::SeventhSharpEleventh => (
+                "seventh sharp eleventh",
+                "7#11",
+                &["7+11"],
+                &[(3, 4), (5, 7), (7, 10), (11, 18)],
+            ),
+            ChordQuality::SeventhFlatThirteenth => (
+                "seventh flat thirteenth",
+                "7♭13",
+                &["7b13"],
+                &[(3, 4), (5, 7), (7, 10), (13, 20)],
+            ),
+        };
+        Description {
+            name,
+            suffix,
+            aliases,
+            tones,
+        }
+    }
+    /// Gives the name of the quality.
+    /// ```
+    /// use music::ChordQuality;
+    /// assert_eq!(ChordQuality::HalfDiminishedSeventh.name(), "half-diminished seventh");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.description().name
+    }
+    /// Gives the suffix written after the root in chord symbols, e.g. "m7" in "Am7".
+    /// ```
+    /// use music::ChordQuality;
+    /// assert_eq!(ChordQuality::Major.suffix(), "");
+    /// assert_eq!(ChordQuality::DominantSeventh.suffix(), "7");
+    /// ```
+    pub fn suffix(&self) -> &'static str {
+        self.description().suffix
+    }
+    /// Gives the other suffixes which are commonly used for the quality.
+    /// ```
+    /// use music::ChordQuality;
+    /// assert!(ChordQuality::Diminished.aliases().contains(&"°"));
+    /// ```
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.description().aliases
+    }
+    /// Gives the tones of the quality above the root,
+    /// as (interval number, half tones) pairs.
+    /// ```
+    /// use music::ChordQuality;
+    /// assert_eq!(ChordQuality::Minor.tones(), &[(3, 3), (5, 7)]);
+    /// ```
+    pub fn tones(&self) -> &'static [(usize, isize)] {
+        self.description().tones
+    }
+    /// Tests whether or not a tone (given by its interval number) may be left out
+    /// of a chord of this quality: the fifth of chords of four or more sounds,
+    /// the ninth of eleventh chords, and the ninth and eleventh of thirteenth chords.
+    /// ```
+    /// use music::ChordQuality;
+    /// assert!(ChordQuality::DominantSeventh.is_optional(5));
+    /// assert!(!ChordQuality::Major.is_optional(5));
+    /// assert!(ChordQuality::DominantThirteenth.is_optional(9));
+    /// assert!(!ChordQuality::DominantThirteenth.is_optional(7));
+    /// assert!(ChordQuality::MinorEleventh.is_optional(9));
+    /// ```
+    pub fn is_optional(&self, number: usize) -> bool {
+        let tones = self.tones();
+        let is_perfect_fifth = tones.contains(&(5, 7));
+        match number {
+            5 => is_perfect_fifth && tones.len() >= 3,
+            9 => tones
+                .iter()
+                .any(|(number, _)| *number == 11 || *number == 13),
+            11 => tones.iter().any(|(number, _)| *number == 13),
+            _ => false,
+        }
+    }
+    /// Finds the quality written with a suffix (or one of its aliases).
+    /// ```
+    /// use music::ChordQuality;
+    /// assert_eq!(ChordQuality::from_suffix("m7b5"), Some(ChordQuality::HalfDiminishedSeventh));
+    /// assert_eq!(ChordQuality::from_suffix(""), Some(ChordQuality::Major));
+    /// assert_eq!(ChordQuality::from_suffix("xyz"), None);
+    /// ```
+    pub fn from_suffix(suffix: &str) -> Option<ChordQuality> {
+        ChordQuality::ALL
+            .into_iter()
+            .find(|quality| quality.suffix() == suffix || quality.aliases().contains(&suffix))
+    }
+}
+
+impl fmt::Display for ChordQuality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{}", self.suffix())
+        }
+    }
+}
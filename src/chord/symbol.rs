@@ -0,0 +1,348 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::interval::MAJOR_SEMITONES;
+use crate::spelling::parse_prefix;
+use crate::{
+    Chord, ChordQuality, Interval, MusicError, ParseError, ParseErrorKind, Sound, SpelledNote,
+};
+
+/// Change written after the quality in a chord symbol, e.g. "♭9" in "C7♭9".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Modification {
+    /// Raises or lowers a tone, adding it when the quality does not contain it.
+    Alter(usize, isize),
+    /// Adds an unaltered tone.
+    Add(usize),
+    /// Leaves a tone out.
+    Omit(usize),
+    /// Replaces the third by the second or the fourth.
+    Suspend(usize),
+}
+
+impl fmt::Display for Modification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Modification::Alter(number, alteration) if *alteration < 0 => {
+                write!(f, "{}{number}", "♭".repeat(alteration.unsigned_abs()))
+            }
+            Modification::Alter(number, alteration) => {
+                write!(f, "{}{number}", "#".repeat(*alteration as usize))
+            }
+            Modification::Add(number) => write!(f, "add{number}"),
+            Modification::Omit(number) => write!(f, "no{number}"),
+            Modification::Suspend(number) => write!(f, "sus{number}"),
+        }
+    }
+}
+
+/// Chord written in jazz and pop notation, e.g. "Am7♭5", "C13♭9" or "D/F#".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordSymbol {
+    root: SpelledNote,
+    quality: ChordQuality,
+    modifications: Vec<Modification>,
+    bass: Option<SpelledNote>,
+}
+
+impl ChordSymbol {
+    /// Creates the symbol of a chord from its root and quality.
+    /// ```
+    /// use music::{ChordQuality, ChordSymbol, Letter, SpelledNote};
+    /// let symbol = ChordSymbol::init(SpelledNote::init(Letter::B, -1), ChordQuality::MinorSeventh);
+    /// assert_eq!(symbol.to_string(), "B♭m7");
+    /// ```
+    pub fn init(root: SpelledNote, quality: ChordQuality) -> Self {
+        Self {
+            root,
+            quality,
+            modifications: Vec::new(),
+            bass: None,
+        }
+    }
+    /// Gives the same chord with another note in the bass (a slash chord).
+    /// ```
+    /// use music::{ChordQuality, ChordSymbol, Letter, SpelledNote};
+    /// let symbol = ChordSymbol::init(SpelledNote::init(Letter::D, 0), ChordQuality::Major)
+    ///     .with_bass(SpelledNote::init(Letter::F, 1));
+    /// assert_eq!(symbol.to_string(), "D/F#");
+    /// ```
+    pub fn with_bass(mut self, bass: SpelledNote) -> Self {
+        self.bass = Some(bass);
+        self
+    }
+    /// Gives the root of the chord.
+    /// ```
+    /// use music::{ChordSymbol, Letter, SpelledNote};
+    /// let symbol = "F#m7/E".parse::<ChordSymbol>().unwrap();
+    /// assert_eq!(symbol.root(), SpelledNote::init(Letter::F, 1));
+    /// ```
+    pub fn root(&self) -> SpelledNote {
+        self.root
+    }
+    /// Gives the quality of the chord, before its alterations.
+    /// ```
+    /// use music::{ChordQuality, ChordSymbol};
+    /// let symbol = "C13b9".parse::<ChordSymbol>().unwrap();
+    /// assert_eq!(symbol.quality(), ChordQuality::DominantThirteenth);
+    /// ```
+    pub fn quality(&self) -> ChordQuality {
+        self.quality
+    }
+    /// Gives the note written after the slash, if any.
+    /// ```
+    /// use music::{ChordSymbol, Letter, SpelledNote};
+    /// let bass = |s: &str| s.parse::<ChordSymbol>().unwrap().bass();
+    /// assert_eq!(bass("G/B"), Some(SpelledNote::init(Letter::B, 0)));
+    /// assert_eq!(bass("C6/9"), None);
+    /// ```
+    pub fn bass(&self) -> Option<SpelledNote> {
+        self.bass
+    }
+    /// Gives the tones of the chord above the root, as (interval number, half tones) pairs
+    /// sorted from the lowest to the highest, once the alterations are applied.
+    /// ```
+    /// use music::ChordSymbol;
+    /// let tones = |s: &str| s.parse::<ChordSymbol>().unwrap().tones();
+    /// assert_eq!(tones("C7b9"), vec![(3, 4), (5, 7), (7, 10), (9, 13)]);
+    /// assert_eq!(tones("C9sus4"), vec![(4, 5), (5, 7), (7, 10), (9, 14)]);
+    /// assert_eq!(tones("Cmaj7no3#11"), vec![(5, 7), (7, 11), (11, 18)]);
+    /// ```
+    pub fn tones(&self) -> Vec<(usize, isize)> {
+        let mut tones = self.quality.tones().to_vec();
+        for modification in self.modifications.iter() {
+            match *modification {
+                Modification::Alter(number, alteration) => {
+                    let simple = (number - 1) % 7;
+                    match tones
+                        .iter_mut()
+                        .find(|(other, _)| (other - 1) % 7 == simple)
+                    {
+                        Some(tone) => tone.1 = natural_semitones(tone.0) + alteration,
+                        None => tones.push((number, natural_semitones(number) + alteration)),
+                    }
+                }
+                Modification::Add(number) => {
+                    if tones.iter().all(|(other, _)| *other != number) {
+                        tones.push((number, natural_semitones(number)));
+                    }
+                }
+                Modification::Omit(number) => tones.retain(|(other, _)| *other != number),
+                Modification::Suspend(number) => {
+                    tones.retain(|(other, _)| *other != 3);
+                    tones.push((number, natural_semitones(number)));
+                }
+            }
+        }
+        tones.sort_by_key(|(_, semitones)| *semitones);
+        tones
+    }
+    /// Builds the chord in close position with its root in an octave,
+    /// each tone being stacked above the root and the bass of a slash chord
+    /// being placed below the root.
+    /// ```
+    /// use music::{Chord, ChordSymbol, MusicError, Sound};
+    /// let symbol = "D/F#".parse::<ChordSymbol>().unwrap();
+    /// let expected = "F#3 D4 A4".parse::<Chord>().unwrap();
+    /// assert!(symbol.try_to_chord(4).unwrap().strict_eq(&expected));
+    /// assert_eq!(
+    ///     symbol.try_to_chord(Sound::MAX_OCTAVE + 1),
+    ///     Err(MusicError::InvalidOctave(Sound::MAX_OCTAVE + 1))
+    /// );
+    /// let symbol = "B13".parse::<ChordSymbol>().unwrap();
+    /// assert_eq!(symbol.try_to_chord(Sound::MAX_OCTAVE), Err(MusicError::AboveHighestSound));
+    /// ```
+    pub fn try_to_chord(&self, octave: isize) -> Result<Chord, MusicError> {
+        let root = Sound::try_init_spelled(self.root, octave)?;
+        let mut tones = self.tones();
+        let mut chord = Chord::init(tones.len() + 2);
+        if let Some(bass) = self
+            .bass
+            .filter(|bass| bass.to_usize() != self.root.to_usize())
+        {
+            let number = (bass.letter().index() + 7 - self.root.letter().index()) % 7 + 1;
+            let semitones = (bass.semitones() - self.root.semitones()).rem_euclid(12);
+            tones.retain(|(_, other)| other % 12 != semitones);
+            chord.add_sound(spell_tone(&root, number, semitones - 12)?);
+        }
+        chord.add_sound(root);
+        for (number, semitones) in tones {
+            chord.add_sound(spell_tone(&root, number, semitones)?);
+        }
+        Ok(chord)
+    }
+    /// Builds the chord in close position with its root in an octave
+    /// (see [`ChordSymbol::try_to_chord`]).
+    /// # Panics
+    /// It panics when a sound is outside the pitch space.
+    /// ```
+    /// use music::{Chord, ChordSymbol};
+    /// let chord = "Am7b5".parse::<ChordSymbol>().unwrap().to_chord(3);
+    /// assert!(chord.strict_eq(&"A3 C4 E♭4 G4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn to_chord(&self, octave: isize) -> Chord {
+        self.try_to_chord(octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl fmt::Display for ChordSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.root, self.quality.suffix())?;
+        for modification in self.modifications.iter() {
+            write!(f, "{modification}")?;
+        }
+        if let Some(bass) = self.bass {
+            write!(f, "/{bass}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ChordSymbol {
+    type Err = ParseError;
+    /// Parses a root, a quality (see [`ChordQuality::from_suffix`]),
+    /// alterations ("♭5", "#9", "b13", ...), added tones ("add9"), omitted tones ("no3"),
+    /// suspensions ("sus4") and an optional bass after a slash.
+    /// ```
+    /// use music::{ChordSymbol, ParseErrorKind};
+    /// let symbol = "Bbmaj9#11/D".parse::<ChordSymbol>().unwrap();
+    /// assert_eq!(symbol.to_string(), "B♭maj9#11/D");
+    /// let error = "Cmaj8".parse::<ChordSymbol>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::UnknownChordQuality);
+    /// assert_eq!(error.position(), 1);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (root, read) = parse_prefix(s)?;
+        let start = s
+            .char_indices()
+            .nth(read)
+            .map_or(s.len(), |(index, _)| index);
+        let mut end = s.len();
+        let mut bass = None;
+        if let Some(slash) = s[start..].rfind('/').map(|index| start + index) {
+            let is_note = s[slash + 1..]
+                .chars()
+                .next()
+                .is_some_and(|c| ('A'..='G').contains(&c));
+            if is_note {
+                let position = s[..slash + 1].chars().count();
+                let note = s[slash + 1..]
+                    .parse::<SpelledNote>()
+                    .map_err(|error| error.within(s, position))?;
+                bass = Some(note);
+                end = slash;
+            }
+        }
+        let suffix = &s[start..end];
+        let mut qualities: Vec<(usize, ChordQuality)> = ChordQuality::ALL
+            .iter()
+            .flat_map(|quality| {
+                std::iter::once(quality.suffix())
+                    .chain(quality.aliases().iter().copied())
+                    .filter(|name| suffix.starts_with(name))
+                    .map(move |name| (name.len(), *quality))
+            })
+            .collect();
+        // The longest quality is tried first, e.g. "m7♭5" before "m7" followed by "♭5".
+        qualities.sort_by_key(|(length, _)| std::cmp::Reverse(*length));
+        qualities
+            .into_iter()
+            .find_map(|(length, quality)| {
+                let modifications = parse_modifications(&suffix[length..])?;
+                Some(Self {
+                    root,
+                    quality,
+                    modifications,
+                    bass,
+                })
+            })
+            .ok_or_else(|| ParseError::init(s, read, ParseErrorKind::UnknownChordQuality))
+    }
+}
+
+impl Chord {
+    /// Builds a chord in close position from its symbol (see [`ChordSymbol`]),
+    /// with its root in an octave.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = Chord::try_from_symbol("C7#9", 4).unwrap();
+    /// assert!(chord.strict_eq(&"C4 E4 G4 B♭4 D#5".parse::<Chord>().unwrap()));
+    /// assert!(matches!(Chord::try_from_symbol("Cxyz", 4), Err(MusicError::Parse(_))));
+    /// ```
+    pub fn try_from_symbol(symbol: &str, octave: isize) -> Result<Chord, MusicError> {
+        symbol.parse::<ChordSymbol>()?.try_to_chord(octave)
+    }
+    /// Builds a chord in close position from its symbol (see [`ChordSymbol`]),
+    /// with its root in an octave.
+    /// # Panics
+    /// It panics when the symbol is invalid or a sound is outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = Chord::from_symbol("G7sus4", 3);
+    /// assert!(chord.strict_eq(&"G3 C4 D4 F4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn from_symbol(symbol: &str, octave: isize) -> Chord {
+        Self::try_from_symbol(symbol, octave).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Parses the modifications following the quality, possibly within parentheses
+/// and separated by commas.
+fn parse_modifications(input: &str) -> Option<Vec<Modification>> {
+    let separators = ['(', ')', ',', ' '];
+    let mut modifications = Vec::new();
+    let mut rest = input.trim_start_matches(separators);
+    while !rest.is_empty() {
+        let (modification, tail) = parse_modification(rest)?;
+        modifications.push(modification);
+        rest = tail.trim_start_matches(separators);
+    }
+    Some(modifications)
+}
+
+/// Parses the modification at the start of `input`, returning it with the rest of the input.
+fn parse_modification(input: &str) -> Option<(Modification, &str)> {
+    if let Some(rest) = input.strip_prefix("add") {
+        let (number, rest) = parse_number(rest)?;
+        Some((Modification::Add(number), rest))
+    } else if let Some(rest) = input.strip_prefix("no") {
+        let (number, rest) = parse_number(rest)?;
+        Some((Modification::Omit(number), rest))
+    } else if let Some(rest) = input.strip_prefix("sus") {
+        match parse_number(rest) {
+            Some((number @ (2 | 4), rest)) => Some((Modification::Suspend(number), rest)),
+            Some(_) => None,
+            None => Some((Modification::Suspend(4), rest)),
+        }
+    } else {
+        let mut chars = input.chars();
+        let alteration = match chars.next()? {
+            'b' | '♭' => -1,
+            '#' | '♯' | '+' => 1,
+            _ => return None,
+        };
+        let (number, rest) = parse_number(chars.as_str())?;
+        Some((Modification::Alter(number, alteration), rest))
+    }
+}
+
+/// Parses the number of a chord tone at the start of `input`,
+/// returning it with the rest of the input.
+fn parse_number(input: &str) -> Option<(usize, &str)> {
+    let digits = input.len() - input.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let number = input[..digits].parse::<usize>().ok()?;
+    matches!(number, 2..=7 | 9 | 11 | 13).then_some((number, &input[digits..]))
+}
+
+/// Gives the size (in half tones) of the major or perfect interval with a number.
+fn natural_semitones(number: usize) -> isize {
+    MAJOR_SEMITONES[(number - 1) % 7] + 12 * ((number - 1) / 7) as isize
+}
+
+/// Gives the sound at a number of half tones from the root, spelled with the letter
+/// given by the interval number.
+fn spell_tone(root: &Sound, number: usize, semitones: isize) -> Result<Sound, MusicError> {
+    let steps = number as isize - 1 - if semitones < 0 { 7 } else { 0 };
+    Interval::try_from_size(steps, semitones)?.try_add_sound(root)
+}
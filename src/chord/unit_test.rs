@@ -1,6 +1,9 @@
 #[cfg(test)]
+#[allow(clippy::op_ref)]
 mod tests {
-    use crate::{Chord, Note, Sharp, Sound};
+    use crate::{
+        Chord, ChordQuality, ChordSymbol, Letter, Note, ParseErrorKind, Sharp, Sound, SpelledNote,
+    };
 
     #[test]
     fn test_chord_algebra() {
@@ -14,4 +17,175 @@ mod tests {
         assert_eq!(chord + accidental, expected_chord);
         // assert_eq!(accidental + chord, expected_chord);
     }
+
+    fn symbols(chord: &str) -> Vec<String> {
+        chord
+            .parse::<Chord>()
+            .unwrap()
+            .identify()
+            .iter()
+            .map(|candidate| candidate.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_identify_qualities() {
+        let cases = [
+            ("C4 E4 G4", "C"),
+            ("A3 C4 E4", "Am"),
+            ("B3 D4 F4", "Bdim"),
+            ("C4 E4 G#4", "Caug"),
+            ("D4 G4 A4", "Dsus4"),
+            ("C4 G4", "C5"),
+            ("G3 B3 D4 F4", "G7"),
+            ("F3 A3 C4 E4", "Fmaj7"),
+            ("C4 E♭4 G4 B4", "Cm(maj7)"),
+            ("B3 D4 F4 A♭4", "Bdim7"),
+            ("G3 C4 D4 F4", "G7sus4"),
+            ("C4 E4 G4 D5", "Cadd9"),
+            ("C4 E4 G4 B♭4 D5", "C9"),
+            ("D3 F3 A3 C4 E4 G4", "Dm11"),
+            ("G2 F3 B3 E4", "G13"),
+            ("C4 E4 B♭4 D♭5", "C7♭9"),
+            ("C4 E4 B♭4 D#5", "C7#9"),
+            ("C4 E4 G4 A4 D5", "C6/9"),
+        ];
+        for (chord, symbol) in cases {
+            assert_eq!(symbols(chord)[0], symbol, "{chord}");
+        }
+    }
+
+    #[test]
+    fn test_identify_inversions_and_doubling() {
+        let chord = "G2 D3 G3 B3 D4 G4".parse::<Chord>().unwrap();
+        let candidates = chord.identify();
+        assert_eq!(candidates[0].quality(), ChordQuality::Major);
+        assert_eq!(candidates[0].root(), SpelledNote::init(Letter::G, 0));
+        assert!(candidates[0].missing().is_empty());
+        assert_eq!(symbols("B2 D3 G3"), vec!["G/B"]);
+        assert_eq!(symbols("D3 G3 B3")[0], "G/D");
+        assert_eq!(symbols("F3 G3 B3 D4")[0], "G7/F");
+        let candidates = "E3 G3 C4".parse::<Chord>().unwrap().identify();
+        assert_eq!(candidates[0].bass(), SpelledNote::init(Letter::E, 0));
+        assert_eq!(candidates[0].inversion(), 1);
+    }
+
+    #[test]
+    fn test_identify_ambiguous() {
+        assert_eq!(symbols("C4 E4 G4 A4"), vec!["C6", "Am7/C"]);
+        assert_eq!(symbols("A3 C4 E4 G4"), vec!["Am7", "C6/A"]);
+        assert_eq!(symbols("C4 D4 G4")[..2], ["Csus2", "Gsus4/C"]);
+        assert_eq!(symbols("C4 D4 G4")[2], "D7sus4/C");
+        assert_eq!(symbols("A3 C4 E♭4 G♭4")[0], "Adim7");
+        assert_eq!(symbols("A3 C4 E♭4 G♭4").len(), 4);
+        assert!(symbols("C4").is_empty());
+        assert!(Chord::init(0).identify().is_empty());
+    }
+
+    #[test]
+    fn test_from_symbol() {
+        let cases = [
+            ("Cmaj7", "C4 E4 G4 B4"),
+            ("CM7", "C4 E4 G4 B4"),
+            ("CΔ7", "C4 E4 G4 B4"),
+            ("Am7b5", "A4 C5 E♭5 G5"),
+            ("Aø", "A4 C5 E♭5 G5"),
+            ("Dm9", "D4 F4 A4 C5 E5"),
+            ("G7#11", "G4 B4 D5 F5 C#6"),
+            ("C13b9", "C4 E4 G4 B♭4 D♭5 A5"),
+            ("Dsus4", "D4 G4 A4"),
+            ("Ebadd9", "E♭4 G4 B♭4 F5"),
+            ("C6/9", "C4 E4 G4 A4 D5"),
+            ("D/F#", "F#3 D4 A4"),
+            ("C/D", "D3 C4 E4 G4"),
+            ("C7no3", "C4 G4 B♭4"),
+            ("C7(b5,b9)", "C4 E4 G♭4 B♭4 D♭5"),
+            ("F#dim7", "F#4 A4 C5 E♭5"),
+            ("Bbm", "B♭4 D♭5 F5"),
+        ];
+        for (symbol, expected) in cases {
+            let chord = Chord::from_symbol(symbol, 4);
+            let expected = expected.parse::<Chord>().unwrap();
+            assert!(chord.strict_eq(&expected), "{symbol}: {chord:?}");
+        }
+    }
+
+    #[test]
+    fn test_symbol_round_trip() {
+        for symbol in ["B♭maj7#11", "C13♭9", "G7sus2", "C7no3"] {
+            let parsed = symbol.parse::<ChordSymbol>().unwrap();
+            assert_eq!(parsed.to_string(), symbol);
+        }
+        for symbol in ["C", "Am7", "G7♭9", "F#m7♭5/E", "Dm(add9)", "E7sus4"] {
+            let parsed = symbol.parse::<ChordSymbol>().unwrap();
+            assert_eq!(parsed.to_string(), symbol);
+            let chord = parsed.to_chord(3);
+            let candidates = chord.identify();
+            assert!(candidates.iter().any(|c| c.symbol() == symbol), "{symbol}");
+        }
+    }
+
+    #[test]
+    fn test_symbol_errors() {
+        for symbol in ["Cfoo", "C7b10", "Cadd", "Cmaj7/"] {
+            let error = symbol.parse::<ChordSymbol>().unwrap_err();
+            assert_eq!(
+                error.kind(),
+                ParseErrorKind::UnknownChordQuality,
+                "{symbol}"
+            );
+        }
+        let error = "H7".parse::<ChordSymbol>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidLetter('H'));
+        let error = "C7/Ez".parse::<ChordSymbol>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidAccidental('z'));
+        assert_eq!(error.position(), 4);
+    }
+
+    #[test]
+    fn test_voicings_keep_chord() {
+        let chord = Chord::from_symbol("Cmaj7", 4);
+        let voicings = [
+            chord.inversion(1),
+            chord.inversion(2),
+            chord.inversion(3),
+            chord.rotate(-2),
+            chord.open_position(),
+            chord.drop2(),
+            chord.drop3(),
+            chord.drop2_and_4(),
+            chord.spread(),
+        ];
+        for voicing in voicings {
+            assert_eq!(voicing.len(), chord.len());
+            let candidate = &voicing.identify()[0];
+            assert_eq!(candidate.root(), SpelledNote::init(Letter::C, 0));
+            assert_eq!(candidate.quality(), ChordQuality::MajorSeventh);
+            let sorted = voicing.clone().to_sorted();
+            assert!(voicing.strict_eq(&sorted));
+        }
+        let bass = |chord: &Chord| chord.sounds()[0].spelled_note().letter();
+        assert_eq!(bass(&chord.inversion(1)), Letter::E);
+        assert_eq!(bass(&chord.drop2()), Letter::G);
+        assert_eq!(bass(&chord.drop3()), Letter::E);
+        assert_eq!(bass(&chord.drop2_and_4()), Letter::C);
+        assert!(chord.inversion(4).strict_eq(&chord.rotate(4)));
+    }
+
+    #[test]
+    fn test_close_position_and_range() {
+        let chord = "G2 D4 B5 G5".parse::<Chord>().unwrap();
+        let close = "G2 B2 D3 G3".parse::<Chord>().unwrap();
+        assert!(chord.close_position().strict_eq(&close));
+        assert_eq!(chord.close_position().range(), 12);
+        assert_eq!(chord.spread().range(), 24);
+        let (low, high) = (Sound::init(Note::E, 3), Sound::init(Note::E, 4));
+        let within = chord.within(low, high);
+        assert!(within
+            .sounds()
+            .iter()
+            .all(|sound| *sound >= low && *sound <= high));
+        let shell = Chord::from_symbol("G13", 2).shell();
+        assert!(shell.strict_eq(&"G2 B2 F3".parse::<Chord>().unwrap()));
+    }
 }
@@ -0,0 +1,395 @@
+use crate::{Chord, MusicError, Sound};
+
+impl Chord {
+    /// Gives the chord in close position (see [`Chord::try_close_position`])
+    /// with its `n`-th sound from the bottom in the bass, failing when a sound
+    /// would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError, Sound};
+    /// let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let first = "E4 G4 C5".parse::<Chord>().unwrap();
+    /// assert!(chord.try_inversion(1).unwrap().strict_eq(&first));
+    /// let second = "G4 C5 E5".parse::<Chord>().unwrap();
+    /// assert!(chord.try_inversion(2).unwrap().strict_eq(&second));
+    /// let highest = Sound::from_range(Sound::MAX_RANGE);
+    /// let chord = Chord::from_vec(vec![Sound::from_range(Sound::MAX_RANGE - 4), highest]);
+    /// assert_eq!(chord.try_inversion(1), Err(MusicError::AboveHighestSound));
+    /// ```
+    pub fn try_inversion(&self, n: usize) -> Result<Chord, MusicError> {
+        self.try_close_position()?.try_rotate(n as isize)
+    }
+    /// Gives the chord in close position with its `n`-th sound from the bottom in the bass
+    /// (see [`Chord::try_inversion`]).
+    /// # Panics
+    /// It panics when a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "G3 B3 D4 F4".parse::<Chord>().unwrap();
+    /// let third = "F4 G4 B4 D5".parse::<Chord>().unwrap();
+    /// assert!(chord.inversion(3).strict_eq(&third));
+    /// ```
+    pub fn inversion(&self, n: usize) -> Chord {
+        self.try_inversion(n)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Moves the lowest sound above the highest one by octaves, `steps` times,
+    /// or the highest sound below the lowest one when `steps` is negative,
+    /// failing when a sound would be outside the pitch space.
+    /// The sounds of the result are sorted.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let up = "G4 C5 E5".parse::<Chord>().unwrap();
+    /// assert!(chord.try_rotate(2).unwrap().strict_eq(&up));
+    /// let down = "G3 C4 E4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_rotate(-1).unwrap().strict_eq(&down));
+    /// assert_eq!(Chord::init(0).try_rotate(1), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_rotate(&self, steps: isize) -> Result<Chord, MusicError> {
+        let mut sounds = self.sorted_sounds()?;
+        for _ in 0..steps.unsigned_abs() {
+            let lowest = sounds[0];
+            let highest = sounds[sounds.len() - 1];
+            if steps > 0 {
+                let octaves = (highest.range() - lowest.range()).div_euclid(12) + 1;
+                sounds.remove(0);
+                sounds.push(shift_octaves(&lowest, octaves)?);
+            } else {
+                let octaves = (highest.range() - lowest.range()).div_euclid(12) + 1;
+                sounds.pop();
+                sounds.insert(0, shift_octaves(&highest, -octaves)?);
+            }
+        }
+        Ok(Chord::from_vec(sounds))
+    }
+    /// Moves the lowest sound above the highest one by octaves, `steps` times,
+    /// or the highest sound below the lowest one when `steps` is negative
+    /// (see [`Chord::try_rotate`]).
+    /// # Panics
+    /// It panics when a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// assert!(chord.rotate(3).strict_eq(&"C5 E5 G5".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn rotate(&self, steps: isize) -> Chord {
+        self.try_rotate(steps)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the chord in close position: the lowest sound stays in place
+    /// and every other sound is moved by octaves to stack as tightly as possible above it,
+    /// doubled notes coming last. It fails when a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C3 G3 C4 E5".parse::<Chord>().unwrap();
+    /// let close = "C3 E3 G3 C4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_close_position().unwrap().strict_eq(&close));
+    /// assert_eq!(Chord::init(0).try_close_position(), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_close_position(&self) -> Result<Chord, MusicError> {
+        let sounds = self.sorted_sounds()?;
+        let bass = sounds[0];
+        let offset = |sound: &Sound| match (sound.range() - bass.range()).rem_euclid(12) {
+            0 => 12,
+            offset => offset,
+        };
+        let mut upper: Vec<(usize, isize, Sound)> = Vec::with_capacity(sounds.len() - 1);
+        for sound in sounds[1..].iter() {
+            let doubling = upper
+                .iter()
+                .filter(|(_, other, _)| *other == offset(sound))
+                .count();
+            let doubling = doubling + usize::from(offset(sound) == 12);
+            upper.push((doubling, offset(sound), *sound));
+        }
+        upper.sort_by_key(|(doubling, offset, _)| (*doubling, *offset));
+        let mut close = vec![bass];
+        for (_, _, sound) in upper {
+            let previous = close[close.len() - 1];
+            let octaves = (previous.range() - sound.range()).div_euclid(12) + 1;
+            close.push(shift_octaves(&sound, octaves)?);
+        }
+        Ok(Chord::from_vec(close))
+    }
+    /// Gives the chord in close position (see [`Chord::try_close_position`]).
+    /// # Panics
+    /// It panics when a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "E3 C5 G5".parse::<Chord>().unwrap();
+    /// assert!(chord.close_position().strict_eq(&"E3 G3 C4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn close_position(&self) -> Chord {
+        self.try_close_position()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the chord in open position: from the close position,
+    /// every other sound above the bass is moved up an octave.
+    /// It fails when a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let open = "C4 G4 E5".parse::<Chord>().unwrap();
+    /// assert!(chord.try_open_position().unwrap().strict_eq(&open));
+    /// assert_eq!(Chord::init(0).try_open_position(), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_open_position(&self) -> Result<Chord, MusicError> {
+        let close = self.try_close_position()?;
+        let indices: Vec<usize> = (1..close.len()).step_by(2).collect();
+        close.try_shift_sounds(&indices, 1)
+    }
+    /// Gives the chord in open position (see [`Chord::try_open_position`]).
+    /// # Panics
+    /// It panics when a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "C4 E4 G4 B4".parse::<Chord>().unwrap();
+    /// assert!(chord.open_position().strict_eq(&"C4 G4 E5 B5".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn open_position(&self) -> Chord {
+        self.try_open_position()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Moves the second highest sound down an octave, which turns a close position
+    /// into a drop-2 voicing. It fails when the chord has fewer than two sounds
+    /// or a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4 B4".parse::<Chord>().unwrap();
+    /// let drop = "G3 C4 E4 B4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_drop2().unwrap().strict_eq(&drop));
+    /// let single = "C4".parse::<Chord>().unwrap();
+    /// assert_eq!(single.try_drop2(), Err(MusicError::TooFewSounds));
+    /// ```
+    pub fn try_drop2(&self) -> Result<Chord, MusicError> {
+        self.try_drop(&[2])
+    }
+    /// Moves the second highest sound down an octave (see [`Chord::try_drop2`]).
+    /// # Panics
+    /// It panics when the chord has fewer than two sounds
+    /// or a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "F4 A4 C5 E5".parse::<Chord>().unwrap();
+    /// assert!(chord.drop2().strict_eq(&"C4 F4 A4 E5".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn drop2(&self) -> Chord {
+        self.try_drop2().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Moves the third highest sound down an octave, which turns a close position
+    /// into a drop-3 voicing. It fails when the chord has fewer than three sounds
+    /// or a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4 B4".parse::<Chord>().unwrap();
+    /// let drop = "E3 C4 G4 B4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_drop3().unwrap().strict_eq(&drop));
+    /// let dyad = "C4 G4".parse::<Chord>().unwrap();
+    /// assert_eq!(dyad.try_drop3(), Err(MusicError::TooFewSounds));
+    /// ```
+    pub fn try_drop3(&self) -> Result<Chord, MusicError> {
+        self.try_drop(&[3])
+    }
+    /// Moves the third highest sound down an octave (see [`Chord::try_drop3`]).
+    /// # Panics
+    /// It panics when the chord has fewer than three sounds
+    /// or a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "G3 B3 D4 F4".parse::<Chord>().unwrap();
+    /// assert!(chord.drop3().strict_eq(&"B2 G3 D4 F4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn drop3(&self) -> Chord {
+        self.try_drop3().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Moves the second and fourth highest sounds down an octave, which turns
+    /// a close position into a drop-2-and-4 voicing. It fails when the chord
+    /// has fewer than four sounds or a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4 B4".parse::<Chord>().unwrap();
+    /// let drop = "C3 G3 E4 B4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_drop2_and_4().unwrap().strict_eq(&drop));
+    /// let triad = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// assert_eq!(triad.try_drop2_and_4(), Err(MusicError::TooFewSounds));
+    /// ```
+    pub fn try_drop2_and_4(&self) -> Result<Chord, MusicError> {
+        self.try_drop(&[2, 4])
+    }
+    /// Moves the second and fourth highest sounds down an octave
+    /// (see [`Chord::try_drop2_and_4`]).
+    /// # Panics
+    /// It panics when the chord has fewer than four sounds
+    /// or a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "D4 F4 A4 C5".parse::<Chord>().unwrap();
+    /// assert!(chord.drop2_and_4().strict_eq(&"D3 A3 F4 C5".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn drop2_and_4(&self) -> Chord {
+        self.try_drop2_and_4()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives a spread voicing: the upper sounds in close position
+    /// and the bass an octave below them, leaving a gap of more than an octave.
+    /// It fails when a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "C4 E4 G4 B4".parse::<Chord>().unwrap();
+    /// let spread = "C3 E4 G4 B4".parse::<Chord>().unwrap();
+    /// assert!(chord.try_spread().unwrap().strict_eq(&spread));
+    /// assert_eq!(Chord::init(0).try_spread(), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_spread(&self) -> Result<Chord, MusicError> {
+        self.try_close_position()?.try_shift_sounds(&[0], -1)
+    }
+    /// Gives a spread voicing (see [`Chord::try_spread`]).
+    /// # Panics
+    /// It panics when a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "A3 C4 E4".parse::<Chord>().unwrap();
+    /// assert!(chord.spread().strict_eq(&"A2 C4 E4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn spread(&self) -> Chord {
+        self.try_spread().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the shell voicing of the chord: its root in the bass, then its third
+    /// (or suspended tone) and its seventh (or sixth) in close position,
+    /// the other tones being left out. The chord is identified with [`Chord::identify`].
+    /// It fails when the chord is not recognized or a sound would be outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError};
+    /// let chord = "E3 G3 B♭3 C4 D4".parse::<Chord>().unwrap();
+    /// let shell = "C3 E3 B♭3".parse::<Chord>().unwrap();
+    /// assert!(chord.try_shell().unwrap().strict_eq(&shell));
+    /// let cluster = "C4 D♭4 D4".parse::<Chord>().unwrap();
+    /// assert_eq!(cluster.try_shell(), Err(MusicError::UnidentifiedChord));
+    /// ```
+    pub fn try_shell(&self) -> Result<Chord, MusicError> {
+        let sounds = self.sorted_sounds()?;
+        let candidate = self
+            .identify()
+            .into_iter()
+            .next()
+            .ok_or(MusicError::UnidentifiedChord)?;
+        let root = candidate.root();
+        let tones = candidate.quality().tones();
+        let find = |numbers: &[usize]| {
+            numbers.iter().find_map(|number| {
+                let (_, semitones) = tones.iter().find(|(other, _)| other == number)?;
+                sounds.iter().find(|sound| {
+                    (sound.spelled_note().semitones() - root.semitones()).rem_euclid(12)
+                        == semitones % 12
+                })
+            })
+        };
+        let bass = sounds
+            .iter()
+            .find(|sound| sound.spelled_note().to_usize() == root.to_usize())
+            .ok_or(MusicError::UnidentifiedChord)?;
+        let octaves = (bass.range() - sounds[0].range() + 11).div_euclid(12);
+        let mut shell = vec![shift_octaves(bass, -octaves)?];
+        shell.extend(find(&[3, 2, 4]));
+        shell.extend(find(&[7, 6]));
+        Chord::from_vec(shell).try_close_position()
+    }
+    /// Gives the shell voicing of the chord (see [`Chord::try_shell`]).
+    /// # Panics
+    /// It panics when the chord is not recognized or a sound would be outside the pitch space.
+    /// ```
+    /// use music::Chord;
+    /// let chord = "F3 A3 C4 E4".parse::<Chord>().unwrap();
+    /// assert!(chord.shell().strict_eq(&"F3 A3 E4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn shell(&self) -> Chord {
+        self.try_shell().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Moves every sound by octaves so that it lies between `low` and `high` (included),
+    /// as little as possible, and sorts the sounds.
+    /// It fails when no octave of a sound lies within the range.
+    /// ```
+    /// use music::{Chord, MusicError, Note, Sound};
+    /// let chord = "C2 E4 G6".parse::<Chord>().unwrap();
+    /// let (low, high) = (Sound::init(Note::D, 4), Sound::init(Note::D, 5));
+    /// let within = "E4 G4 C5".parse::<Chord>().unwrap();
+    /// assert!(chord.try_within(low, high).unwrap().strict_eq(&within));
+    /// let (low, high) = (Sound::init(Note::D, 4), Sound::init(Note::F, 4));
+    /// assert_eq!(
+    ///     chord.try_within(low, high),
+    ///     Err(MusicError::NotWithinRange(Sound::init(Note::C, 2)))
+    /// );
+    /// ```
+    pub fn try_within(&self, low: Sound, high: Sound) -> Result<Chord, MusicError> {
+        let mut sounds = Vec::with_capacity(self.len());
+        for sound in self.sounds() {
+            let octaves = if sound.range() < low.range() {
+                (low.range() - sound.range() + 11).div_euclid(12)
+            } else if sound.range() > high.range() {
+                -(sound.range() - high.range() + 11).div_euclid(12)
+            } else {
+                0
+            };
+            let moved = shift_octaves(sound, octaves)?;
+            if moved < low || moved > high {
+                return Err(MusicError::NotWithinRange(*sound));
+            }
+            sounds.push(moved);
+        }
+        Ok(Chord::from_vec(sounds).to_sorted())
+    }
+    /// Moves every sound by octaves so that it lies between `low` and `high`
+    /// (see [`Chord::try_within`]).
+    /// # Panics
+    /// It panics when no octave of a sound lies within the range.
+    /// ```
+    /// use music::{Chord, Note, Sound};
+    /// let chord = "G2 B2 D3".parse::<Chord>().unwrap();
+    /// let (low, high) = (Sound::init(Note::C, 3), Sound::init(Note::C, 4));
+    /// assert!(chord.within(low, high).strict_eq(&"D3 G3 B3".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn within(&self, low: Sound, high: Sound) -> Chord {
+        self.try_within(low, high)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the sounds sorted from the lowest to the highest,
+    /// failing when the chord is empty.
+    fn sorted_sounds(&self) -> Result<Vec<Sound>, MusicError> {
+        if self.is_empty() {
+            return Err(MusicError::EmptyChord);
+        }
+        Ok(self.clone().to_sorted().sounds().clone())
+    }
+    /// Moves the sounds at some positions (from the lowest) by octaves
+    /// and sorts the result.
+    fn try_shift_sounds(&self, indices: &[usize], octaves: isize) -> Result<Chord, MusicError> {
+        let mut sounds = self.sorted_sounds()?;
+        for index in indices {
+            sounds[*index] = shift_octaves(&sounds[*index], octaves)?;
+        }
+        Ok(Chord::from_vec(sounds).to_sorted())
+    }
+    /// Moves the sounds at some positions (from the highest) down an octave.
+    fn try_drop(&self, positions: &[usize]) -> Result<Chord, MusicError> {
+        let count = positions.iter().max().copied().unwrap_or(0);
+        if self.len() < count {
+            return Err(MusicError::TooFewSounds);
+        }
+        let indices: Vec<usize> = positions
+            .iter()
+            .map(|position| self.len() - position)
+            .collect();
+        self.try_shift_sounds(&indices, -1)
+    }
+}
+
+/// Moves a sound by octaves, keeping its spelling.
+fn shift_octaves(sound: &Sound, octaves: isize) -> Result<Sound, MusicError> {
+    Sound::try_init_spelled(sound.spelled_note(), sound.octave() + octaves).map_err(|error| {
+        match error {
+            MusicError::InvalidOctave(_) if octaves > 0 => MusicError::AboveHighestSound,
+            MusicError::InvalidOctave(_) => MusicError::BelowLowestSound,
+            error => error,
+        }
+    })
+}
@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod unit_test;
+
+use crate::tuning::Tuning;
+use crate::{Chord, Interval, Quality};
+
+/// Classification of intervals from the most stable to the least stable,
+/// as taught in counterpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Consonance {
+    /// Unisons, fifths, octaves and their compounds.
+    PerfectConsonance,
+    /// Major and minor thirds and sixths, and their compounds.
+    ImperfectConsonance,
+    /// The perfect fourth, dissonant above the bass
+    /// and consonant between upper voices.
+    ContextDependent,
+    /// Seconds, sevenths, and all the augmented and diminished intervals.
+    Dissonance,
+}
+
+impl Consonance {
+    /// Tests whether or not the class is a consonance,
+    /// the fourth being a consonance only when it is not above the bass.
+    /// ```
+    /// use music::Consonance;
+    /// assert!(Consonance::ImperfectConsonance.is_consonant(true));
+    /// assert!(!Consonance::ContextDependent.is_consonant(true));
+    /// assert!(Consonance::ContextDependent.is_consonant(false));
+    /// ```
+    pub fn is_consonant(&self, above_bass: bool) -> bool {
+        match self {
+            Consonance::PerfectConsonance | Consonance::ImperfectConsonance => true,
+            Consonance::ContextDependent => !above_bass,
+            Consonance::Dissonance => false,
+        }
+    }
+}
+
+impl Interval {
+    /// Classifies the interval according to its quality and number,
+    /// compound intervals being classified as their simple counterparts.
+    /// ```
+    /// use music::{Consonance, Interval};
+    /// let consonance = |name: &str| name.parse::<Interval>().unwrap().consonance();
+    /// assert_eq!(consonance("P12"), Consonance::PerfectConsonance);
+    /// assert_eq!(consonance("m6"), Consonance::ImperfectConsonance);
+    /// assert_eq!(consonance("P4"), Consonance::ContextDependent);
+    /// assert_eq!(consonance("A4"), Consonance::Dissonance);
+    /// assert_eq!(consonance("d4"), Consonance::Dissonance);
+    /// ```
+    pub fn consonance(&self) -> Consonance {
+        match (self.quality(), self.simple_number()) {
+            (Quality::Perfect, 1 | 5 | 8) => Consonance::PerfectConsonance,
+            (Quality::Major | Quality::Minor, 3 | 6) => Consonance::ImperfectConsonance,
+            (Quality::Perfect, 4) => Consonance::ContextDependent,
+            _ => Consonance::Dissonance,
+        }
+    }
+    /// Computes the sensory roughness of the interval when both sounds are played
+    /// with a timbre in a tuning system (see [`Timbre::roughness`]).
+    /// ```
+    /// use music::tuning::EqualTemperament;
+    /// use music::{Interval, Timbre};
+    /// let tuning = EqualTemperament::default();
+    /// let timbre = Timbre::default();
+    /// let roughness = |name: &str| name.parse::<Interval>().unwrap().roughness(&timbre, &tuning);
+    /// assert!(roughness("m2") > roughness("M3"));
+    /// assert!(roughness("M3") > roughness("P5"));
+    /// assert!(roughness("P5") > roughness("P8"));
+    /// ```
+    pub fn roughness<T: Tuning + ?Sized>(&self, timbre: &Timbre, tuning: &T) -> f64 {
+        timbre.roughness(&[
+            self.first_sound().frequency(tuning),
+            self.second_sound().frequency(tuning),
+        ])
+    }
+}
+
+impl Chord {
+    /// Computes the sensory roughness of the chord when all its sounds are played
+    /// with a timbre in a tuning system (see [`Timbre::roughness`]).
+    /// ```
+    /// use music::tuning::{EqualTemperament, JustIntonation};
+    /// use music::{Chord, Note, Timbre};
+    /// let timbre = Timbre::default();
+    /// let major = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let cluster = "C4 D♭4 D4".parse::<Chord>().unwrap();
+    /// let equal = EqualTemperament::default();
+    /// assert!(cluster.roughness(&timbre, &equal) > major.roughness(&timbre, &equal));
+    /// let just = JustIntonation::init(Note::C, 440.0);
+    /// assert!(major.roughness(&timbre, &just) < major.roughness(&timbre, &equal));
+    /// ```
+    pub fn roughness<T: Tuning + ?Sized>(&self, timbre: &Timbre, tuning: &T) -> f64 {
+        let frequencies: Vec<f64> = self
+            .sounds()
+            .iter()
+            .map(|sound| sound.frequency(tuning))
+            .collect();
+        timbre.roughness(&frequencies)
+    }
+    /// Gives the intervals between every pair of sounds of the chord that are dissonant,
+    /// the fourths above the lowest sound being dissonant.
+    /// ```
+    /// use music::Chord;
+    /// let dominant_seventh = "G3 B3 D4 F4".parse::<Chord>().unwrap();
+    /// let names: Vec<String> = dominant_seventh
+    ///     .dissonances()
+    ///     .iter()
+    ///     .map(|interval| interval.short_name())
+    ///     .collect();
+    /// assert_eq!(names, vec!["m7", "d5"]);
+    /// let six_four = "G3 C4 E4".parse::<Chord>().unwrap();
+    /// assert_eq!(six_four.dissonances().len(), 1);
+    /// ```
+    pub fn dissonances(&self) -> Vec<Interval> {
+        let sounds = self.sounds();
+        let Some(bass) = sounds.iter().min() else {
+            return Vec::new();
+        };
+        let mut dissonances = Vec::new();
+        for (index, sound_one) in sounds.iter().enumerate() {
+            for sound_two in sounds[index + 1..].iter() {
+                let interval = Interval::init(*sound_one, *sound_two);
+                let above_bass = sound_one == bass || sound_two == bass;
+                if !interval.consonance().is_consonant(above_bass) {
+                    dissonances.push(interval);
+                }
+            }
+        }
+        dissonances
+    }
+}
+
+/// Spectrum of a sound: the frequency ratios of its partials to the fundamental,
+/// with their amplitudes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timbre {
+    partials: Vec<(f64, f64)>,
+}
+
+impl Timbre {
+    /// Creates a timbre from its partials, given as (frequency ratio, amplitude) pairs.
+    /// ```
+    /// use music::Timbre;
+    /// let timbre = Timbre::init(vec![(1.0, 1.0), (2.76, 0.5), (5.4, 0.25)]);
+    /// assert_eq!(timbre.partials().len(), 3);
+    /// ```
+    pub fn init(partials: Vec<(f64, f64)>) -> Self {
+        Self { partials }
+    }
+    /// Creates a timbre made of the first `count` harmonics,
+    /// the amplitude of each harmonic being `decay` times the amplitude of the previous one.
+    /// ```
+    /// use music::Timbre;
+    /// let timbre = Timbre::harmonic(3, 0.5);
+    /// assert_eq!(timbre.partials(), &[(1.0, 1.0), (2.0, 0.5), (3.0, 0.25)]);
+    /// ```
+    pub fn harmonic(count: usize, decay: f64) -> Self {
+        let partials = (0..count)
+            .map(|index| ((index + 1) as f64, decay.powi(index as i32)))
+            .collect();
+        Self::init(partials)
+    }
+    /// Creates a timbre without overtones, such as a sine wave.
+    /// ```
+    /// use music::Timbre;
+    /// assert_eq!(Timbre::pure().partials(), &[(1.0, 1.0)]);
+    /// ```
+    pub fn pure() -> Self {
+        Self::harmonic(1, 1.0)
+    }
+    /// Gives the partials of the timbre, as (frequency ratio, amplitude) pairs.
+    /// ```
+    /// use music::Timbre;
+    /// assert_eq!(Timbre::default().partials().len(), 6);
+    /// ```
+    pub fn partials(&self) -> &[(f64, f64)] {
+        &self.partials
+    }
+    /// Computes the sensory roughness of sounds of given fundamental frequencies (in Hz)
+    /// played with the timbre, using Sethares' parametrization of the Plomp–Levelt curve.
+    ///
+    /// It adds up the roughness of every pair of partials of different sounds,
+    /// so that a single sound is not rough.
+    /// ```
+    /// use music::Timbre;
+    /// let timbre = Timbre::pure();
+    /// assert_eq!(timbre.roughness(&[440.0]), 0.0);
+    /// assert!(timbre.roughness(&[440.0, 466.16]) > timbre.roughness(&[440.0, 523.25]));
+    /// assert!(timbre.roughness(&[440.0, 441.0]) < timbre.roughness(&[440.0, 466.16]));
+    /// ```
+    pub fn roughness(&self, frequencies: &[f64]) -> f64 {
+        let mut roughness = 0.0;
+        for (index, frequency_one) in frequencies.iter().enumerate() {
+            for frequency_two in frequencies[index + 1..].iter() {
+                for (ratio_one, amplitude_one) in self.partials.iter() {
+                    for (ratio_two, amplitude_two) in self.partials.iter() {
+                        roughness += plomp_levelt(
+                            frequency_one * ratio_one,
+                            *amplitude_one,
+                            frequency_two * ratio_two,
+                            *amplitude_two,
+                        );
+                    }
+                }
+            }
+        }
+        roughness
+    }
+}
+
+impl Default for Timbre {
+    /// Gives the six first harmonics, each one 0.88 times as loud as the previous one,
+    /// as in Sethares' experiments.
+    fn default() -> Self {
+        Self::harmonic(6, 0.88)
+    }
+}
+
+/// Roughness of two partials according to Sethares' model of the Plomp–Levelt curve.
+fn plomp_levelt(
+    frequency_one: f64,
+    amplitude_one: f64,
+    frequency_two: f64,
+    amplitude_two: f64,
+) -> f64 {
+    const MAXIMUM: f64 = 0.24;
+    const SLOPE: f64 = 0.0207;
+    const OFFSET: f64 = 18.96;
+    const DECAY_ONE: f64 = 3.51;
+    const DECAY_TWO: f64 = 5.75;
+    let lowest = frequency_one.min(frequency_two);
+    let difference = (frequency_two - frequency_one).abs();
+    let scale = MAXIMUM / (SLOPE * lowest + OFFSET);
+    amplitude_one.min(amplitude_two)
+        * ((-DECAY_ONE * scale * difference).exp() - (-DECAY_TWO * scale * difference).exp())
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{Consonance, Timbre};
+    use crate::tuning::{EqualTemperament, JustIntonation};
+    use crate::{Chord, Interval, Note, Sound};
+
+    #[test]
+    fn test_consonance() {
+        let classes = [
+            ("P1", Consonance::PerfectConsonance),
+            ("P8", Consonance::PerfectConsonance),
+            ("P15", Consonance::PerfectConsonance),
+            ("M3", Consonance::ImperfectConsonance),
+            ("m10", Consonance::ImperfectConsonance),
+            ("P11", Consonance::ContextDependent),
+            ("M2", Consonance::Dissonance),
+            ("M7", Consonance::Dissonance),
+            ("A5", Consonance::Dissonance),
+            ("d8", Consonance::Dissonance),
+        ];
+        for (name, consonance) in classes {
+            assert_eq!(name.parse::<Interval>().unwrap().consonance(), consonance);
+        }
+        // Enharmonically equivalent intervals are told apart by their spelling.
+        let c_4 = Sound::init(Note::C, 4);
+        let minor_third = Interval::init(c_4, Sound::init(Note::Eb, 4));
+        let augmented_second = Interval::init(c_4, Sound::init(Note::Ds, 4));
+        assert_eq!(minor_third.consonance(), Consonance::ImperfectConsonance);
+        assert_eq!(augmented_second.consonance(), Consonance::Dissonance);
+    }
+    #[test]
+    fn test_roughness() {
+        let timbre = Timbre::default();
+        let tuning = EqualTemperament::default();
+        let roughness = |chord: &str| chord.parse::<Chord>().unwrap().roughness(&timbre, &tuning);
+        assert!(roughness("C4 E4 G4") < roughness("C4 E♭4 G♭4"));
+        assert!(roughness("C4 G4") < roughness("C4 F#4"));
+        assert!(roughness("C2 E2") > roughness("C5 E5"));
+        assert_eq!(roughness("C4"), 0.0);
+        let fifth = "P5".parse::<Interval>().unwrap();
+        let just = JustIntonation::init(Note::C, 440.0);
+        assert!(fifth.roughness(&timbre, &just) < fifth.roughness(&timbre, &tuning));
+        assert!(fifth.roughness(&Timbre::pure(), &tuning) < fifth.roughness(&timbre, &tuning));
+    }
+    #[test]
+    fn test_chord_dissonances() {
+        let chord = "C4 E4 G4 C5".parse::<Chord>().unwrap();
+        assert!(chord.dissonances().is_empty());
+        let chord = "C4 F4 A4".parse::<Chord>().unwrap();
+        assert_eq!(chord.dissonances().len(), 1);
+        let chord = "F3 C4 F4 A4".parse::<Chord>().unwrap();
+        assert!(chord.dissonances().is_empty());
+        assert!(Chord::init(0).dissonances().is_empty());
+    }
+}
@@ -0,0 +1,604 @@
+#[cfg(test)]
+mod unit_test;
+
+use crate::tuning::{cents_to_ratio, Tuning, A4_FREQUENCY};
+use crate::{Interval, Letter, MusicError, Note, Scale, Sound, SpelledNote};
+
+/// Implementation of an equal division of the octave (EDO),
+/// tuned from the frequency of A4.
+///
+/// Spelled sounds are mapped to steps along the line of fifths,
+/// the fifth being the nearest step to a pure fifth,
+/// so that for instance C# and D♭ are different steps in 31-EDO.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Edo {
+    divisions: usize,
+    reference: f64,
+}
+
+impl Edo {
+    /// Creates an equal division of the octave into `divisions` steps,
+    /// A4 having the frequency `reference` (in Hz).
+    /// # Panics
+    /// It panics when `divisions` is zero or when `reference` is not a positive
+    /// finite frequency.
+    /// ```
+    /// use music::Edo;
+    /// let edo = Edo::init(31, 440.0);
+    /// assert_eq!(edo.divisions(), 31);
+    /// ```
+    pub fn init(divisions: usize, reference: f64) -> Self {
+        Self::try_init(divisions, reference).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates an equal division of the octave into `divisions` steps,
+    /// A4 having the frequency `reference` (in Hz),
+    /// failing when `divisions` is zero or when `reference` is not a positive finite frequency.
+    /// ```
+    /// use music::{Edo, MusicError};
+    /// assert!(Edo::try_init(19, 440.0).is_ok());
+    /// assert_eq!(Edo::try_init(0, 440.0), Err(MusicError::InvalidDivisions));
+    /// for reference in [0.0, -440.0, f64::NAN, f64::INFINITY] {
+    ///     assert_eq!(Edo::try_init(12, reference), Err(MusicError::InvalidFrequency));
+    /// }
+    /// ```
+    pub fn try_init(divisions: usize, reference: f64) -> Result<Self, MusicError> {
+        if divisions == 0 {
+            return Err(MusicError::InvalidDivisions);
+        }
+        if !reference.is_finite() || reference <= 0.0 {
+            return Err(MusicError::InvalidFrequency);
+        }
+        Ok(Self {
+            divisions,
+            reference,
+        })
+    }
+    /// Gives the number of steps in an octave.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(53, 440.0).divisions(), 53);
+    /// ```
+    pub fn divisions(&self) -> usize {
+        self.divisions
+    }
+    /// Gives the frequency (in Hz) of A4.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(24, 442.0).reference(), 442.0);
+    /// ```
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+    /// Gives the size (in cents) of a step.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(24, 440.0).step_cents(), 50.0);
+    /// ```
+    pub fn step_cents(&self) -> f64 {
+        1200.0 / self.divisions as f64
+    }
+    /// Gives the number of steps of the fifth, the nearest to a pure fifth (3/2).
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(12, 440.0).fifth(), 7);
+    /// assert_eq!(Edo::init(31, 440.0).fifth(), 18);
+    /// assert_eq!(Edo::init(53, 440.0).fifth(), 31);
+    /// ```
+    pub fn fifth(&self) -> isize {
+        (self.divisions as f64 * 1.5f64.log2()).round() as isize
+    }
+    /// Gives the number of steps of the whole tone, made of two fifths minus an octave.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(19, 440.0).whole_tone(), 3);
+    /// assert_eq!(Edo::init(24, 440.0).whole_tone(), 4);
+    /// ```
+    pub fn whole_tone(&self) -> isize {
+        2 * self.fifth() - self.divisions as isize
+    }
+    /// Gives the number of steps of the diatonic half tone (e.g. from E to F),
+    /// made of three octaves minus five fifths.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(31, 440.0).diatonic_semitone(), 3);
+    /// assert_eq!(Edo::init(53, 440.0).diatonic_semitone(), 4);
+    /// ```
+    pub fn diatonic_semitone(&self) -> isize {
+        3 * self.divisions as isize - 5 * self.fifth()
+    }
+    /// Gives the number of steps of a sharp, the difference between a whole tone
+    /// and a diatonic half tone.
+    /// ```
+    /// use music::Edo;
+    /// assert_eq!(Edo::init(12, 440.0).chromatic_semitone(), 1);
+    /// assert_eq!(Edo::init(31, 440.0).chromatic_semitone(), 2);
+    /// ```
+    pub fn chromatic_semitone(&self) -> isize {
+        self.whole_tone() - self.diatonic_semitone()
+    }
+    /// Gives the number of steps from C to a spelled note of the same octave
+    /// (it may be negative or exceed the octave with many accidentals).
+    /// ```
+    /// use music::{Edo, Letter, SpelledNote};
+    /// let edo = Edo::init(31, 440.0);
+    /// assert_eq!(edo.note_steps(SpelledNote::init(Letter::C, 1)), 2);
+    /// assert_eq!(edo.note_steps(SpelledNote::init(Letter::D, -1)), 3);
+    /// assert_eq!(edo.note_steps(SpelledNote::init(Letter::C, -1)), -2);
+    /// ```
+    pub fn note_steps(&self, note: SpelledNote) -> isize {
+        let (tone, semitone) = (self.whole_tone(), self.diatonic_semitone());
+        let letter = match note.letter() {
+            Letter::C => 0,
+            Letter::D => tone,
+            Letter::E => 2 * tone,
+            Letter::F => 2 * tone + semitone,
+            Letter::G => 3 * tone + semitone,
+            Letter::A => 4 * tone + semitone,
+            Letter::B => 5 * tone + semitone,
+        };
+        letter + note.accidental() as isize * self.chromatic_semitone()
+    }
+}
+
+impl Default for Edo {
+    fn default() -> Self {
+        Self::init(12, A4_FREQUENCY)
+    }
+}
+
+impl Tuning for Edo {
+    /// Gives the frequency (in Hz) of the step of the equal division matching a spelled sound.
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        Ok(EdoSound::from_sound(*self, sound).frequency())
+    }
+}
+
+/// Implementation of a sound of an equal division of the octave,
+/// given by its number of steps from C1 and an offset (in cents).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdoSound {
+    edo: Edo,
+    steps: isize,
+    offset: f64,
+}
+
+impl EdoSound {
+    /// Creates the sound `steps` steps above C1 (or below it when negative).
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let edo = Edo::init(24, 440.0);
+    /// let sound = EdoSound::init(edo, 24 * 3 + 19);
+    /// assert_eq!(sound.octave(), 4);
+    /// assert_eq!(sound.degree(), 19);
+    /// ```
+    pub fn init(edo: Edo, steps: isize) -> Self {
+        Self {
+            edo,
+            steps,
+            offset: 0.0,
+        }
+    }
+    /// Creates the sound of an equal division matching a spelled sound.
+    /// ```
+    /// use music::{Edo, EdoSound, Note, Sound};
+    /// let edo = Edo::init(31, 440.0);
+    /// let c_sharp = EdoSound::from_sound(edo, &Sound::init(Note::Cs, 4));
+    /// let d_flat = EdoSound::from_sound(edo, &Sound::init(Note::Db, 4));
+    /// assert_eq!(d_flat.steps() - c_sharp.steps(), 1);
+    /// let edo = Edo::init(12, 440.0);
+    /// assert_eq!(EdoSound::from_sound(edo, &Sound::init(Note::Bs, 3)).steps(), 36);
+    /// ```
+    pub fn from_sound(edo: Edo, sound: &Sound) -> Self {
+        let octave = (sound.octave() - 1) * edo.divisions as isize;
+        Self::init(edo, octave + edo.note_steps(sound.spelled_note()))
+    }
+    /// Gives the same sound shifted by an offset (in cents), replacing the previous one.
+    /// ```
+    /// use music::{Edo, EdoSound, Note, Sound};
+    /// let edo = Edo::init(12, 440.0);
+    /// let sound = EdoSound::from_sound(edo, &Sound::init(Note::A, 4)).with_offset(-14.0);
+    /// assert_eq!(sound.offset(), -14.0);
+    /// ```
+    pub fn with_offset(&self, offset: f64) -> Self {
+        Self { offset, ..*self }
+    }
+    /// Gives the equal division of the sound.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let edo = Edo::init(19, 440.0);
+    /// assert_eq!(EdoSound::init(edo, 3).edo(), edo);
+    /// ```
+    pub fn edo(&self) -> Edo {
+        self.edo
+    }
+    /// Gives the number of steps of the sound from C1.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// assert_eq!(EdoSound::init(Edo::init(19, 440.0), -4).steps(), -4);
+    /// ```
+    pub fn steps(&self) -> isize {
+        self.steps
+    }
+    /// Gives the offset (in cents) of the sound from its step.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// assert_eq!(EdoSound::init(Edo::init(19, 440.0), 7).offset(), 0.0);
+    /// ```
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+    /// Gives the octave of the sound, C1 starting the first octave.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let edo = Edo::init(31, 440.0);
+    /// assert_eq!(EdoSound::init(edo, 31).octave(), 2);
+    /// assert_eq!(EdoSound::init(edo, -1).octave(), 0);
+    /// ```
+    pub fn octave(&self) -> isize {
+        self.steps.div_euclid(self.edo.divisions as isize) + 1
+    }
+    /// Gives the number of steps of the sound from the C of its octave,
+    /// the counterpart of `Note::to_usize`.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let edo = Edo::init(31, 440.0);
+    /// assert_eq!(EdoSound::init(edo, 33).degree(), 2);
+    /// assert_eq!(EdoSound::init(edo, -1).degree(), 30);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.steps.rem_euclid(self.edo.divisions as isize) as usize
+    }
+    /// Gives the position (in cents) of the sound from C1.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let sound = EdoSound::init(Edo::init(24, 440.0), 3).with_offset(5.0);
+    /// assert_eq!(sound.cents(), 155.0);
+    /// ```
+    pub fn cents(&self) -> f64 {
+        self.steps as f64 * self.edo.step_cents() + self.offset
+    }
+    /// Gives the frequency (in Hz) of the sound.
+    /// ```
+    /// use music::{Edo, EdoSound, Note, Sound};
+    /// let edo = Edo::init(24, 440.0);
+    /// let a_4 = EdoSound::from_sound(edo, &Sound::init(Note::A, 4));
+    /// assert_eq!(a_4.frequency(), 440.0);
+    /// assert!((a_4.shift(1).frequency() - 452.893).abs() < 1e-3);
+    /// ```
+    pub fn frequency(&self) -> f64 {
+        let a_4 = Self::from_sound(self.edo, &Sound::init(Note::A, 4));
+        self.edo.reference * cents_to_ratio(self.cents() - a_4.cents())
+    }
+    /// Moves the sound by a signed number of steps, keeping its offset.
+    /// ```
+    /// use music::{Edo, EdoSound};
+    /// let sound = EdoSound::init(Edo::init(31, 440.0), 10);
+    /// assert_eq!(sound.shift(-12).steps(), -2);
+    /// ```
+    pub fn shift(&self, steps: isize) -> Self {
+        Self {
+            steps: self.steps + steps,
+            ..*self
+        }
+    }
+}
+
+/// Implementation of an interval between two sounds of the same equal division of the octave.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdoInterval {
+    sound_one: EdoSound,
+    sound_two: EdoSound,
+}
+
+impl EdoInterval {
+    /// Creates an interval between two sounds.
+    /// # Panics
+    /// It panics when the sounds do not belong to the same division of the octave.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound};
+    /// let edo = Edo::init(31, 440.0);
+    /// let interval = EdoInterval::init(EdoSound::init(edo, 0), EdoSound::init(edo, 10));
+    /// assert_eq!(interval.distance(), 10);
+    /// ```
+    pub fn init(sound_one: EdoSound, sound_two: EdoSound) -> Self {
+        Self::try_init(sound_one, sound_two).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates an interval between two sounds,
+    /// failing when they do not belong to the same division of the octave.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound, MusicError};
+    /// let sound_one = EdoSound::init(Edo::init(31, 440.0), 0);
+    /// let sound_two = EdoSound::init(Edo::init(19, 440.0), 0);
+    /// assert_eq!(EdoInterval::try_init(sound_one, sound_two), Err(MusicError::EdoMismatch));
+    /// ```
+    pub fn try_init(sound_one: EdoSound, sound_two: EdoSound) -> Result<Self, MusicError> {
+        if sound_one.edo != sound_two.edo {
+            return Err(MusicError::EdoMismatch);
+        }
+        Ok(Self {
+            sound_one,
+            sound_two,
+        })
+    }
+    /// Gives the first sound of the interval.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound};
+    /// let edo = Edo::init(19, 440.0);
+    /// let interval = EdoInterval::init(EdoSound::init(edo, 2), EdoSound::init(edo, 8));
+    /// assert_eq!(interval.first_sound(), EdoSound::init(edo, 2));
+    /// ```
+    pub fn first_sound(&self) -> EdoSound {
+        self.sound_one
+    }
+    /// Gives the second sound of the interval.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound};
+    /// let edo = Edo::init(19, 440.0);
+    /// let interval = EdoInterval::init(EdoSound::init(edo, 2), EdoSound::init(edo, 8));
+    /// assert_eq!(interval.second_sound(), EdoSound::init(edo, 8));
+    /// ```
+    pub fn second_sound(&self) -> EdoSound {
+        self.sound_two
+    }
+    /// Computes the length of the interval in steps.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound, Note, Sound};
+    /// let edo = Edo::init(31, 440.0);
+    /// let c_4 = EdoSound::from_sound(edo, &Sound::init(Note::C, 4));
+    /// let e_4 = EdoSound::from_sound(edo, &Sound::init(Note::E, 4));
+    /// assert_eq!(EdoInterval::init(e_4, c_4).distance(), 10);
+    /// ```
+    pub fn distance(&self) -> usize {
+        (self.sound_two.steps - self.sound_one.steps).unsigned_abs()
+    }
+    /// Computes the size of the interval in cents, offsets included,
+    /// it is negative when the second sound is the lowest.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound};
+    /// let edo = Edo::init(53, 440.0);
+    /// let interval = EdoInterval::init(EdoSound::init(edo, 0), EdoSound::init(edo, 31));
+    /// assert!((interval.cents() - 701.887).abs() < 1e-3);
+    /// ```
+    pub fn cents(&self) -> f64 {
+        self.sound_two.cents() - self.sound_one.cents()
+    }
+    /// Computes the frequency ratio from the first sound to the second one.
+    /// ```
+    /// use music::{Edo, EdoInterval, EdoSound};
+    /// let edo = Edo::init(24, 440.0);
+    /// let interval = EdoInterval::init(EdoSound::init(edo, 0), EdoSound::init(edo, 48));
+    /// assert_eq!(interval.ratio(), 4.0);
+    /// ```
+    pub fn ratio(&self) -> f64 {
+        cents_to_ratio(self.cents())
+    }
+}
+
+impl Interval {
+    /// Computes the length of the interval in steps of an equal division of the octave,
+    /// the counterpart of [`Interval::distance`].
+    /// ```
+    /// use music::{Edo, Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+    /// assert_eq!(interval.edo_distance(Edo::init(31, 440.0)), 10);
+    /// assert_eq!(interval.edo_distance(Edo::default()), interval.distance());
+    /// ```
+    pub fn edo_distance(&self, edo: Edo) -> usize {
+        let sound_one = EdoSound::from_sound(edo, &self.first_sound());
+        let sound_two = EdoSound::from_sound(edo, &self.second_sound());
+        EdoInterval::init(sound_one, sound_two).distance()
+    }
+}
+
+impl Scale {
+    /// Gives the steps of an equal division of the octave between the consecutive notes
+    /// of the scale (see [`Scale::try_edo_steps`]).
+    /// # Panics
+    /// It panics when two consecutive notes fall on the same step of the division.
+    /// ```
+    /// use music::{Edo, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::D, ScaleType::Dorian);
+    /// assert_eq!(scale.edo_steps(Edo::init(31, 440.0)), vec![5, 3, 5, 5, 5, 3, 5]);
+    /// ```
+    pub fn edo_steps(&self, edo: Edo) -> Vec<usize> {
+        self.try_edo_steps(edo)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the steps of an equal division of the octave between the consecutive notes
+    /// of the scale, as spelled, the last step going back to the tonic an octave higher.
+    /// It fails when two consecutive notes fall on the same step of the division.
+    /// ```
+    /// use music::{Edo, MusicError, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// assert_eq!(scale.try_edo_steps(Edo::default()), Ok(vec![2, 2, 1, 2, 2, 2, 1]));
+    /// assert_eq!(scale.try_edo_steps(Edo::init(5, 440.0)), Err(MusicError::InvalidScaleSteps));
+    /// ```
+    pub fn try_edo_steps(&self, edo: Edo) -> Result<Vec<usize>, MusicError> {
+        let mut steps: Vec<isize> = self
+            .sounds(1)
+            .iter()
+            .map(|sound| EdoSound::from_sound(edo, sound).steps())
+            .collect();
+        steps.push(steps[0] + edo.divisions as isize);
+        steps
+            .windows(2)
+            .map(|pair| match pair[1] - pair[0] {
+                step if step > 0 => Ok(step as usize),
+                _ => Err(MusicError::InvalidScaleSteps),
+            })
+            .collect()
+    }
+}
+
+/// Implementation of a scale of an equal division of the octave,
+/// given by its tonic and the steps between its consecutive degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdoScale {
+    tonic: EdoSound,
+    steps: Vec<usize>,
+}
+
+impl EdoScale {
+    /// Creates a scale from its tonic and the steps between its consecutive degrees,
+    /// the last one going back to the tonic an octave higher.
+    /// # Panics
+    /// It panics when the steps are not positive or do not add up to an octave.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let edo = Edo::init(24, 440.0);
+    /// let rast = EdoScale::init(EdoSound::init(edo, 72), vec![4, 3, 3, 4, 4, 3, 3]);
+    /// assert_eq!(rast.degrees(), vec![0, 4, 7, 10, 14, 18, 21]);
+    /// ```
+    pub fn init(tonic: EdoSound, steps: Vec<usize>) -> Self {
+        Self::try_init(tonic, steps).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a scale from its tonic and the steps between its consecutive degrees,
+    /// failing when the steps are not positive or do not add up to an octave.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound, MusicError};
+    /// let tonic = EdoSound::init(Edo::init(19, 440.0), 0);
+    /// assert!(EdoScale::try_init(tonic, vec![3, 3, 2, 3, 3, 3, 2]).is_ok());
+    /// assert_eq!(EdoScale::try_init(tonic, vec![3, 3, 2]), Err(MusicError::InvalidScaleSteps));
+    /// ```
+    pub fn try_init(tonic: EdoSound, steps: Vec<usize>) -> Result<Self, MusicError> {
+        if steps.contains(&0) || steps.iter().sum::<usize>() != tonic.edo.divisions {
+            return Err(MusicError::InvalidScaleSteps);
+        }
+        Ok(Self { tonic, steps })
+    }
+    /// Creates the major scale of an equal division, made of its whole tones and diatonic half tones.
+    /// # Panics
+    /// It panics when the division has no positive whole tone and diatonic half tone (e.g. 5-EDO).
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(31, 440.0), 0);
+    /// assert_eq!(EdoScale::major(tonic).steps(), &[5, 5, 3, 5, 5, 5, 3]);
+    /// ```
+    pub fn major(tonic: EdoSound) -> Self {
+        Self::try_major(tonic).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates the major scale of an equal division, made of its whole tones and diatonic
+    /// half tones, failing when they are not both positive (e.g. in 5-EDO).
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound, MusicError};
+    /// let tonic = EdoSound::init(Edo::init(19, 440.0), 0);
+    /// assert_eq!(EdoScale::try_major(tonic).unwrap().steps(), &[3, 3, 2, 3, 3, 3, 2]);
+    /// let tonic = EdoSound::init(Edo::init(5, 440.0), 0);
+    /// assert_eq!(EdoScale::try_major(tonic), Err(MusicError::InvalidScaleSteps));
+    /// ```
+    pub fn try_major(tonic: EdoSound) -> Result<Self, MusicError> {
+        let (tone, semitone) = (tonic.edo.whole_tone(), tonic.edo.diatonic_semitone());
+        if tone <= 0 || semitone <= 0 {
+            return Err(MusicError::InvalidScaleSteps);
+        }
+        let (tone, semitone) = (tone as usize, semitone as usize);
+        Self::try_init(
+            tonic,
+            vec![tone, tone, semitone, tone, tone, tone, semitone],
+        )
+    }
+    /// Creates the scale made of every step of the division.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(19, 440.0), 0);
+    /// assert_eq!(EdoScale::chromatic(tonic).len(), 19);
+    /// ```
+    pub fn chromatic(tonic: EdoSound) -> Self {
+        Self::init(tonic, vec![1; tonic.edo.divisions])
+    }
+    /// Creates the scale of an equal division matching a scale, its tonic being in `octave`.
+    /// # Panics
+    /// It panics when the octave is outside the pitch space or when two consecutive notes
+    /// of the scale fall on the same step of the division (e.g. E and F in 5-EDO).
+    /// ```
+    /// use music::{Edo, EdoScale, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// let edo_scale = EdoScale::from_scale(Edo::init(31, 440.0), &scale, 4);
+    /// assert_eq!(edo_scale.steps(), &[5, 3, 5, 5, 3, 7, 3]);
+    /// ```
+    pub fn from_scale(edo: Edo, scale: &Scale, octave: isize) -> Self {
+        Self::try_from_scale(edo, scale, octave).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates the scale of an equal division matching a scale, its tonic being in `octave`
+    /// (see [`Scale::try_edo_steps`]), failing when the octave is outside the pitch space
+    /// or when two consecutive notes of the scale fall on the same step of the division.
+    /// ```
+    /// use music::{Edo, EdoScale, MusicError, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// assert!(EdoScale::try_from_scale(Edo::init(19, 440.0), &scale, 4).is_ok());
+    /// assert_eq!(
+    ///     EdoScale::try_from_scale(Edo::init(5, 440.0), &scale, 4),
+    ///     Err(MusicError::InvalidScaleSteps)
+    /// );
+    /// ```
+    pub fn try_from_scale(edo: Edo, scale: &Scale, octave: isize) -> Result<Self, MusicError> {
+        let steps = scale.try_edo_steps(edo)?;
+        let tonic = Sound::try_init_spelled(SpelledNote::from(scale.tonic()), octave)?;
+        Self::try_init(EdoSound::from_sound(edo, &tonic), steps)
+    }
+    /// Gives the tonic of the scale.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(19, 440.0), 5);
+    /// assert_eq!(EdoScale::major(tonic).tonic(), tonic);
+    /// ```
+    pub fn tonic(&self) -> EdoSound {
+        self.tonic
+    }
+    /// Gives the steps between consecutive degrees of the scale.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(12, 440.0), 0);
+    /// assert_eq!(EdoScale::major(tonic).steps(), &[2, 2, 1, 2, 2, 2, 1]);
+    /// ```
+    pub fn steps(&self) -> &[usize] {
+        &self.steps
+    }
+    /// Gives the number of degrees of the scale.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(31, 440.0), 0);
+    /// assert_eq!(EdoScale::major(tonic).len(), 7);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+    /// Gives the degrees of the scale as numbers of steps from C,
+    /// the counterpart of `Scale::usize_notes`.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(12, 440.0), 9);
+    /// assert_eq!(EdoScale::major(tonic).degrees(), vec![9, 11, 1, 2, 4, 6, 8]);
+    /// ```
+    pub fn degrees(&self) -> Vec<usize> {
+        self.sounds().iter().map(EdoSound::degree).collect()
+    }
+    /// Gives the sounds of the scale, from the tonic upwards.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let tonic = EdoSound::init(Edo::init(24, 440.0), 0);
+    /// let scale = EdoScale::init(tonic, vec![7, 7, 10]);
+    /// assert_eq!(scale.sounds()[2], tonic.shift(14));
+    /// ```
+    pub fn sounds(&self) -> Vec<EdoSound> {
+        let mut sound = self.tonic;
+        let mut sounds = Vec::with_capacity(self.steps.len());
+        for step in self.steps.iter() {
+            sounds.push(sound);
+            sound = sound.shift(*step as isize);
+        }
+        sounds
+    }
+    /// Tests whether or not a sound of the same division belongs to the scale, in any octave.
+    /// ```
+    /// use music::{Edo, EdoScale, EdoSound};
+    /// let edo = Edo::init(31, 440.0);
+    /// let scale = EdoScale::major(EdoSound::init(edo, 0));
+    /// assert!(scale.contains(&EdoSound::init(edo, 31 + 10)));
+    /// assert!(!scale.contains(&EdoSound::init(edo, 11)));
+    /// ```
+    pub fn contains(&self, sound: &EdoSound) -> bool {
+        sound.edo == self.tonic.edo && self.degrees().contains(&sound.degree())
+    }
+}
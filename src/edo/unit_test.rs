@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{Edo, EdoInterval, EdoScale, EdoSound};
+    use crate::tuning::{EqualTemperament, RegularTemperament, Tuning};
+    use crate::{Interval, MusicError, Note, Scale, ScaleType, Sound};
+
+    #[test]
+    fn test_twelve_edo() {
+        let edo = Edo::default();
+        let equal = EqualTemperament::default();
+        for range in -30..100 {
+            let sound = Sound::from_range(range);
+            let edo_sound = EdoSound::from_sound(edo, &sound);
+            assert_eq!(edo_sound.steps(), sound.range());
+            assert_eq!(edo_sound.octave(), sound.octave());
+            assert_eq!(edo_sound.degree(), sound.note().to_usize());
+            assert!((edo.frequency(&sound) - equal.frequency(&sound)).abs() < 1e-9);
+        }
+        let scale = Scale::init(Note::D, ScaleType::Major);
+        assert_eq!(
+            EdoScale::from_scale(edo, &scale, 3).degrees(),
+            scale.usize_notes()
+        );
+    }
+    #[test]
+    fn test_microtonal_edo() {
+        let edo = Edo::init(31, 440.0);
+        let meantone = RegularTemperament::quarter_comma_meantone(440.0);
+        let c_4 = Sound::init(Note::C, 4);
+        for note in [
+            Note::Cs,
+            Note::Db,
+            Note::E,
+            Note::Fs,
+            Note::Gb,
+            Note::A,
+            Note::Bb,
+        ] {
+            let sound = Sound::init(note, 4);
+            let cents = Interval::init(c_4, sound).cents(&edo);
+            assert!((cents - Interval::init(c_4, sound).cents(&meantone)).abs() < 6.0);
+        }
+        let quarter_tones = Edo::init(24, 440.0);
+        let e_4 = EdoSound::from_sound(quarter_tones, &Sound::init(Note::E, 4));
+        let half_flat = e_4.shift(-1);
+        assert_eq!(half_flat.octave(), 4);
+        assert_eq!(half_flat.degree(), 7);
+        let c_4 = EdoSound::from_sound(quarter_tones, &c_4);
+        let neutral_third = EdoInterval::init(c_4, half_flat);
+        assert_eq!(neutral_third.distance(), 7);
+        assert_eq!(neutral_third.cents(), 350.0);
+        let detuned = EdoInterval::init(c_4, half_flat.with_offset(-10.0));
+        assert_eq!(detuned.cents(), 340.0);
+        assert_eq!(detuned.distance(), 7);
+    }
+    #[test]
+    fn test_edo_scales() {
+        let edo = Edo::init(53, 440.0);
+        let tonic = EdoSound::init(edo, 53 * 3);
+        let major = EdoScale::major(tonic);
+        assert_eq!(major.steps(), &[9, 9, 4, 9, 9, 9, 4]);
+        assert_eq!(major.degrees(), vec![0, 9, 18, 22, 31, 40, 49]);
+        assert_eq!(major.sounds()[4].octave(), 4);
+        assert!(major.contains(&tonic.shift(-53 + 22)));
+        let scale = Scale::init(Note::Eb, ScaleType::Major);
+        let from_scale = EdoScale::from_scale(edo, &scale, 4);
+        assert_eq!(from_scale.steps(), major.steps());
+        assert_eq!(
+            from_scale.tonic(),
+            EdoSound::from_sound(edo, &Sound::init(Note::Eb, 4))
+        );
+        let other = EdoSound::init(Edo::init(12, 440.0), 0);
+        assert!(!major.contains(&other));
+        let chromatic = EdoScale::chromatic(tonic);
+        assert_eq!(chromatic.degrees(), (0..53).collect::<Vec<usize>>());
+        let pentatonic = Edo::init(5, 440.0);
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        assert_eq!(
+            EdoScale::try_from_scale(pentatonic, &scale, 4),
+            Err(MusicError::InvalidScaleSteps)
+        );
+        assert_eq!(
+            EdoScale::try_major(EdoSound::init(pentatonic, 0)),
+            Err(MusicError::InvalidScaleSteps)
+        );
+        assert_eq!(scale.edo_steps(edo), major.steps());
+        let third = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+        assert_eq!(third.edo_distance(edo), 18);
+    }
+}
@@ -0,0 +1,327 @@
+#[cfg(test)]
+mod unit_test;
+use std::fmt;
+
+use crate::{Note, Sound};
+
+/// Describes what went wrong when parsing a value from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Nothing to parse.
+    Empty,
+    /// The character is not a note letter (A to G).
+    InvalidLetter(char),
+    /// The character is not an accidental nor the start of an octave.
+    InvalidAccidental(char),
+    /// The spelling needs more accidentals than the target type can represent.
+    UnsupportedAccidental,
+    /// The octave of a sound is missing.
+    MissingOctave,
+    /// The octave of a sound is not an integer within the pitch space.
+    InvalidOctave,
+    /// The character is not an interval quality (P, M, m, A or d).
+    InvalidQuality(char),
+    /// The number of an interval is missing or is not a positive integer.
+    InvalidIntervalNumber,
+    /// The quality does not apply to the interval number, e.g. a major fifth.
+    QualityMismatch,
+    /// The character is not expected at this position.
+    UnexpectedCharacter(char),
+    /// The text is not a known chord quality nor a known chord alteration.
+    UnknownChordQuality,
+    /// The text is not a Roman numeral with a valid quality and inversion figure.
+    InvalidRomanNumeral,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Empty => write!(f, "empty input"),
+            ParseErrorKind::InvalidLetter(c) => write!(f, "invalid note letter '{c}'"),
+            ParseErrorKind::InvalidAccidental(c) => write!(f, "invalid accidental '{c}'"),
+            ParseErrorKind::UnsupportedAccidental => write!(f, "unsupported number of accidentals"),
+            ParseErrorKind::MissingOctave => write!(f, "missing octave"),
+            ParseErrorKind::InvalidOctave => write!(f, "invalid octave"),
+            ParseErrorKind::InvalidQuality(c) => write!(f, "invalid interval quality '{c}'"),
+            ParseErrorKind::InvalidIntervalNumber => write!(f, "invalid interval number"),
+            ParseErrorKind::QualityMismatch => {
+                write!(f, "quality does not apply to the interval number")
+            }
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{c}'"),
+            ParseErrorKind::UnknownChordQuality => write!(f, "unknown chord quality"),
+            ParseErrorKind::InvalidRomanNumeral => write!(f, "invalid Roman numeral"),
+        }
+    }
+}
+
+/// Error returned when parsing a value from text fails.
+///
+/// It keeps the whole input and the position (in characters)
+/// where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    input: String,
+    position: usize,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Creates a parsing error.
+    /// ```
+    /// use music::{ParseError, ParseErrorKind};
+    /// let error = ParseError::init("H4", 0, ParseErrorKind::InvalidLetter('H'));
+    /// assert_eq!(error.to_string(), "invalid note letter 'H' at position 0 in \"H4\"");
+    /// ```
+    pub fn init(input: &str, position: usize, kind: ParseErrorKind) -> Self {
+        Self {
+            input: input.to_string(),
+            position,
+            kind,
+        }
+    }
+    /// Gives the text that failed to be parsed.
+    /// ```
+    /// let error = "C4 X4".parse::<music::Chord>().unwrap_err();
+    /// assert_eq!(error.input(), "C4 X4");
+    /// ```
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Gives the position (in characters) where parsing failed.
+    /// ```
+    /// use music::Chord;
+    /// let error = "C4 E4 Gz4".parse::<Chord>().unwrap_err();
+    /// assert_eq!(error.position(), 7);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    /// Gives the reason why parsing failed.
+    /// ```
+    /// use music::{ParseErrorKind, Sound};
+    /// let error = "C".parse::<Sound>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::MissingOctave);
+    /// ```
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+    /// Moves the error into a larger input, the current input starting at `offset`.
+    pub(crate) fn within(self, input: &str, offset: usize) -> Self {
+        Self::init(input, self.position + offset, self.kind)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at position {} in \"{}\"",
+            self.kind, self.position, self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Describes what is wrong in a Scala scale (.scl) or keyboard mapping (.kbm) file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalaErrorKind {
+    /// The file ends before all the expected lines.
+    MissingLine,
+    /// The number of notes of a scale is not a positive integer.
+    InvalidNoteCount,
+    /// The pitch is neither cents (with a '.') nor a positive ratio.
+    InvalidPitch,
+    /// The field is not an integer, or is not a MIDI note number (0 to 127) for keys.
+    InvalidInteger,
+    /// The first key to retune is after the last one.
+    InvalidKeyRange,
+    /// The reference frequency is not a finite positive number of hertz.
+    InvalidFrequency,
+    /// The entry of the mapping is neither a scale degree nor 'x'.
+    InvalidMapping,
+    /// The reference key is mapped to no scale degree.
+    UnmappedReference,
+}
+
+impl fmt::Display for ScalaErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScalaErrorKind::MissingLine => write!(f, "missing line"),
+            ScalaErrorKind::InvalidNoteCount => write!(f, "invalid number of notes"),
+            ScalaErrorKind::InvalidPitch => write!(f, "invalid pitch"),
+            ScalaErrorKind::InvalidInteger => write!(f, "invalid integer"),
+            ScalaErrorKind::InvalidKeyRange => write!(f, "first key after last key"),
+            ScalaErrorKind::InvalidFrequency => write!(f, "invalid reference frequency"),
+            ScalaErrorKind::InvalidMapping => write!(f, "invalid mapping entry"),
+            ScalaErrorKind::UnmappedReference => write!(f, "unmapped reference key"),
+        }
+    }
+}
+
+/// Error returned when reading a Scala file fails.
+///
+/// It keeps the line (counted from 1, comments included) where reading failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalaError {
+    line: usize,
+    kind: ScalaErrorKind,
+}
+
+impl ScalaError {
+    /// Creates a Scala file error.
+    /// ```
+    /// use music::{ScalaError, ScalaErrorKind};
+    /// let error = ScalaError::init(3, ScalaErrorKind::InvalidPitch);
+    /// assert_eq!(error.to_string(), "invalid pitch at line 3");
+    /// ```
+    pub fn init(line: usize, kind: ScalaErrorKind) -> Self {
+        Self { line, kind }
+    }
+    /// Gives the line (counted from 1) where reading failed.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let error = "! comment\nExample\n2\n100.0\n".parse::<ScalaScale>().unwrap_err();
+    /// assert_eq!(error.line(), 5);
+    /// ```
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    /// Gives the reason why reading failed.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// use music::ScalaErrorKind;
+    /// let error = "Example\n1\n3/-2\n".parse::<ScalaScale>().unwrap_err();
+    /// assert_eq!(error.kind(), ScalaErrorKind::InvalidPitch);
+    /// ```
+    pub fn kind(&self) -> ScalaErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ScalaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at line {}", self.kind, self.line)
+    }
+}
+
+impl std::error::Error for ScalaError {}
+
+/// Error returned by the fallible operations of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MusicError {
+    /// An accidental must be made of at least one symbol.
+    NoAccidental,
+    /// The octave of a sound is outside the pitch space.
+    InvalidOctave(isize),
+    /// The sound would be lower than the lowest sound of the pitch space.
+    BelowLowestSound,
+    /// The sound would be higher than the highest sound of the pitch space.
+    AboveHighestSound,
+    /// The sound has no MIDI note number, or the number is greater than 127.
+    OutOfMidiRange,
+    /// The frequency is not a finite positive number of hertz.
+    InvalidFrequency,
+    /// The chord does not contain any sound.
+    EmptyChord,
+    /// The note does not belong to the scale.
+    NoteNotInScale(Note),
+    /// The sound does not belong to the scale.
+    SoundNotInScale(Sound),
+    /// The spelling needs more accidentals than a spelled note can represent.
+    TooManyAccidentals,
+    /// The number of an interval must be positive.
+    InvalidIntervalNumber,
+    /// The quality does not apply to the interval number, e.g. a major fifth.
+    QualityMismatch,
+    /// The tuning gives no frequency to the sound.
+    UnmappedSound(Sound),
+    /// A Scala scale needs at least one pitch.
+    EmptyScale,
+    /// An equal division of the octave needs at least one step.
+    InvalidDivisions,
+    /// The sounds belong to different equal divisions of the octave.
+    EdoMismatch,
+    /// The steps of a scale must be positive and add up to an octave.
+    InvalidScaleSteps,
+    /// The pitch classes of a scale must be distinct, below 12 and include the tonic.
+    InvalidPitchClassSet,
+    /// Degrees of a scale start at 1 for the tonic.
+    InvalidDegree,
+    /// The chord has fewer sounds than the operation needs.
+    TooFewSounds,
+    /// The chord has more distinct notes than there are voices.
+    TooManyNotes,
+    /// The chord is not recognized as any known chord quality.
+    UnidentifiedChord,
+    /// No octave of the sound lies within the requested range.
+    NotWithinRange(Sound),
+    /// No voicing of the chord fits the range of the voices.
+    NoVoicing,
+    /// The chords do not all have the same number of voices.
+    VoiceCountMismatch,
+    /// The text could not be parsed.
+    Parse(ParseError),
+    /// The Scala file could not be read.
+    Scala(ScalaError),
+}
+
+impl fmt::Display for MusicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MusicError::NoAccidental => write!(f, "accidental without any symbol"),
+            MusicError::InvalidOctave(octave) => write!(f, "invalid octave {octave}"),
+            MusicError::BelowLowestSound => write!(f, "sound lower than the lowest sound"),
+            MusicError::AboveHighestSound => write!(f, "sound higher than the highest sound"),
+            MusicError::OutOfMidiRange => write!(f, "outside the MIDI range"),
+            MusicError::InvalidFrequency => write!(f, "invalid frequency"),
+            MusicError::EmptyChord => write!(f, "chord is empty"),
+            MusicError::NoteNotInScale(note) => write!(f, "note {note} is not in the scale"),
+            MusicError::SoundNotInScale(sound) => write!(f, "sound {sound} is not in the scale"),
+            MusicError::TooManyAccidentals => write!(f, "too many accidentals"),
+            MusicError::InvalidIntervalNumber => write!(f, "invalid interval number"),
+            MusicError::QualityMismatch => {
+                write!(f, "quality does not apply to the interval number")
+            }
+            MusicError::UnmappedSound(sound) => write!(f, "sound {sound} is not tuned"),
+            MusicError::EmptyScale => write!(f, "scale without any pitch"),
+            MusicError::InvalidDivisions => write!(f, "octave divided into zero steps"),
+            MusicError::EdoMismatch => write!(f, "different divisions of the octave"),
+            MusicError::InvalidScaleSteps => write!(f, "scale steps do not add up to an octave"),
+            MusicError::InvalidPitchClassSet => write!(f, "invalid set of pitch classes"),
+            MusicError::InvalidDegree => write!(f, "degrees start at 1"),
+            MusicError::TooFewSounds => write!(f, "chord has too few sounds"),
+            MusicError::TooManyNotes => write!(f, "chord has more notes than there are voices"),
+            MusicError::UnidentifiedChord => write!(f, "chord is not recognized"),
+            MusicError::NotWithinRange(sound) => {
+                write!(f, "no octave of sound {sound} is within the range")
+            }
+            MusicError::NoVoicing => write!(f, "no voicing of the chord fits the range"),
+            MusicError::VoiceCountMismatch => write!(f, "chords with different numbers of voices"),
+            MusicError::Parse(error) => write!(f, "{error}"),
+            MusicError::Scala(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for MusicError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MusicError::Parse(error) => Some(error),
+            MusicError::Scala(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for MusicError {
+    fn from(error: ParseError) -> Self {
+        MusicError::Parse(error)
+    }
+}
+
+impl From<ScalaError> for MusicError {
+    fn from(error: ScalaError) -> Self {
+        MusicError::Scala(error)
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use super::super::ParseErrorKind;
+    use crate::{Chord, Interval, Letter, Note, Sound, SpelledNote};
+
+    #[test]
+    fn test_parse() {
+        assert!("b".parse::<Note>().unwrap().strict_eq(&Note::B));
+        assert!("bb".parse::<Note>().unwrap().strict_eq(&Note::Bb));
+        assert_eq!(
+            "G𝄫".parse::<SpelledNote>(),
+            Ok(SpelledNote::init(Letter::G, -2))
+        );
+        let sound = "B#3".parse::<Sound>().unwrap();
+        assert!(sound.strict_eq(&Sound::init(Note::Bs, 3)));
+        let interval = "AA4".parse::<Interval>().unwrap();
+        assert_eq!(interval.distance(), 7);
+        assert_eq!(
+            interval.second_sound().spelled_note(),
+            SpelledNote::init(Letter::F, 2)
+        );
+        let interval = "d7".parse::<Interval>().unwrap();
+        assert_eq!(interval.distance(), 9);
+        let interval = "M10".parse::<Interval>().unwrap();
+        assert!(interval.second_sound().strict_eq(&Sound::init(Note::E, 5)));
+        let chord = " C4  E♭4 G4 ".parse::<Chord>().unwrap();
+        assert_eq!(chord.len(), 3);
+    }
+    #[test]
+    fn test_parse_errors() {
+        let error = "H".parse::<Note>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidLetter('H'));
+        let error = "C##".parse::<Note>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::UnsupportedAccidental);
+        let error = "C#z4".parse::<Sound>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidAccidental('z'));
+        assert_eq!(error.position(), 2);
+        let error = "C#500".parse::<Sound>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidOctave);
+        let error = "Cb-128".parse::<Sound>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidOctave);
+        let error = "C-".parse::<Sound>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidOctave);
+        let error = "m5".parse::<Interval>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::QualityMismatch);
+        let error = "Mm3".parse::<Interval>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidQuality('m'));
+        assert_eq!(error.position(), 1);
+        let error = "P0".parse::<Interval>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidIntervalNumber);
+        let error = "C4 - E♭z".parse::<Interval>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidAccidental('z'));
+        assert_eq!(error.position(), 7);
+        let error = "C4 E♭4 G".parse::<Chord>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::MissingOctave);
+        assert_eq!(error.position(), 8);
+        assert_eq!(
+            error.to_string(),
+            "missing octave at position 8 in \"C4 E♭4 G\""
+        );
+        let error = "   ".parse::<Chord>().unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Empty);
+    }
+}
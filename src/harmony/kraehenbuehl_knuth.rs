@@ -2,7 +2,7 @@
 
 use std::collections::HashSet;
 
-use crate::{Chord, Note, Scale, Sound};
+use crate::{Chord, DegreeName, MusicError, Note, Scale, Sound, SpelledNote, Stacking, Transpose};
 
 use rand::prelude::*;
 use rand_chacha::rand_core::SeedableRng;
@@ -12,7 +12,6 @@ pub struct KraehenbuehlKnuth {
     scale: Scale,
     next_position: u8,
     seed: u64,
-    scale_range: Vec<Sound>,
 }
 
 impl KraehenbuehlKnuth {
@@ -23,7 +22,6 @@ impl KraehenbuehlKnuth {
                 next_position: 0,
                 scale: _scale,
                 seed: 0,
-                scale_range: Vec::new(),
             }
         } else {
             let scale = Self::find_scale(melody.clone());
@@ -32,7 +30,6 @@ impl KraehenbuehlKnuth {
                 next_position: 0,
                 scale,
                 seed: 0,
-                scale_range: Vec::new(),
             }
         }
     }
@@ -64,77 +61,112 @@ impl KraehenbuehlKnuth {
         Scale::default()
     }
 
+    /// Gives the sound `number` steps of the scale below a sound of the scale.
+    /// # Panics
+    /// It panics when the sound is not in the scale or the result is below the tonic
+    /// of the first octave.
+    /// ```
+    /// use music::{KraehenbuehlKnuth, Note, Scale, ScaleType, Sound};
+    /// let scale = Scale::init(Note::F, ScaleType::Major);
+    /// let harmonizer = KraehenbuehlKnuth::init(vec![], Some(scale));
+    /// let below = harmonizer.sound_below(Sound::init(Note::C, 5), 3);
+    /// assert!(below.strict_eq(&Sound::init(Note::G, 4)));
+    /// ```
     pub fn sound_below(&self, sound: Sound, number: usize) -> Sound {
-        let sound_position = self
-            .scale_range
-            .binary_search(&sound)
-            .expect("Failed to find sound");
-        self.scale_range[sound_position - number]
+        self.try_sound_below(sound, number)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
-    fn adjust_bass(&mut self, chord: &mut [Sound]) {
-        let bass_note = chord[0].note();
-        let scale_notes = self.scale.notes();
-        let leading_tone = scale_notes[scale_notes.len() - 1];
-        if bass_note == leading_tone {
-            chord[0] = self.sound_below(chord[0], 2);
+    /// Gives the sound `number` steps of the scale below a sound of the scale
+    /// (see [`Transpose::try_transpose_diatonic`]), failing when the sound is not in
+    /// the scale or the result is below the tonic of the first octave.
+    /// ```
+    /// use music::{KraehenbuehlKnuth, MusicError, Note, Scale, ScaleType, Sound};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let harmonizer = KraehenbuehlKnuth::init(vec![], Some(scale));
+    /// let sound = Sound::init(Note::D, 1);
+    /// assert_eq!(harmonizer.try_sound_below(sound, 1), Ok(Sound::init(Note::C, 1)));
+    /// assert_eq!(harmonizer.try_sound_below(sound, 2), Err(MusicError::BelowLowestSound));
+    /// ```
+    pub fn try_sound_below(&self, sound: Sound, number: usize) -> Result<Sound, MusicError> {
+        let below = sound.try_transpose_diatonic(-(number as isize), &self.scale)?;
+        if below < Sound::init(self.scale.tonic(), 1) {
+            return Err(MusicError::BelowLowestSound);
         }
+        Ok(below)
     }
 
-    fn get_chord(&mut self, sound: Sound, random_number: u8) -> Chord {
-        let mut chord = vec![
-            self.sound_below(sound, 11),
-            self.sound_below(sound, 4),
-            self.sound_below(sound, 2),
-            sound,
-        ];
-        if self.next_position == 1 {
-            chord = vec![
-                self.sound_below(sound, 7),
-                self.sound_below(sound, 5),
-                self.sound_below(sound, 3),
-                sound,
-            ];
-        } else if self.next_position == 2 {
-            chord = vec![
-                self.sound_below(sound, 9),
-                self.sound_below(sound, 5),
-                self.sound_below(sound, 2),
-                sound,
-            ];
+    fn adjust_bass(&mut self, chord: &mut [Sound]) -> Result<(), MusicError> {
+        let degree = self.scale.degree_of(chord[0].note());
+        if degree.and_then(|degree| self.scale.degree_name(degree)) == Some(DegreeName::LeadingTone)
+        {
+            chord[0] = self.try_sound_below(chord[0], 2)?;
         }
-        self.adjust_bass(&mut chord);
-        self.next_position = (self.next_position + 1 + 2 * random_number) % 3;
-        Chord::from_vec(chord)
+        Ok(())
     }
 
-    fn initialise_range(&mut self) {
-        self.scale_range = self.scale.sounds(1);
-        self.scale_range.extend_from_slice(&self.scale.sounds(2));
-        self.scale_range.extend_from_slice(&self.scale.sounds(3));
-        self.scale_range.extend_from_slice(&self.scale.sounds(4));
-        self.scale_range.extend_from_slice(&self.scale.sounds(5));
+    /// Gives the highest sound of a note strictly below a sound, failing when it is below
+    /// the tonic of the first octave.
+    fn try_note_below(&self, note: SpelledNote, sound: Sound) -> Result<Sound, MusicError> {
+        let distance = (sound.range() - note.to_usize() as isize - 1).rem_euclid(12) + 1;
+        let range = sound.range() - distance;
+        Sound::try_from_range(range)?;
+        let below = Sound::from_range_spelled(range, note);
+        if below < Sound::init(self.scale.tonic(), 1) {
+            return Err(MusicError::BelowLowestSound);
+        }
+        Ok(below)
     }
 
-    fn add_octaves(&mut self, octave: usize) {
-        let largest_octave = self.scale_range[self.scale_range.len() - 1].octave();
-        if octave > largest_octave {
-            for oct in largest_octave + 1..octave + 1 {
-                self.scale_range.extend_from_slice(&self.scale.sounds(oct));
-            }
-        }
+    /// Builds the triad of the scale (see [`Scale::try_diatonic_chord`]) whose root, third
+    /// or fifth, according to the position, is the melody sound. Its other tones lie in the
+    /// octave below the melody, and its root is doubled below them in the bass.
+    fn get_chord(&mut self, sound: Sound, random_number: u8) -> Result<Chord, MusicError> {
+        // Index in the triad of the tone in the melody.
+        let tone = match self.next_position {
+            1 => 0,
+            2 => 1,
+            _ => 2,
+        };
+        let degree = self
+            .scale
+            .degree_of(sound.note())
+            .ok_or(MusicError::SoundNotInScale(sound))?;
+        let len = self.scale.spelled_notes().len();
+        let root = (degree - 1 + len - Stacking::Triad.steps()[tone] % len) % len + 1;
+        let triad = self.scale.try_diatonic_chord(root, Stacking::Triad, 4)?;
+        let notes: Vec<SpelledNote> = triad
+            .chord()
+            .sounds()
+            .iter()
+            .map(Sound::spelled_note)
+            .collect();
+        let mut chord = notes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != tone)
+            .map(|(_, note)| self.try_note_below(*note, sound))
+            .collect::<Result<Vec<Sound>, MusicError>>()?;
+        chord.sort();
+        chord.insert(0, self.try_note_below(notes[0], chord[0])?);
+        chord.push(sound);
+        self.adjust_bass(&mut chord)?;
+        self.next_position = (self.next_position + 1 + 2 * random_number) % 3;
+        Ok(Chord::from_vec(chord))
     }
 
     pub fn harmonize(&mut self) -> Vec<Chord> {
-        self.initialise_range();
+        self.try_harmonize()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_harmonize(&mut self) -> Result<Vec<Chord>, MusicError> {
         let mut rng = ChaCha20Rng::seed_from_u64(self.seed);
-        // println!("{:?}", self.scale_range);
         let mut harmonics = Vec::with_capacity(self.melody.len());
-        for sound in self.melody.clone() {
-            self.add_octaves(sound.octave());
+        for sound in self.scale.spell_sounds(&self.melody) {
             let random_number = rng.gen_range(0..=1); // equal to 0 or 1.
-            harmonics.push(self.get_chord(sound, random_number));
+            harmonics.push(self.get_chord(sound, random_number)?);
         }
-        harmonics
+        Ok(harmonics)
     }
 }
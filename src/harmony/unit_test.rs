@@ -1,6 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use crate::{KraehenbuehlKnuth, Note, Scale, ScaleType, Sound};
+    use crate::{Chord, KraehenbuehlKnuth, MusicError, Note, Scale, ScaleType, Sound};
+
+    fn assert_harmonics(harmonics: &[Chord], expected: &[&str]) {
+        assert_eq!(harmonics.len(), expected.len());
+        for (chord, expected) in harmonics.iter().zip(expected) {
+            assert!(chord.strict_eq(&expected.parse::<Chord>().unwrap()));
+        }
+    }
 
     #[test]
     fn test_kraehenbuel_knuth_with_scale() {
@@ -35,19 +42,94 @@ mod tests {
             Sound::init(Note::Bb, 4),
         ];
         let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
-        // let mut harmonizer = KraehenbuehlKnuth::init(melody, None);
-        let harmonics: Vec<crate::Chord> = harmonizer.harmonize();
-        for chord in &harmonics {
-            println!("{:?}", chord);
-        }
-        // use std::collections::HashSet;
-        // let notes = melody
-        //     .iter()
-        //     .map(|sound| sound.note())
-        //     .collect::<HashSet<Note>>();
-        // println!("{:?}", notes);
-        // println!("{:?}", Note::C.major_scale_from_tonic());
-        // println!("{:?}", Note::D.major_scale_from_tonic());
-        // assert_eq!(Sound::init(Note::Cs, 4), Sound::init(Note::Db, 4));
+        assert_harmonics(
+            &harmonizer.harmonize(),
+            &[
+                "F3 F4 Ab4 C5",
+                "F3 F4 Ab4 C5",
+                "C4 Eb4 G4 C5",
+                "G3 D4 G4 Bb4",
+                "F3 C4 F4 Ab4",
+                "F3 F4 Ab4 C5",
+                "C3 C4 Eb4 G4",
+                "F3 F4 Ab4 C5",
+                "Eb4 G4 Bb4 Eb5",
+                "Ab3 Eb4 Ab4 C5",
+                "G3 D4 G4 Bb4",
+                "G3 D4 G4 Bb4",
+                "C3 C4 Eb4 G4",
+                "Ab3 C4 Eb4 Ab4",
+                "Ab3 Eb4 Ab4 C5",
+                "G3 D4 G4 Bb4",
+                "F3 F4 Ab4 C5",
+                "Bb3 D4 F4 Bb4",
+                "Ab3 Eb4 Ab4 C5",
+                "C4 G4 C5 Eb5",
+                "C4 G4 C5 Eb5",
+                "G3 D4 G4 Bb4",
+                "F3 F4 Ab4 C5",
+                // The leading tone D is replaced in the bass by the dominant.
+                "Bb2 D4 F4 Ab4",
+                "Ab3 C4 Eb4 Ab4",
+                "Ab3 Eb4 Ab4 C5",
+                "Eb3 Eb4 G4 Bb4",
+            ],
+        );
+    }
+    #[test]
+    fn test_kraehenbuel_knuth_with_subtonic() {
+        // The subtonic B♭ of the natural minor scale stays in the bass...
+        let melody = vec![
+            Sound::init(Note::F, 5),
+            Sound::init(Note::D, 5),
+            Sound::init(Note::Bb, 4),
+            Sound::init(Note::C, 5),
+        ];
+        let scale = Scale::init(Note::C, ScaleType::NaturalMinor);
+        let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
+        assert_harmonics(
+            &harmonizer.harmonize(),
+            &[
+                "Bb3 Bb4 D5 F5",
+                "G3 G4 Bb4 D5",
+                "Bb3 D4 F4 Bb4",
+                "Ab3 Eb4 Ab4 C5",
+            ],
+        );
+        // ...while the leading tone B of the harmonic minor scale is replaced by the dominant.
+        let melody = vec![
+            Sound::init(Note::F, 5),
+            Sound::init(Note::D, 5),
+            Sound::init(Note::B, 4),
+            Sound::init(Note::C, 5),
+        ];
+        let scale = Scale::init(Note::C, ScaleType::HarmonicMinor);
+        let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
+        assert_harmonics(
+            &harmonizer.harmonize(),
+            &[
+                "G3 B4 D5 F5",
+                "G3 G4 B4 D5",
+                "G3 D4 F4 B4",
+                "Ab3 Eb4 Ab4 C5",
+            ],
+        );
+    }
+    #[test]
+    fn test_kraehenbuel_knuth_errors() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        let melody = vec![Sound::init(Note::C, 5), Sound::init(Note::Cs, 5)];
+        let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
+        assert_eq!(
+            harmonizer.try_harmonize(),
+            Err(MusicError::SoundNotInScale(Sound::init(Note::Cs, 5)))
+        );
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        let melody = vec![Sound::init(Note::E, 1)];
+        let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
+        assert_eq!(
+            harmonizer.try_harmonize(),
+            Err(MusicError::BelowLowestSound)
+        );
     }
 }
@@ -1,7 +1,36 @@
+mod quality;
 #[cfg(test)]
 mod unit_test;
 
-use crate::Sound;
+use std::str::FromStr;
+
+pub use quality::Quality;
+
+use crate::tuning::{ratio_to_cents, Tuning};
+use crate::{Letter, MusicError, ParseError, ParseErrorKind, Sound, SpelledNote};
+
+/// Half tones from C to the natural notes C, D, E, F, G, A and B,
+/// which are also the sizes of the perfect and major simple intervals.
+pub(crate) const MAJOR_SEMITONES: [isize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Names of the interval numbers up to the double octave.
+const NUMBER_NAMES: [&str; 15] = [
+    "unison",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "octave",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+];
 
 /// Implements an interval between two sounds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +118,19 @@ impl Interval {
     pub fn is_in_unison(&self) -> bool {
         self.sound_one == self.sound_two
     }
+    /// Tests whether or not two intervals are made of sounds with the same spelling
+    /// and octave, contrary to `==` which only compares pitches.
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let c_4 = Sound::init(Note::C, 4);
+    /// let major_third = Interval::init(c_4, Sound::init(Note::E, 4));
+    /// let diminished_fourth = Interval::init(c_4, Sound::init(Note::Fb, 4));
+    /// assert_eq!(major_third, diminished_fourth);
+    /// assert!(!major_third.strict_eq(&diminished_fourth));
+    /// ```
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        self.sound_one.strict_eq(&other.sound_one) & self.sound_two.strict_eq(&other.sound_two)
+    }
     /// Computes the length of the interval in half tones.
     /// ```
     /// use music::Interval;
@@ -100,12 +142,343 @@ impl Interval {
     /// assert_eq!(interval.distance(), 19);
     /// ```
     pub fn distance(&self) -> usize {
-        if self.is_ascending() {
-            self.sound_two.range() - self.sound_one.range()
+        (self.sound_two.range() - self.sound_one.range()).unsigned_abs()
+    }
+    /// Creates the interval of a given quality and number above middle C (C4).
+    /// # Panics
+    /// It panics when `try_from_quality` fails.
+    /// ```
+    /// use music::{Interval, Note, Quality, Sound};
+    /// let interval = Interval::from_quality(Quality::Augmented(1), 4);
+    /// assert!(interval.second_sound().strict_eq(&Sound::init(Note::Fs, 4)));
+    /// let interval = Interval::from_quality(Quality::Minor, 10);
+    /// assert!(interval.second_sound().strict_eq(&Sound::init(Note::Eb, 5)));
+    /// ```
+    pub fn from_quality(quality: Quality, number: usize) -> Self {
+        Self::try_from_quality(quality, number).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates the interval of a given quality and number above middle C (C4),
+    /// failing when the number is zero or when the quality does not apply to it
+    /// (e.g. a major fifth).
+    /// ```
+    /// use music::{Interval, MusicError, Quality};
+    /// let interval = Interval::try_from_quality(Quality::Diminished(1), 7).unwrap();
+    /// assert_eq!(interval.distance(), 9);
+    /// assert_eq!(interval.short_name(), "d7");
+    /// assert_eq!(Interval::try_from_quality(Quality::Major, 5), Err(MusicError::QualityMismatch));
+    /// assert_eq!(
+    ///     Interval::try_from_quality(Quality::Perfect, 0),
+    ///     Err(MusicError::InvalidIntervalNumber)
+    /// );
+    /// ```
+    pub fn try_from_quality(quality: Quality, number: usize) -> Result<Self, MusicError> {
+        if number == 0 {
+            return Err(MusicError::InvalidIntervalNumber);
+        }
+        let simple = (number - 1) % 7;
+        let alteration = quality
+            .alteration(Self::is_perfect_number(number))
+            .ok_or(MusicError::QualityMismatch)?;
+        let semitones = MAJOR_SEMITONES[simple] + 12 * ((number - 1) / 7) as isize + alteration;
+        Self::try_from_size(number as isize - 1, semitones)
+    }
+    /// Creates the interval above middle C (C4) spanning a signed number of letters
+    /// and of half tones, failing when the second sound cannot be spelled.
+    pub(crate) fn try_from_size(steps: isize, semitones: isize) -> Result<Self, MusicError> {
+        let position = 4 * 7 + steps;
+        let letter = Letter::from_index(position.rem_euclid(7) as usize);
+        let octave = position.div_euclid(7);
+        let accidental = 3 * 12 + semitones - (octave - 1) * 12 - letter.to_usize() as isize;
+        let accidental = i8::try_from(accidental).map_err(|_| MusicError::TooManyAccidentals)?;
+        let sound_one = Sound::init_spelled(SpelledNote::init(Letter::C, 0), 4);
+        let sound_two = Sound::try_init_spelled(SpelledNote::init(letter, accidental), octave)?;
+        Ok(Self::init(sound_one, sound_two))
+    }
+    /// Tests whether or not intervals of this number are perfect rather than major or minor.
+    fn is_perfect_number(number: usize) -> bool {
+        [0, 3, 4].contains(&((number - 1) % 7))
+    }
+    /// Gives the signed number of letters from the first sound to the second one.
+    fn diatonic_steps(&self) -> isize {
+        let position =
+            |sound: &Sound| sound.octave() * 7 + sound.spelled_note().letter().index() as isize;
+        position(&self.sound_two) - position(&self.sound_one)
+    }
+    /// Tests whether or not the letter of the second sound is below the letter of the first one,
+    /// the half tones telling apart the unisons.
+    fn is_descending_spelled(&self) -> bool {
+        let steps = self.diatonic_steps();
+        steps < 0 || (steps == 0 && self.sound_two < self.sound_one)
+    }
+    /// Gives the number of letters and the signed number of half tones
+    /// of the interval, taken from its lowest letter.
+    fn oriented_size(&self) -> (usize, isize) {
+        let steps = self.diatonic_steps().unsigned_abs();
+        let semitones = self.sound_two.range() - self.sound_one.range();
+        if self.is_descending_spelled() {
+            (steps, -semitones)
         } else {
-            self.sound_one.range() - self.sound_two.range()
+            (steps, semitones)
         }
     }
+    /// Gives the diatonic number of the interval (1 for a unison, 3 for a third, 10 for a tenth...),
+    /// counting the letters from the lowest sound to the highest.
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::E, 4), Sound::init(Note::C, 4));
+    /// assert_eq!(interval.number(), 3);
+    /// let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::Fb, 4));
+    /// assert_eq!(interval.number(), 4);
+    /// let interval = Interval::init(Sound::init(Note::G, 2), Sound::init(Note::B, 3));
+    /// assert_eq!(interval.number(), 10);
+    /// ```
+    pub fn number(&self) -> usize {
+        self.oriented_size().0 + 1
+    }
+    /// Gives the quality of the interval according to the spelling of its sounds.
+    /// ```
+    /// use music::{Interval, Note, Quality, Sound};
+    /// let c_4 = Sound::init(Note::C, 4);
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::Eb, 4)).quality(), Quality::Minor);
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::Ds, 4)).quality(), Quality::Augmented(1));
+    /// assert_eq!(Interval::init(Sound::init(Note::Fs, 4), c_4).quality(), Quality::Augmented(1));
+    /// let c_5 = Sound::init(Note::C, 5);
+    /// assert_eq!(Interval::init(Sound::init(Note::Fs, 4), c_5).quality(), Quality::Diminished(1));
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::G, 5)).quality(), Quality::Perfect);
+    /// ```
+    pub fn quality(&self) -> Quality {
+        let (steps, semitones) = self.oriented_size();
+        let expected = MAJOR_SEMITONES[steps % 7] + 12 * (steps / 7) as isize;
+        Quality::from_alteration(semitones - expected, Self::is_perfect_number(steps + 1))
+    }
+    /// Tests whether or not the interval is larger than an octave.
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let c_4 = Sound::init(Note::C, 4);
+    /// assert!(Interval::init(c_4, Sound::init(Note::D, 5)).is_compound());
+    /// assert!(!Interval::init(c_4, Sound::init(Note::C, 5)).is_compound());
+    /// ```
+    pub fn is_compound(&self) -> bool {
+        self.number() > 8
+    }
+    /// Gives the number of the simple interval matching the interval,
+    /// removing octaves from compound intervals (a tenth gives a third).
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let c_4 = Sound::init(Note::C, 4);
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::E, 5)).simple_number(), 3);
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::C, 6)).simple_number(), 8);
+    /// assert_eq!(Interval::init(c_4, Sound::init(Note::A, 4)).simple_number(), 6);
+    /// ```
+    pub fn simple_number(&self) -> usize {
+        match self.number() {
+            number if number <= 8 => number,
+            number => (number - 2) % 7 + 2,
+        }
+    }
+    /// Gives the abbreviated name of the interval, e.g. "m3", "P5" or "AA4".
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::D, 3), Sound::init(Note::F, 4));
+    /// assert_eq!(interval.short_name(), "m10");
+    /// ```
+    pub fn short_name(&self) -> String {
+        format!("{}{}", self.quality().short_name(), self.number())
+    }
+    /// Gives the name of the interval, e.g. "minor third" or "augmented fourth".
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::F, 3), Sound::init(Note::B, 3));
+    /// assert_eq!(interval.long_name(), "augmented fourth");
+    /// let interval = Interval::init(Sound::init(Note::C, 3), Sound::init(Note::E, 4));
+    /// assert_eq!(interval.long_name(), "major tenth");
+    /// let interval = Interval::init(Sound::init(Note::C, 3), Sound::init(Note::D, 5));
+    /// assert_eq!(interval.long_name(), "major 16th");
+    /// ```
+    pub fn long_name(&self) -> String {
+        let number = self.number();
+        let name = match NUMBER_NAMES.get(number - 1) {
+            Some(name) => name.to_string(),
+            None => {
+                let suffix = match (number % 10, number % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{number}{suffix}")
+            }
+        };
+        format!("{} {name}", self.quality().long_name())
+    }
+    /// Adds the interval to a sound, spelling the result from the letters of the interval,
+    /// failing when the result is outside the pitch space.
+    /// A descending interval gives a lower sound.
+    /// ```
+    /// use music::{Interval, MusicError, Note, Quality, Sound};
+    /// let minor_third = Interval::from_quality(Quality::Minor, 3);
+    /// let sound = minor_third.try_add_sound(&Sound::init(Note::Fs, 4)).unwrap();
+    /// assert!(sound.strict_eq(&Sound::init(Note::A, 4)));
+    /// let sound = minor_third.try_add_sound(&Sound::init(Note::Gs, 2)).unwrap();
+    /// assert!(sound.strict_eq(&Sound::init(Note::B, 2)));
+    /// let highest = Sound::from_range(Sound::MAX_RANGE);
+    /// assert_eq!(minor_third.try_add_sound(&highest), Err(MusicError::AboveHighestSound));
+    /// ```
+    pub fn try_add_sound(&self, sound: &Sound) -> Result<Sound, MusicError> {
+        let semitones = self.sound_two.range() - self.sound_one.range();
+        let range = sound
+            .range()
+            .checked_add(semitones)
+            .ok_or(MusicError::AboveHighestSound)?;
+        Sound::try_from_range(range)?;
+        let note = sound.spelled_note();
+        let position = sound.octave() * 7 + note.letter().index() as isize + self.diatonic_steps();
+        let letter = Letter::from_index(position.rem_euclid(7) as usize);
+        let octave = position.div_euclid(7);
+        let accidental = range - (octave - 1) * 12 - letter.to_usize() as isize;
+        let accidental = i8::try_from(accidental).map_err(|_| MusicError::TooManyAccidentals)?;
+        Sound::try_init_spelled(SpelledNote::init(letter, accidental), octave)
+    }
+    /// Subtracts the interval from a sound, spelling the result from the letters of the interval,
+    /// failing when the result is outside the pitch space.
+    /// ```
+    /// use music::{Interval, Note, Quality, Sound};
+    /// let major_third = Interval::from_quality(Quality::Major, 3);
+    /// let sound = major_third.try_sub_sound(&Sound::init(Note::C, 4)).unwrap();
+    /// assert!(sound.strict_eq(&Sound::init(Note::Ab, 3)));
+    /// ```
+    pub fn try_sub_sound(&self, sound: &Sound) -> Result<Sound, MusicError> {
+        self.reversed().try_add_sound(sound)
+    }
+    /// Adds an interval to the current one, the second sound being moved by `interval`,
+    /// failing when the result is outside the pitch space.
+    /// ```
+    /// use music::{Interval, Quality};
+    /// let major_third = Interval::from_quality(Quality::Major, 3);
+    /// let minor_third = Interval::from_quality(Quality::Minor, 3);
+    /// let fifth = major_third.try_add_interval(&minor_third).unwrap();
+    /// assert_eq!(fifth.short_name(), "P5");
+    /// ```
+    pub fn try_add_interval(&self, interval: &Interval) -> Result<Interval, MusicError> {
+        Ok(Self::init(
+            self.sound_one,
+            interval.try_add_sound(&self.sound_two)?,
+        ))
+    }
+    /// Subtracts an interval from the current one, the second sound being moved back by `interval`,
+    /// failing when the result is outside the pitch space.
+    /// ```
+    /// use music::{Interval, Quality};
+    /// let fifth = Interval::from_quality(Quality::Perfect, 5);
+    /// let major_third = Interval::from_quality(Quality::Major, 3);
+    /// let minor_third = fifth.try_sub_interval(&major_third).unwrap();
+    /// assert_eq!(minor_third.short_name(), "m3");
+    /// ```
+    pub fn try_sub_interval(&self, interval: &Interval) -> Result<Interval, MusicError> {
+        Ok(Self::init(
+            self.sound_one,
+            interval.try_sub_sound(&self.sound_two)?,
+        ))
+    }
+    /// Gives the interval going from the second sound to the first one.
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::G, 4));
+    /// assert_eq!(interval.reversed().first_sound(), Sound::init(Note::G, 4));
+    /// assert_eq!(interval.reversed().short_name(), "P5");
+    /// ```
+    pub fn reversed(&self) -> Interval {
+        Self::init(self.sound_two, self.sound_one)
+    }
+    /// Gives the simple interval matching a compound interval, the second sound
+    /// being moved by octaves towards the first one (a major tenth gives a major third).
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let tenth = Interval::init(Sound::init(Note::C, 3), Sound::init(Note::E, 5));
+    /// assert_eq!(tenth.reduce(), Interval::init(Sound::init(Note::C, 3), Sound::init(Note::E, 3)));
+    /// let octave = Interval::init(Sound::init(Note::C, 3), Sound::init(Note::C, 4));
+    /// assert_eq!(octave.reduce(), octave);
+    /// ```
+    pub fn reduce(&self) -> Interval {
+        let number = self.number();
+        if number <= 8 {
+            return *self;
+        }
+        let octaves = ((number - 2) / 7) as isize;
+        let octaves = if self.is_descending_spelled() {
+            -octaves
+        } else {
+            octaves
+        };
+        let sound_two = Sound::init_spelled(
+            self.sound_two.spelled_note(),
+            self.sound_two.octave() - octaves,
+        );
+        Self::init(self.sound_one, sound_two)
+    }
+    /// Gives the inversion of the interval within the octave, keeping its first sound and its direction
+    /// (a major third gives a minor sixth, a unison gives an octave).
+    /// Compound intervals are reduced first.
+    /// # Panics
+    /// It panics when `try_invert` fails.
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let major_third = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+    /// let minor_sixth = major_third.invert();
+    /// assert!(minor_sixth.second_sound().strict_eq(&Sound::init(Note::Ab, 4)));
+    /// let descending = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::A, 3));
+    /// assert!(descending.invert().second_sound().strict_eq(&Sound::init(Note::Eb, 3)));
+    /// ```
+    pub fn invert(&self) -> Interval {
+        self.try_invert().unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the inversion of the interval within the octave, keeping its first sound and its direction,
+    /// failing when the result is outside the pitch space.
+    /// ```
+    /// use music::{Interval, MusicError, Note, Sound};
+    /// let highest = Sound::from_range(Sound::MAX_RANGE);
+    /// let unison = Interval::init(highest, highest);
+    /// assert_eq!(unison.try_invert(), Err(MusicError::AboveHighestSound));
+    /// ```
+    pub fn try_invert(&self) -> Result<Interval, MusicError> {
+        let reduced = self.reduce();
+        let octave = Self::from_quality(Quality::Perfect, 8);
+        let octave = if self.is_descending_spelled() {
+            octave.reversed()
+        } else {
+            octave
+        };
+        let complement = Self::init(reduced.sound_two, octave.try_add_sound(&self.sound_one)?);
+        Ok(Self::init(
+            self.sound_one,
+            complement.try_add_sound(&self.sound_one)?,
+        ))
+    }
+    /// Computes the frequency ratio from the first sound to the second one in a tuning system.
+    /// ```
+    /// use music::tuning::JustIntonation;
+    /// use music::{Interval, Note, Sound};
+    /// let tuning = JustIntonation::init(Note::C, 440.0);
+    /// let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+    /// assert!((interval.ratio(&tuning) - 1.25).abs() < 1e-9);
+    /// ```
+    pub fn ratio<T: Tuning + ?Sized>(&self, tuning: &T) -> f64 {
+        self.sound_two.frequency(tuning) / self.sound_one.frequency(tuning)
+    }
+    /// Computes the size of the interval in cents in a tuning system,
+    /// it is negative when the second sound is the lowest.
+    /// ```
+    /// use music::tuning::{EqualTemperament, RegularTemperament};
+    /// use music::{Interval, Note, Sound};
+    /// let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+    /// assert!((interval.cents(&EqualTemperament::default()) - 400.0).abs() < 1e-9);
+    /// let meantone = RegularTemperament::quarter_comma_meantone(440.0);
+    /// assert!((interval.cents(&meantone) - 386.3137).abs() < 1e-4);
+    /// ```
+    pub fn cents<T: Tuning + ?Sized>(&self, tuning: &T) -> f64 {
+        ratio_to_cents(self.ratio(tuning))
+    }
 }
 
 impl PartialOrd for Interval {
@@ -118,3 +491,87 @@ impl Ord for Interval {
         self.distance().cmp(&other.distance())
     }
 }
+
+impl FromStr for Interval {
+    type Err = ParseError;
+    /// Parses either two sounds separated by whitespace or by a '-' right before the letter
+    /// of the second sound, e.g. "C4 E4" or "C4-E4" (a '-' before a digit being the sign
+    /// of a negative octave, as in "A-1 C0"),
+    /// or an interval name made of a quality (P, M, m, A, d, AA, dd, ...) and a number,
+    /// e.g. "m3" or "P5", which is then built above middle C (C4).
+    /// ```
+    /// use music::{Interval, Note, Sound};
+    /// let c_4 = Sound::init(Note::C, 4);
+    /// let minor_third = "m3".parse::<Interval>().unwrap();
+    /// assert!(minor_third.strict_eq(&Interval::init(c_4, Sound::init(Note::Eb, 4))));
+    /// assert_eq!("P5".parse(), Ok(Interval::init(c_4, Sound::init(Note::G, 4))));
+    /// assert_eq!("C4 G4".parse(), Ok(Interval::init(c_4, Sound::init(Note::G, 4))));
+    /// assert_eq!("A-1-C0".parse(), Ok(Interval::init(Sound::init(Note::A, -1), Sound::init(Note::C, 0))));
+    /// assert!("M5".parse::<Interval>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let separator = s.char_indices().find(|(index, c)| {
+            c.is_whitespace()
+                || *c == '-'
+                    && s[index + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(|next| next.is_alphabetic())
+        });
+        match separator {
+            None => parse_name(s),
+            Some((index, separator)) => {
+                let sound_one = s[..index]
+                    .parse::<Sound>()
+                    .map_err(|error| error.within(s, 0))?;
+                let rest = s[index + separator.len_utf8()..]
+                    .trim_start_matches(|c: char| c.is_whitespace() || c == '-');
+                let start = s.len() - rest.len();
+                let sound_two = rest
+                    .parse::<Sound>()
+                    .map_err(|error| error.within(s, s[..start].chars().count()))?;
+                Ok(Self::init(sound_one, sound_two))
+            }
+        }
+    }
+}
+
+/// Parses an interval name such as "m3" or "AA4" and builds it above C4.
+fn parse_name(s: &str) -> Result<Interval, ParseError> {
+    let error = |position, kind| Err(ParseError::init(s, position, kind));
+    let quality: String = s.chars().take_while(|c| c.is_alphabetic()).collect();
+    let read = quality.chars().count();
+    let kind = match quality.chars().next() {
+        Some(c) => c,
+        None => match s.chars().next() {
+            None => return error(0, ParseErrorKind::Empty),
+            Some(c) => return error(0, ParseErrorKind::InvalidQuality(c)),
+        },
+    };
+    let repeated = if "PMm".contains(kind) { 1 } else { read };
+    if let Some((position, c)) = quality
+        .chars()
+        .enumerate()
+        .find(|(position, c)| *c != kind || *position >= repeated)
+    {
+        return error(position, ParseErrorKind::InvalidQuality(c));
+    }
+    let count = read.min(u8::MAX as usize) as u8;
+    let quality = match kind {
+        'P' => Quality::Perfect,
+        'M' => Quality::Major,
+        'm' => Quality::Minor,
+        'A' => Quality::Augmented(count),
+        'd' => Quality::Diminished(count),
+        c => return error(0, ParseErrorKind::InvalidQuality(c)),
+    };
+    let number = match s[read..].parse::<usize>() {
+        Ok(number) if number > 0 => number,
+        _ => return error(read, ParseErrorKind::InvalidIntervalNumber),
+    };
+    match Interval::try_from_quality(quality, number) {
+        Ok(interval) => Ok(interval),
+        Err(MusicError::QualityMismatch) => error(0, ParseErrorKind::QualityMismatch),
+        Err(_) => error(read, ParseErrorKind::InvalidIntervalNumber),
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+/// Quality of an interval, telling apart intervals with the same number
+/// (e.g. the minor, major and augmented thirds).
+///
+/// Augmented and diminished qualities count how many times they apply,
+/// e.g. `Augmented(2)` for doubly augmented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quality {
+    Perfect,
+    Major,
+    Minor,
+    Augmented(u8),
+    Diminished(u8),
+}
+
+impl Quality {
+    /// Gives the quality of an interval from the difference (in half tones)
+    /// between its size and the size of the perfect or major interval with the same number.
+    pub(crate) fn from_alteration(alteration: isize, is_perfect: bool) -> Self {
+        let count = |alteration: isize| alteration.unsigned_abs().min(u8::MAX as usize) as u8;
+        match (alteration, is_perfect) {
+            (0, true) => Quality::Perfect,
+            (0, false) => Quality::Major,
+            (-1, false) => Quality::Minor,
+            (alteration, _) if alteration > 0 => Quality::Augmented(count(alteration)),
+            (alteration, true) => Quality::Diminished(count(alteration)),
+            (alteration, false) => Quality::Diminished(count(alteration + 1)),
+        }
+    }
+    /// Gives the difference (in half tones) between the size of an interval of this quality
+    /// and the size of the perfect or major interval with the same number,
+    /// `None` when the quality does not apply.
+    pub(crate) fn alteration(&self, is_perfect: bool) -> Option<isize> {
+        match (self, is_perfect) {
+            (Quality::Perfect, true) | (Quality::Major, false) => Some(0),
+            (Quality::Minor, false) => Some(-1),
+            (Quality::Augmented(0), _) | (Quality::Diminished(0), _) => None,
+            (Quality::Augmented(count), _) => Some(*count as isize),
+            (Quality::Diminished(count), true) => Some(-(*count as isize)),
+            (Quality::Diminished(count), false) => Some(-(*count as isize) - 1),
+            _ => None,
+        }
+    }
+    /// Gives the abbreviation of the quality (P, M, m, A, d, AA, dd, ...).
+    /// ```
+    /// use music::Quality;
+    /// assert_eq!(Quality::Minor.short_name(), "m");
+    /// assert_eq!(Quality::Augmented(2).short_name(), "AA");
+    /// ```
+    pub fn short_name(&self) -> String {
+        match self {
+            Quality::Perfect => "P".to_string(),
+            Quality::Major => "M".to_string(),
+            Quality::Minor => "m".to_string(),
+            Quality::Augmented(count) => "A".repeat(*count as usize),
+            Quality::Diminished(count) => "d".repeat(*count as usize),
+        }
+    }
+    /// Gives the name of the quality.
+    /// ```
+    /// use music::Quality;
+    /// assert_eq!(Quality::Perfect.long_name(), "perfect");
+    /// assert_eq!(Quality::Diminished(2).long_name(), "doubly diminished");
+    /// assert_eq!(Quality::Augmented(4).long_name(), "4 times augmented");
+    /// ```
+    pub fn long_name(&self) -> String {
+        let repeated = |count: u8, name: &str| match count {
+            1 => name.to_string(),
+            2 => format!("doubly {name}"),
+            3 => format!("triply {name}"),
+            count => format!("{count} times {name}"),
+        };
+        match self {
+            Quality::Perfect => "perfect".to_string(),
+            Quality::Major => "major".to_string(),
+            Quality::Minor => "minor".to_string(),
+            Quality::Augmented(count) => repeated(*count, "augmented"),
+            Quality::Diminished(count) => repeated(*count, "diminished"),
+        }
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.long_name())
+        } else {
+            write!(f, "{}", self.short_name())
+        }
+    }
+}
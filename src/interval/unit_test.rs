@@ -1,10 +1,12 @@
 #[cfg(test)]
+#[allow(clippy::op_ref)]
 mod tests {
-    use super::super::Interval;
+    use super::super::{Interval, Quality};
     use crate::{
         accidental::{Flat, Natural, Sharp},
         note::Note,
         sound::Sound,
+        MusicError, ParseErrorKind,
     };
 
     #[test]
@@ -39,4 +41,129 @@ mod tests {
         assert_eq!(interval + accidental, expected_interval);
         assert_eq!(&accidental + &interval, expected_interval);
     }
+    #[test]
+    fn test_interval_names() {
+        let names = [
+            "P1", "A1", "d2", "m2", "M2", "A2", "d3", "m3", "M3", "A3", "d4", "P4", "A4", "d5",
+            "P5", "A5", "d6", "m6", "M6", "A6", "d7", "m7", "M7", "A7", "d8", "P8", "A8", "m9",
+            "M10", "P11", "AA11", "dd12", "m13", "P15", "M16",
+        ];
+        for name in names {
+            let interval = name.parse::<Interval>().unwrap();
+            assert_eq!(interval.short_name(), name);
+            let rebuilt = Interval::from_quality(interval.quality(), interval.number());
+            assert!(rebuilt.strict_eq(&interval));
+            let reversed = Interval::init(interval.second_sound(), interval.first_sound());
+            assert_eq!(reversed.short_name(), name);
+        }
+        let interval = Interval::init(Sound::init(Note::Bs, 3), Sound::init(Note::C, 4));
+        assert_eq!(interval.long_name(), "diminished second");
+        assert_eq!(interval.distance(), 0);
+        let interval = Interval::init(Sound::init(Note::Cb, 4), Sound::init(Note::Bs, 5));
+        assert_eq!(interval.quality(), Quality::Augmented(2));
+        assert_eq!(interval.long_name(), "doubly augmented fourteenth");
+        assert!(interval.is_compound());
+        assert_eq!(interval.simple_number(), 7);
+        assert_eq!(
+            "ddd5".parse::<Interval>().unwrap().long_name(),
+            "triply diminished fifth"
+        );
+    }
+    #[test]
+    fn test_sound_interval_algebra() {
+        let major_sixth = Interval::from_quality(Quality::Major, 6);
+        let sound = Sound::init(Note::Eb, 3) + major_sixth;
+        assert!(sound.strict_eq(&Sound::init(Note::C, 4)));
+        let sound = &Sound::init(Note::Ds, 3) + &major_sixth;
+        assert!(sound.strict_eq(&Sound::init(Note::Bs, 3)));
+        let descending = Interval::init(Sound::init(Note::E, 4), Sound::init(Note::Cs, 3));
+        let sound = Sound::init(Note::Bb, 5) + descending;
+        assert!(sound.strict_eq(&Sound::init(Note::G, 4)));
+        let augmented_unison = Interval::from_quality(Quality::Augmented(1), 1);
+        let sound = Sound::init(Note::Bs, 3) + augmented_unison;
+        assert_eq!(sound.spelled_note().accidental(), 2);
+        assert_eq!(sound.octave(), 3);
+        let lowest = Sound::from_range(Sound::MIN_RANGE);
+        assert_eq!(
+            descending.try_add_sound(&lowest),
+            Err(MusicError::BelowLowestSound)
+        );
+    }
+    #[test]
+    fn test_interval_arithmetic() {
+        let c_4 = Sound::init(Note::C, 4);
+        let e_4 = Sound::init(Note::E, 4);
+        let g_4 = Sound::init(Note::G, 4);
+        let major_third = e_4 - c_4;
+        assert_eq!(major_third, Interval::init(c_4, e_4));
+        assert_eq!(&e_4 - &c_4, major_third);
+        assert!((c_4 + (e_4 - c_4)).strict_eq(&e_4));
+        let minor_third = g_4 - e_4;
+        let fifth = major_third + minor_third;
+        assert_eq!(fifth.short_name(), "P5");
+        assert!(fifth.second_sound().strict_eq(&g_4));
+        assert!((&fifth - &minor_third).strict_eq(&major_third));
+        assert!((fifth - major_third)
+            .second_sound()
+            .strict_eq(&Sound::init(Note::Eb, 4)));
+        let octave = fifth + Interval::from_quality(Quality::Perfect, 4);
+        assert_eq!(octave.short_name(), "P8");
+        assert!((g_4 - major_third).strict_eq(&Sound::init(Note::Eb, 4)));
+        assert!((&g_4 - &minor_third).strict_eq(&e_4));
+
+        let names = [
+            ("P1", "P8"),
+            ("m2", "M7"),
+            ("M3", "m6"),
+            ("A4", "d5"),
+            ("P5", "P4"),
+            ("AA6", "dd3"),
+        ];
+        for (name, inverted) in names {
+            let interval = name.parse::<Interval>().unwrap();
+            assert_eq!(interval.invert().short_name(), inverted);
+            assert_eq!(interval.invert().invert().short_name(), name);
+            assert_eq!(interval.reversed().invert().short_name(), inverted);
+            let descending = interval.number() > 1;
+            assert_eq!(interval.reversed().invert().is_ascending(), !descending);
+        }
+        let tenth = Interval::init(Sound::init(Note::Ab, 2), Sound::init(Note::C, 4));
+        assert_eq!(tenth.reduce().short_name(), "M3");
+        assert_eq!(tenth.invert().short_name(), "m6");
+        let descending = tenth.reversed().reduce();
+        assert!(descending
+            .second_sound()
+            .strict_eq(&Sound::init(Note::Ab, 3)));
+        let double_octave = "P15".parse::<Interval>().unwrap();
+        assert_eq!(double_octave.reduce().short_name(), "P8");
+        assert_eq!(double_octave.invert().short_name(), "P1");
+    }
+    #[test]
+    fn test_interval_parsing() {
+        let interval = Interval::init(Sound::init(Note::A, -1), Sound::init(Note::C, 0));
+        assert_eq!("A-1 C0".parse(), Ok(interval));
+        assert_eq!(
+            "C0 A-1".parse(),
+            Ok(Interval::init(
+                Sound::init(Note::C, 0),
+                Sound::init(Note::A, -1)
+            ))
+        );
+        let error = "X4 C4".parse::<Interval>().unwrap_err();
+        assert_eq!(error.input(), "X4 C4");
+        assert_eq!(error.position(), 0);
+        let error = "C4 E".parse::<Interval>().unwrap_err();
+        assert_eq!(error.input(), "C4 E");
+        assert_eq!(error.kind(), ParseErrorKind::MissingOctave);
+    }
+    #[test]
+    fn test_negative_octave_intervals() {
+        // A '-' right after a note letter is the sign of a negative octave.
+        let b_1 = Sound::init(Note::B, -1);
+        let c_0 = Sound::init(Note::C, 0);
+        assert_eq!("B-1-C0".parse(), Ok(Interval::init(b_1, c_0)));
+        assert_eq!("B-1 - C0".parse(), Ok(Interval::init(b_1, c_0)));
+        assert_eq!("C0-B-1".parse(), Ok(Interval::init(c_0, b_1)));
+        assert!(!"C0-B-1".parse::<Interval>().unwrap().is_ascending());
+    }
 }
@@ -1,16 +1,31 @@
 mod accidental;
 mod algebra;
 mod chord;
+mod consonance;
+mod edo;
+mod error;
 mod harmony;
 mod interval;
 mod note;
+mod roman;
 mod scale;
 mod sound;
+mod spelling;
+mod transpose;
+pub mod tuning;
+mod voice_leading;
 
 pub use accidental::{Flat, Natural, Sharp};
-pub use chord::Chord;
+pub use chord::{Chord, ChordCandidate, ChordQuality, ChordSymbol};
+pub use consonance::{Consonance, Timbre};
+pub use edo::{Edo, EdoInterval, EdoScale, EdoSound};
+pub use error::{MusicError, ParseError, ParseErrorKind, ScalaError, ScalaErrorKind};
 pub use harmony::KraehenbuehlKnuth;
-pub use interval::Interval;
+pub use interval::{Interval, Quality};
 pub use note::Note;
-pub use scale::{Scale, ScaleType};
-pub use sound::Sound;
+pub use roman::{AugmentedSixth, RomanNumeral};
+pub use scale::{DegreeName, DiatonicChord, PitchClassSet, Scale, ScaleType, Stacking};
+pub use sound::{PitchNotation, Sound};
+pub use spelling::{Letter, SpelledNote};
+pub use transpose::Transpose;
+pub use voice_leading::{Metric, Violation, VoiceLeading, VoiceLeadingChecker, VoiceLeadingRule};
@@ -2,6 +2,9 @@
 mod unit_test;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::{ParseError, ParseErrorKind, SpelledNote};
 
 /// Implementation of a note
 #[derive(Debug, Clone, Copy, Eq)]
@@ -108,6 +111,17 @@ impl Note {
             _ => panic!("Not implemented."),
         }
     }
+    /// Tests whether or not two notes have the same spelling,
+    /// contrary to `==` which only compares pitch classes.
+    /// ```
+    /// use music::Note;
+    /// assert_eq!(Note::Gs, Note::Ab);
+    /// assert!(!Note::Gs.strict_eq(&Note::Ab));
+    /// assert!(Note::Gs.strict_eq(&Note::Gs));
+    /// ```
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
     /// Gives the major scale whose tonic is the current note.
     pub fn major_scale_from_tonic(&self) -> Vec<usize> {
         [0, 2, 4, 5, 7, 9, 11]
@@ -115,7 +129,7 @@ impl Note {
             .map(|note| (note + self.to_usize()) % 12)
             .collect()
     }
-    /// Gives the minor scale whose tonic is the current note.
+    /// Gives the harmonic minor scale whose tonic is the current note.
     pub fn minor_scale_from_tonic(&self) -> Vec<usize> {
         [0, 2, 3, 5, 7, 8, 11]
             .iter()
@@ -226,3 +240,27 @@ impl fmt::Display for Note {
         }
     }
 }
+
+impl FromStr for Note {
+    type Err = ParseError;
+    /// Parses a letter followed by at most one sharp ('#' or '♯')
+    /// or flat ('b' or '♭'), use `SpelledNote` for more accidentals.
+    /// ```
+    /// use music::Note;
+    /// assert!("Bb".parse::<Note>().unwrap().strict_eq(&Note::Bb));
+    /// assert!("B♭".parse::<Note>().unwrap().strict_eq(&Note::Bb));
+    /// assert!("C#".parse::<Note>().unwrap().strict_eq(&Note::Cs));
+    /// assert!("Ebb".parse::<Note>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let note = s.parse::<SpelledNote>()?;
+        if note.accidental().abs() > 1 {
+            return Err(ParseError::init(
+                s,
+                1,
+                ParseErrorKind::UnsupportedAccidental,
+            ));
+        }
+        Ok(note.to_note())
+    }
+}
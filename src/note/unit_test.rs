@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::op_ref)]
 mod tests {
     use super::super::Note;
     use crate::accidental::{Flat, Natural, Sharp};
@@ -0,0 +1,901 @@
+#[cfg(test)]
+mod unit_test;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::scale::{spell_with_letter, CHROMATIC_STEPS};
+use crate::{
+    Chord, ChordQuality, ChordSymbol, Letter, MusicError, ParseError, ParseErrorKind, Scale,
+    ScaleType, Sound, SpelledNote,
+};
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// Qualities of the triads on each degree of the major and minor scales,
+/// as they are named when tonicized (`true` for major, `false` for minor, `None` for diminished).
+const MAJOR_TRIADS: [Option<bool>; 7] = [
+    Some(true),
+    Some(false),
+    Some(false),
+    Some(true),
+    Some(true),
+    Some(false),
+    None,
+];
+const MINOR_TRIADS: [Option<bool>; 7] = [
+    Some(false),
+    None,
+    Some(true),
+    Some(false),
+    Some(true),
+    Some(true),
+    Some(true),
+];
+
+/// Augmented sixth chord, built on the lowered sixth degree with the raised fourth degree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AugmentedSixth {
+    /// Lowered sixth, tonic and raised fourth degrees.
+    Italian,
+    /// Lowered sixth, tonic, second and raised fourth degrees.
+    French,
+    /// Lowered sixth, tonic, lowered third and raised fourth degrees.
+    German,
+}
+
+impl AugmentedSixth {
+    /// Gives the abbreviation of the chord used in Roman numeral analysis.
+    /// ```
+    /// use music::AugmentedSixth;
+    /// assert_eq!(AugmentedSixth::German.abbreviation(), "Ger");
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            AugmentedSixth::Italian => "It",
+            AugmentedSixth::French => "Fr",
+            AugmentedSixth::German => "Ger",
+        }
+    }
+    /// Gives the degrees of the chord as half tones above the tonic, from the bass.
+    pub(crate) fn semitones(&self) -> &'static [usize] {
+        match self {
+            AugmentedSixth::Italian => &[8, 0, 6],
+            AugmentedSixth::French => &[8, 0, 2, 6],
+            AugmentedSixth::German => &[8, 0, 3, 6],
+        }
+    }
+}
+
+/// Function of a chord in a key, written as a Roman numeral
+/// (e.g. I, ii6, V7, viiø7, ♭VI, V/V, N6 or Ger+6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RomanNumeral {
+    degree: usize,
+    alteration: i8,
+    quality: ChordQuality,
+    inversion: usize,
+    target: Option<(usize, bool)>,
+    augmented_sixth: Option<AugmentedSixth>,
+}
+
+impl RomanNumeral {
+    /// Creates the Roman numeral of a chord in root position on a degree of the scale (1 to 7).
+    /// # Panics
+    /// It panics when the degree is not between 1 and 7.
+    /// ```
+    /// use music::{ChordQuality, RomanNumeral};
+    /// let numeral = RomanNumeral::init(2, ChordQuality::MinorSeventh);
+    /// assert_eq!(numeral.to_string(), "ii7");
+    /// ```
+    pub fn init(degree: usize, quality: ChordQuality) -> Self {
+        assert!((1..=7).contains(&degree), "Invalid degree {degree}.");
+        Self {
+            degree,
+            alteration: 0,
+            quality,
+            inversion: 0,
+            target: None,
+            augmented_sixth: None,
+        }
+    }
+    /// Creates the Roman numeral of an augmented sixth chord.
+    /// ```
+    /// use music::{AugmentedSixth, RomanNumeral};
+    /// let numeral = RomanNumeral::from_augmented_sixth(AugmentedSixth::Italian);
+    /// assert_eq!(numeral.to_string(), "It+6");
+    /// ```
+    pub fn from_augmented_sixth(augmented_sixth: AugmentedSixth) -> Self {
+        let quality = match augmented_sixth {
+            AugmentedSixth::French => ChordQuality::SeventhFlatFifth,
+            _ => ChordQuality::DominantSeventh,
+        };
+        Self {
+            augmented_sixth: Some(augmented_sixth),
+            ..Self::init(6, quality)
+        }
+    }
+    /// Gives the same numeral with its root raised (positive) or lowered (negative)
+    /// from the degree of the scale.
+    /// ```
+    /// use music::{ChordQuality, RomanNumeral};
+    /// let numeral = RomanNumeral::init(6, ChordQuality::Major).with_alteration(-1);
+    /// assert_eq!(numeral.to_string(), "♭VI");
+    /// ```
+    pub fn with_alteration(mut self, alteration: i8) -> Self {
+        self.alteration = alteration;
+        self
+    }
+    /// Gives the same numeral with another sound of the chord in the bass
+    /// (0 for the root, 1 for the third, 2 for the fifth, 3 for the seventh).
+    /// ```
+    /// use music::{ChordQuality, RomanNumeral};
+    /// let numeral = RomanNumeral::init(5, ChordQuality::DominantSeventh).with_inversion(2);
+    /// assert_eq!(numeral.to_string(), "V43");
+    /// ```
+    pub fn with_inversion(mut self, inversion: usize) -> Self {
+        self.inversion = inversion;
+        self
+    }
+    /// Gives the same numeral applied to (tonicizing) the major or minor triad on another
+    /// degree of the scale (1 to 7), its degree being then counted in the key of that triad.
+    /// # Panics
+    /// It panics when the target is not between 1 and 7.
+    /// ```
+    /// use music::{ChordQuality, RomanNumeral};
+    /// let numeral = RomanNumeral::init(5, ChordQuality::DominantSeventh).with_target(2, false);
+    /// assert_eq!(numeral.to_string(), "V7/ii");
+    /// ```
+    pub fn with_target(mut self, target: usize, is_major: bool) -> Self {
+        assert!((1..=7).contains(&target), "Invalid target {target}.");
+        self.target = Some((target, is_major));
+        self
+    }
+    /// Gives the degree of the root (1 to 7), in the key of the target for applied chords.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = scale.roman_numeral(&"D3 F#3 A3".parse::<Chord>().unwrap());
+    /// assert_eq!(numeral.degree(), 5);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+    /// Gives the chromatic alteration of the root from the degree of the scale.
+    /// In minor keys, the natural forms of the sixth and seventh degrees are used for chords
+    /// with a major third and their raised forms for chords with a minor third.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = scale.roman_numeral(&"A♭3 C4 E♭4".parse::<Chord>().unwrap());
+    /// assert_eq!(numeral.alteration(), -1);
+    /// ```
+    pub fn alteration(&self) -> i8 {
+        self.alteration
+    }
+    /// Gives the quality of the chord.
+    /// For augmented sixth chords, it is the quality of the enharmonic chord
+    /// (seventh flat fifth for the French sixth, dominant seventh otherwise).
+    /// ```
+    /// use music::{ChordQuality, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = scale.roman_numeral(&"B3 D4 F4 A4".parse().unwrap());
+    /// assert_eq!(numeral.quality(), ChordQuality::HalfDiminishedSeventh);
+    /// ```
+    pub fn quality(&self) -> ChordQuality {
+        self.quality
+    }
+    /// Gives the sound of the chord in the bass
+    /// (0 for the root, 1 for the third, 2 for the fifth, 3 for the seventh).
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// assert_eq!(scale.roman_numeral(&"B2 D3 G3".parse().unwrap()).inversion(), 1);
+    /// ```
+    pub fn inversion(&self) -> usize {
+        self.inversion
+    }
+    /// Gives the degree tonicized by an applied chord.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// assert_eq!(scale.roman_numeral(&"E3 G#3 B3 D4".parse().unwrap()).target(), Some(6));
+    /// assert_eq!(scale.roman_numeral(&"G3 B3 D4 F4".parse().unwrap()).target(), None);
+    /// ```
+    pub fn target(&self) -> Option<usize> {
+        self.target.map(|(target, _)| target)
+    }
+    /// Gives the kind of augmented sixth chord, if it is one.
+    /// ```
+    /// use music::{AugmentedSixth, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = scale.roman_numeral(&"A♭3 C4 D4 F#4".parse().unwrap());
+    /// assert_eq!(numeral.augmented_sixth(), Some(AugmentedSixth::French));
+    /// ```
+    pub fn augmented_sixth(&self) -> Option<AugmentedSixth> {
+        self.augmented_sixth
+    }
+    /// Tests whether or not the chord is the Neapolitan chord,
+    /// the major triad on the lowered second degree.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// let numeral = scale.roman_numeral(&"D3 F3 B♭3".parse().unwrap());
+    /// assert!(numeral.is_neapolitan());
+    /// assert_eq!(numeral.to_string(), "N6");
+    /// ```
+    pub fn is_neapolitan(&self) -> bool {
+        self.degree == 2
+            && self.alteration == -1
+            && self.quality == ChordQuality::Major
+            && self.target.is_none()
+            && self.augmented_sixth.is_none()
+    }
+    /// Gives the figured bass of the inversion: "", "6" or "64" for triads,
+    /// "7", "65", "43" or "42" for seventh chords. Ninth, eleventh and thirteenth chords
+    /// are figured by their highest tone whatever their inversion.
+    /// ```
+    /// use music::{ChordQuality, RomanNumeral};
+    /// let numeral = RomanNumeral::init(1, ChordQuality::Major).with_inversion(2);
+    /// assert_eq!(numeral.figure(), "64");
+    /// assert_eq!(RomanNumeral::init(2, ChordQuality::MinorEleventh).figure(), "11");
+    /// ```
+    pub fn figure(&self) -> &'static str {
+        let highest = self.quality.tones().iter().map(|(number, _)| *number).max();
+        let figures: &[&str] = match highest {
+            Some(9) => &["9"],
+            Some(11) => &["11"],
+            Some(13) => &["13"],
+            Some(7) => &["7", "65", "43", "42"],
+            _ => &["", "6", "64"],
+        };
+        let inversion = if figures.len() == 1 {
+            0
+        } else {
+            self.inversion
+        };
+        figures.get(inversion).copied().unwrap_or("")
+    }
+    /// Tests whether or not the numeral is written in upper case, for chords with a major third.
+    fn is_upper_case(&self) -> bool {
+        has_major_third(self.quality)
+    }
+}
+
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(augmented_sixth) = self.augmented_sixth {
+            return write!(f, "{}+6", augmented_sixth.abbreviation());
+        }
+        if self.is_neapolitan() {
+            return write!(f, "N{}", self.figure());
+        }
+        let accidental = if self.alteration < 0 {
+            "♭".repeat(self.alteration.unsigned_abs() as usize)
+        } else {
+            "#".repeat(self.alteration as usize)
+        };
+        let numeral = NUMERALS[self.degree - 1];
+        let numeral = if self.is_upper_case() {
+            numeral.to_string()
+        } else {
+            numeral.to_lowercase()
+        };
+        let mark = match self.quality {
+            ChordQuality::Diminished | ChordQuality::DiminishedSeventh => "°",
+            ChordQuality::HalfDiminishedSeventh => "ø",
+            ChordQuality::Augmented | ChordQuality::AugmentedSeventh => "+",
+            ChordQuality::MajorSeventh
+            | ChordQuality::MinorMajorSeventh
+            | ChordQuality::MajorNinth
+            | ChordQuality::MajorEleventh
+            | ChordQuality::MajorThirteenth => "M",
+            ChordQuality::AugmentedMajorSeventh => "+M",
+            _ => "",
+        };
+        write!(f, "{accidental}{numeral}{mark}{}", self.figure())?;
+        if let Some((target, is_major)) = self.target {
+            let numeral = NUMERALS[target - 1];
+            if is_major {
+                write!(f, "/{numeral}")?;
+            } else {
+                write!(f, "/{}", numeral.to_lowercase())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = ParseError;
+    /// Parses an optional accidental (b, ♭, # or ♯), a numeral whose case gives the third,
+    /// a quality mark (° or o, ø, + and M for major sevenths), a figured-bass inversion
+    /// ("6", "64", "7", "65", "43", "42", possibly written "6/4", ...) and an optional
+    /// applied target after a slash. The Neapolitan ("N6") and augmented sixths
+    /// ("It+6", "Fr+6", "Ger+6") are also recognized.
+    /// ```
+    /// use music::{ChordQuality, ParseErrorKind, RomanNumeral};
+    /// let numeral = "viiø6/5/V".parse::<RomanNumeral>().unwrap();
+    /// assert_eq!(numeral.quality(), ChordQuality::HalfDiminishedSeventh);
+    /// assert_eq!(numeral.to_string(), "viiø65/V");
+    /// assert_eq!("bVII".parse::<RomanNumeral>().unwrap().to_string(), "♭VII");
+    /// let error = "IIX".parse::<RomanNumeral>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::UnexpectedCharacter('X'));
+    /// assert_eq!(error.position(), 2);
+    /// let error = "V8".parse::<RomanNumeral>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidRomanNumeral);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let error = |position: usize, kind: ParseErrorKind| ParseError::init(s, position, kind);
+        if chars.is_empty() {
+            return Err(error(0, ParseErrorKind::Empty));
+        }
+        for augmented_sixth in [
+            AugmentedSixth::Italian,
+            AugmentedSixth::French,
+            AugmentedSixth::German,
+        ] {
+            if let Some(rest) = s.strip_prefix(augmented_sixth.abbreviation()) {
+                let figure = rest.strip_prefix('+').unwrap_or(rest);
+                if !figure.is_empty() && figure != "6" {
+                    let position = chars.len() - figure.chars().count();
+                    return Err(error(position, ParseErrorKind::InvalidRomanNumeral));
+                }
+                return Ok(Self::from_augmented_sixth(augmented_sixth));
+            }
+        }
+        let mut position = 0;
+        let numeral = if chars[0] == 'N' {
+            let (figure, end) = parse_figure(&chars, 1);
+            let inversion = ["", "6", "64"]
+                .iter()
+                .position(|triad_figure| *triad_figure == figure)
+                .ok_or_else(|| error(1, ParseErrorKind::InvalidRomanNumeral))?;
+            position = end;
+            Self::init(2, ChordQuality::Major)
+                .with_alteration(-1)
+                .with_inversion(inversion)
+        } else {
+            let mut alteration: i8 = 0;
+            while let Some(c) = chars.get(position) {
+                let altered = match c {
+                    'b' | '♭' => alteration.checked_sub(1),
+                    '#' | '♯' => alteration.checked_add(1),
+                    _ => break,
+                };
+                alteration =
+                    altered.ok_or_else(|| error(position, ParseErrorKind::InvalidRomanNumeral))?;
+                position += 1;
+            }
+            let (degree, is_major, end) = parse_numeral(&chars, position)
+                .ok_or_else(|| error(position, ParseErrorKind::InvalidRomanNumeral))?;
+            let mark_position = end;
+            position = end;
+            let mut mark = String::new();
+            while let Some(c) = chars.get(position).filter(|c| "°oø+M".contains(**c)) {
+                mark.push(*c);
+                position += 1;
+            }
+            let (figure, end) = parse_figure(&chars, position);
+            // The highest tone of the chord, given by the figure.
+            let (inversion, highest) = match figure.as_str() {
+                "" => (0, 5),
+                "6" => (1, 5),
+                "64" => (2, 5),
+                "7" => (0, 7),
+                "65" => (1, 7),
+                "43" => (2, 7),
+                "42" | "2" => (3, 7),
+                "9" => (0, 9),
+                "11" => (0, 11),
+                "13" => (0, 13),
+                _ => return Err(error(position, ParseErrorKind::InvalidRomanNumeral)),
+            };
+            position = end;
+            let quality = match (is_major, mark.as_str(), highest) {
+                (true, "", 5) => ChordQuality::Major,
+                (true, "", 7) => ChordQuality::DominantSeventh,
+                (true, "", 9) => ChordQuality::DominantNinth,
+                (true, "", 11) => ChordQuality::DominantEleventh,
+                (true, "", 13) => ChordQuality::DominantThirteenth,
+                (true, "+", 5) => ChordQuality::Augmented,
+                (true, "+", 7) => ChordQuality::AugmentedSeventh,
+                (true, "M", 7) => ChordQuality::MajorSeventh,
+                (true, "M", 9) => ChordQuality::MajorNinth,
+                (true, "M", 11) => ChordQuality::MajorEleventh,
+                (true, "M", 13) => ChordQuality::MajorThirteenth,
+                (true, "+M", 7) => ChordQuality::AugmentedMajorSeventh,
+                (false, "", 5) => ChordQuality::Minor,
+                (false, "", 7) => ChordQuality::MinorSeventh,
+                (false, "", 9) => ChordQuality::MinorNinth,
+                (false, "", 11) => ChordQuality::MinorEleventh,
+                (false, "", 13) => ChordQuality::MinorThirteenth,
+                (false, "M", 7) => ChordQuality::MinorMajorSeventh,
+                (false, "°" | "o", 5) => ChordQuality::Diminished,
+                (false, "°" | "o", 7) => ChordQuality::DiminishedSeventh,
+                // The half-diminished mark implies the seventh.
+                (false, "ø", 7) => ChordQuality::HalfDiminishedSeventh,
+                (false, "ø", 5) if figure.is_empty() => ChordQuality::HalfDiminishedSeventh,
+                _ => return Err(error(mark_position, ParseErrorKind::InvalidRomanNumeral)),
+            };
+            Self::init(degree, quality)
+                .with_alteration(alteration)
+                .with_inversion(inversion)
+        };
+        let numeral = if chars.get(position) == Some(&'/') {
+            let (target, is_major, end) = parse_numeral(&chars, position + 1)
+                .ok_or_else(|| error(position + 1, ParseErrorKind::InvalidRomanNumeral))?;
+            position = end;
+            numeral.with_target(target, is_major)
+        } else {
+            numeral
+        };
+        match chars.get(position) {
+            Some(c) => Err(error(position, ParseErrorKind::UnexpectedCharacter(*c))),
+            None => Ok(numeral),
+        }
+    }
+}
+
+impl Scale {
+    /// Analyzes a chord in the key of the scale, failing when the chord is empty
+    /// or is neither a triad, a seventh chord, a ninth, eleventh or thirteenth chord
+    /// nor an augmented sixth chord.
+    /// The key is minor when the scale is (see [`ScaleType::is_minor`]), major otherwise.
+    ///
+    /// Chords whose notes do not all belong to the key (both forms of the sixth
+    /// and seventh degrees belonging to minor keys) are read as applied chords
+    /// when they are major triads, dominant sevenths or diminished chords
+    /// resolving to a major or minor triad of the key other than the tonic.
+    /// ```
+    /// use music::{Chord, MusicError, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = |sounds: &str| scale.try_roman_numeral(&sounds.parse().unwrap());
+    /// assert_eq!(numeral("F3 A3 D4").unwrap().to_string(), "ii6");
+    /// assert_eq!(numeral("F3 G3 B3 D4").unwrap().to_string(), "V42");
+    /// assert_eq!(numeral("F#3 A3 C4 E♭4").unwrap().to_string(), "vii°7/V");
+    /// assert_eq!(numeral("D3 F3 A3 C4 E4").unwrap().to_string(), "ii9");
+    /// assert_eq!(numeral("C4 D♭4 D4"), Err(MusicError::UnidentifiedChord));
+    /// assert_eq!(scale.try_roman_numeral(&Chord::init(0)), Err(MusicError::EmptyChord));
+    /// ```
+    pub fn try_roman_numeral(&self, chord: &Chord) -> Result<RomanNumeral, MusicError> {
+        if chord.is_empty() {
+            return Err(MusicError::EmptyChord);
+        }
+        if let Some(augmented_sixth) = self.augmented_sixth(chord) {
+            return Ok(RomanNumeral::from_augmented_sixth(augmented_sixth));
+        }
+        let candidate = chord
+            .identify()
+            .into_iter()
+            .find(|candidate| is_analyzable(candidate.quality()))
+            .ok_or(MusicError::UnidentifiedChord)?;
+        let (root, quality) = (candidate.root(), candidate.quality());
+        let numeral = self
+            .numeral(root, quality)
+            .with_inversion(candidate.inversion());
+        if self.is_diatonic(chord) {
+            return Ok(numeral);
+        }
+        match self.tonicized(root, quality) {
+            Some((target, scale)) => Ok(scale
+                .numeral(root, quality)
+                .with_inversion(candidate.inversion())
+                .with_target(target, !scale.scale_type().is_minor())),
+            None => Ok(numeral),
+        }
+    }
+    /// Analyzes a chord in the key of the scale (see [`Scale::try_roman_numeral`]).
+    /// # Panics
+    /// It panics when the chord is empty or is neither a triad, a seventh chord,
+    /// an extended chord nor an augmented sixth chord.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::E, ScaleType::HarmonicMinor);
+    /// assert_eq!(scale.roman_numeral(&"E3 G3 B3".parse().unwrap()).to_string(), "i");
+    /// assert_eq!(scale.roman_numeral(&"D3 F#3 A3".parse().unwrap()).to_string(), "VII");
+    /// assert_eq!(scale.roman_numeral(&"D#3 F#3 A3 C4".parse().unwrap()).to_string(), "vii°7");
+    /// ```
+    pub fn roman_numeral(&self, chord: &Chord) -> RomanNumeral {
+        self.try_roman_numeral(chord)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Analyzes a sequence of chords, such as the output of a harmonizer,
+    /// giving `None` for the chords that cannot be analyzed (see [`Scale::try_roman_numeral`]).
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::G, ScaleType::Major);
+    /// let chords: Vec<Chord> = ["G3 B3 D4", "E3 G3 C4", "D3 F#3 A3 C4", "G3 B3 D4", "C4 C#4"]
+    ///     .iter()
+    ///     .map(|chord| chord.parse().unwrap())
+    ///     .collect();
+    /// let analysis: Vec<String> = scale
+    ///     .analyze(&chords)
+    ///     .iter()
+    ///     .map(|numeral| numeral.map_or("?".to_string(), |numeral| numeral.to_string()))
+    ///     .collect();
+    /// assert_eq!(analysis, vec!["I", "IV6", "V7", "I", "?"]);
+    /// ```
+    pub fn analyze(&self, chords: &[Chord]) -> Vec<Option<RomanNumeral>> {
+        chords
+            .iter()
+            .map(|chord| self.try_roman_numeral(chord).ok())
+            .collect()
+    }
+    /// Gives the chord symbol of a Roman numeral in the key of the scale
+    /// (see [`Scale::try_chord_symbol`]).
+    /// # Panics
+    /// It panics when the root needs too many accidentals.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::F, ScaleType::Major);
+    /// let symbol = |numeral: &str| scale.chord_symbol(&numeral.parse().unwrap()).to_string();
+    /// assert_eq!(symbol("ii6"), "Gm/B♭");
+    /// assert_eq!(symbol("V7/V"), "G7");
+    /// assert_eq!(symbol("bVII"), "E♭");
+    /// assert_eq!(symbol("N6"), "G♭/B♭");
+    /// assert_eq!(symbol("Ger+6"), "D♭7");
+    /// ```
+    pub fn chord_symbol(&self, numeral: &RomanNumeral) -> ChordSymbol {
+        self.try_chord_symbol(numeral)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the chord symbol of a Roman numeral in the key of the scale, with the bass
+    /// of inverted chords after a slash. Augmented sixth chords give the symbol of the
+    /// enharmonic chord on the lowered sixth degree.
+    /// It fails when the root needs too many accidentals.
+    /// ```
+    /// use music::{ChordQuality, MusicError, Note, RomanNumeral, Scale, ScaleType};
+    /// let scale = Scale::init(Note::Fs, ScaleType::Major);
+    /// let numeral = RomanNumeral::init(7, ChordQuality::Diminished).with_inversion(1);
+    /// assert_eq!(scale.try_chord_symbol(&numeral).unwrap().to_string(), "E#dim/G#");
+    /// let numeral = RomanNumeral::init(3, ChordQuality::Minor).with_alteration(i8::MAX);
+    /// assert_eq!(scale.try_chord_symbol(&numeral), Err(MusicError::TooManyAccidentals));
+    /// ```
+    pub fn try_chord_symbol(&self, numeral: &RomanNumeral) -> Result<ChordSymbol, MusicError> {
+        let key = match numeral.target {
+            Some((target, is_major)) => self.key_of(target, is_major),
+            None => *self,
+        };
+        let root = match numeral.augmented_sixth {
+            Some(_) => key.augmented_sixth_note(8),
+            None => {
+                let note = key.degree_note(numeral.degree, has_major_third(numeral.quality));
+                let accidental = note
+                    .accidental()
+                    .checked_add(numeral.alteration)
+                    .ok_or(MusicError::TooManyAccidentals)?;
+                SpelledNote::init(note.letter(), accidental)
+            }
+        };
+        let symbol = ChordSymbol::init(root, numeral.quality);
+        let bass = numeral
+            .inversion
+            .checked_sub(1)
+            .and_then(|tone| numeral.quality.tones().get(tone));
+        Ok(match bass {
+            Some((number, semitones)) => {
+                let num = (root.to_usize() as isize + semitones).rem_euclid(12) as usize;
+                let letter = Letter::from_index(root.letter().index() + number - 1);
+                symbol.with_bass(spell_with_letter(num, letter))
+            }
+            None => symbol,
+        })
+    }
+    /// Builds the chord of a Roman numeral in the key of the scale, from its chord symbol
+    /// with the root in an octave (see [`ChordSymbol::try_to_chord`]). Augmented sixth
+    /// chords are built upwards from the lowered sixth degree in the octave.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let chord = |numeral: &str| scale.try_chord(&numeral.parse().unwrap(), 3).unwrap();
+    /// assert!(chord("V7").strict_eq(&"G3 B3 D4 F4".parse::<Chord>().unwrap()));
+    /// assert!(chord("I6").strict_eq(&"E2 C3 G3".parse::<Chord>().unwrap()));
+    /// assert!(chord("It+6").strict_eq(&"A♭3 C4 F#4".parse::<Chord>().unwrap()));
+    /// assert!(scale.try_chord(&"I".parse().unwrap(), 200).is_err());
+    /// ```
+    pub fn try_chord(&self, numeral: &RomanNumeral, octave: isize) -> Result<Chord, MusicError> {
+        let Some(augmented_sixth) = numeral.augmented_sixth else {
+            return self.try_chord_symbol(numeral)?.try_to_chord(octave);
+        };
+        let mut octave = octave;
+        let mut sounds: Vec<Sound> = Vec::new();
+        for semitones in augmented_sixth.semitones() {
+            let note = self.augmented_sixth_note(*semitones);
+            let mut sound = Sound::try_init_spelled(note, octave)?;
+            while sounds.last().is_some_and(|last| sound <= *last) {
+                octave += 1;
+                sound = Sound::try_init_spelled(note, octave)?;
+            }
+            sounds.push(sound);
+        }
+        Ok(Chord::from_vec(sounds))
+    }
+    /// Builds the chord of a Roman numeral in the key of the scale (see [`Scale::try_chord`]).
+    /// # Panics
+    /// It panics when a sound is outside the pitch space.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// let chord = scale.chord(&"vii°7".parse().unwrap(), 3);
+    /// assert!(chord.strict_eq(&"G#3 B3 D4 F4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn chord(&self, numeral: &RomanNumeral, octave: isize) -> Chord {
+        self.try_chord(numeral, octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Builds the chords of a progression of Roman numerals separated by dashes,
+    /// commas or spaces (e.g. "ii7-V7/V-V7-I"), with their roots in an octave
+    /// (see [`Scale::try_chord`]).
+    /// ```
+    /// use music::{MusicError, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let chords = scale.try_progression("I-vi-IV-V7", 3).unwrap();
+    /// assert_eq!(chords.len(), 4);
+    /// assert!(chords[1].strict_eq(&"A3 C4 E4".parse().unwrap()));
+    /// let error = scale.try_progression("I-X-V", 3).unwrap_err();
+    /// assert!(matches!(error, MusicError::Parse(error) if error.position() == 2));
+    /// ```
+    pub fn try_progression(
+        &self,
+        progression: &str,
+        octave: isize,
+    ) -> Result<Vec<Chord>, MusicError> {
+        parse_progression(progression)?
+            .iter()
+            .map(|numeral| self.try_chord(numeral, octave))
+            .collect()
+    }
+    /// Builds the chords of a progression of Roman numerals (see [`Scale::try_progression`]).
+    /// # Panics
+    /// It panics when a numeral is invalid or a sound is outside the pitch space.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::G, ScaleType::Major);
+    /// let chords = scale.progression("ii7 V7 I", 3);
+    /// assert!(chords[0].strict_eq(&"A3 C4 E4 G4".parse::<Chord>().unwrap()));
+    /// assert!(chords[1].strict_eq(&"D3 F#3 A3 C4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn progression(&self, progression: &str, octave: isize) -> Vec<Chord> {
+        self.try_progression(progression, octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the chord symbols of a progression of Roman numerals
+    /// (see [`Scale::try_progression`] and [`Scale::try_chord_symbol`]).
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::D, ScaleType::Major);
+    /// let symbols: Vec<String> = scale
+    ///     .try_progression_symbols("I-V65/IV-IV-iv-I64-V7-I")
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|symbol| symbol.to_string())
+    ///     .collect();
+    /// assert_eq!(symbols, ["D", "D7/F#", "G", "Gm", "D/A", "A7", "D"]);
+    /// ```
+    pub fn try_progression_symbols(
+        &self,
+        progression: &str,
+    ) -> Result<Vec<ChordSymbol>, MusicError> {
+        parse_progression(progression)?
+            .iter()
+            .map(|numeral| self.try_chord_symbol(numeral))
+            .collect()
+    }
+    /// Gives the chord symbols of a progression of Roman numerals
+    /// (see [`Scale::try_progression_symbols`]). It panics when a numeral is invalid
+    /// or when a root needs too many accidentals.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::E, ScaleType::HarmonicMinor);
+    /// let symbols = scale.progression_symbols("i iv6 V");
+    /// assert_eq!(symbols[1].to_string(), "Am/C");
+    /// ```
+    pub fn progression_symbols(&self, progression: &str) -> Vec<ChordSymbol> {
+        self.try_progression_symbols(progression)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the numeral of a chord in root position from its root and quality.
+    pub(crate) fn numeral(&self, root: SpelledNote, quality: ChordQuality) -> RomanNumeral {
+        let tonic = SpelledNote::from(self.tonic());
+        let degree = (root.letter().index() + 7 - tonic.letter().index()) % 7 + 1;
+        let reference = self.degree_note(degree, has_major_third(quality));
+        RomanNumeral::init(degree, quality)
+            .with_alteration(root.accidental() - reference.accidental())
+    }
+    /// Gives the note of a degree of the scale. In minor keys, the sixth and seventh degrees
+    /// are natural for chords with a major third and raised for chords with a minor third.
+    fn degree_note(&self, degree: usize, major_third: bool) -> SpelledNote {
+        let is_minor = self.scale_type().is_minor();
+        let key_type = if is_minor {
+            ScaleType::HarmonicMinor
+        } else {
+            ScaleType::Major
+        };
+        let note = Scale::init(self.tonic(), key_type).spelled_notes()[degree - 1];
+        let alteration = match (is_minor, degree, major_third) {
+            (true, 6, false) => 1,
+            (true, 7, true) => -1,
+            _ => 0,
+        };
+        SpelledNote::init(note.letter(), note.accidental() + alteration)
+    }
+    /// Tests whether or not every note of a chord belongs to the key.
+    fn is_diatonic(&self, chord: &Chord) -> bool {
+        let semitones: &[usize] = if self.scale_type().is_minor() {
+            &[0, 2, 3, 5, 7, 8, 9, 10, 11]
+        } else {
+            &[0, 2, 4, 5, 7, 9, 11]
+        };
+        let tonic = self.tonic().to_usize();
+        chord
+            .sounds()
+            .iter()
+            .all(|sound| semitones.contains(&((sound.spelled_note().to_usize() + 12 - tonic) % 12)))
+    }
+    /// Gives the degree tonicized by a dominant or leading-tone chord, with its key.
+    fn tonicized(&self, root: SpelledNote, quality: ChordQuality) -> Option<(usize, Scale)> {
+        let (steps, semitones) = match quality {
+            ChordQuality::Major | ChordQuality::DominantSeventh => (3, 5),
+            ChordQuality::Diminished
+            | ChordQuality::DiminishedSeventh
+            | ChordQuality::HalfDiminishedSeventh => (1, 1),
+            _ => return None,
+        };
+        let tonic = SpelledNote::from(self.tonic());
+        let degree = (root.letter().index() + steps + 7 - tonic.letter().index()) % 7 + 1;
+        let is_major = target_triads(self.scale_type().is_minor())[degree - 1]?;
+        let note = self.degree_note(degree, is_major);
+        if degree == 1 || note.to_usize() != (root.to_usize() + semitones) % 12 {
+            return None;
+        }
+        Some((degree, self.key_of(degree, is_major)))
+    }
+    /// Gives the major or minor key of a degree of the scale.
+    fn key_of(&self, degree: usize, is_major: bool) -> Scale {
+        let note = self.degree_note(degree, is_major).to_note();
+        if is_major {
+            Scale::init(note, ScaleType::Major)
+        } else {
+            Scale::init(note, ScaleType::HarmonicMinor)
+        }
+    }
+    /// Spells a note of an augmented sixth chord from its half tones above the tonic.
+    fn augmented_sixth_note(&self, semitones: usize) -> SpelledNote {
+        let tonic = SpelledNote::from(self.tonic());
+        let steps = CHROMATIC_STEPS[semitones];
+        spell_with_letter(
+            (tonic.to_usize() + semitones) % 12,
+            Letter::from_index(tonic.letter().index() + steps),
+        )
+    }
+    /// Recognizes an augmented sixth chord spelled with the raised fourth degree.
+    fn augmented_sixth(&self, chord: &Chord) -> Option<AugmentedSixth> {
+        let tonic = SpelledNote::from(self.tonic());
+        let mut semitones: Vec<usize> = Vec::new();
+        for sound in chord.sounds() {
+            let note = sound.spelled_note();
+            let offset = (note.to_usize() + 12 - tonic.to_usize()) % 12;
+            let degree = (note.letter().index() + 7 - tonic.letter().index()) % 7;
+            if offset == 6 && degree != 3 {
+                return None;
+            }
+            if !semitones.contains(&offset) {
+                semitones.push(offset);
+            }
+        }
+        semitones.sort();
+        [
+            AugmentedSixth::Italian,
+            AugmentedSixth::French,
+            AugmentedSixth::German,
+        ]
+        .into_iter()
+        .find(|augmented_sixth| {
+            let mut expected = augmented_sixth.semitones().to_vec();
+            expected.sort();
+            expected == semitones
+        })
+    }
+}
+
+/// Parses Roman numerals separated by dashes, commas or spaces.
+fn parse_progression(progression: &str) -> Result<Vec<RomanNumeral>, ParseError> {
+    let mut numerals = Vec::new();
+    let mut position = 0;
+    for token in progression.split(|c: char| c == '-' || c == ',' || c.is_whitespace()) {
+        if !token.is_empty() {
+            let numeral = token
+                .parse::<RomanNumeral>()
+                .map_err(|error| error.within(progression, position))?;
+            numerals.push(numeral);
+        }
+        position += token.chars().count() + 1;
+    }
+    if numerals.is_empty() {
+        return Err(ParseError::init(progression, 0, ParseErrorKind::Empty));
+    }
+    Ok(numerals)
+}
+
+/// Parses a numeral (I to VII) in a single case, giving its degree, whether it is
+/// upper case and the position following it.
+fn parse_numeral(chars: &[char], start: usize) -> Option<(usize, bool, usize)> {
+    let length = chars
+        .get(start..)?
+        .iter()
+        .take_while(|c| matches!(c, 'I' | 'V' | 'i' | 'v'))
+        .count();
+    let numeral: String = chars[start..start + length].iter().collect();
+    let is_major = numeral.chars().all(char::is_uppercase);
+    if !is_major && numeral.chars().any(char::is_uppercase) {
+        return None;
+    }
+    let degree = NUMERALS
+        .iter()
+        .position(|candidate| *candidate == numeral.to_uppercase())?;
+    Some((degree + 1, is_major, start + length))
+}
+
+/// Parses a figured-bass inversion, whose digits may be separated by slashes,
+/// giving its digits and the position following it.
+fn parse_figure(chars: &[char], start: usize) -> (String, usize) {
+    let mut figure = String::new();
+    let mut position = start;
+    while let Some(c) = chars.get(position) {
+        let is_separator = *c == '/'
+            && !figure.is_empty()
+            && chars.get(position + 1).is_some_and(char::is_ascii_digit);
+        if c.is_ascii_digit() {
+            figure.push(*c);
+        } else if !is_separator {
+            break;
+        }
+        position += 1;
+    }
+    (figure, position)
+}
+
+/// Qualities of the tonicizable triads of a major or minor key (see [`MAJOR_TRIADS`]).
+fn target_triads(is_minor: bool) -> [Option<bool>; 7] {
+    if is_minor {
+        MINOR_TRIADS
+    } else {
+        MAJOR_TRIADS
+    }
+}
+
+/// Tests whether or not a chord quality has a major third above its root.
+fn has_major_third(quality: ChordQuality) -> bool {
+    quality.tones().contains(&(3, 4))
+}
+
+/// Tests whether or not a quality is a triad, a seventh chord or an extended chord
+/// with a third, which Roman numerals can name.
+pub(crate) fn is_analyzable(quality: ChordQuality) -> bool {
+    matches!(
+        quality,
+        ChordQuality::Major
+            | ChordQuality::Minor
+            | ChordQuality::Diminished
+            | ChordQuality::Augmented
+            | ChordQuality::DominantSeventh
+            | ChordQuality::MajorSeventh
+            | ChordQuality::MinorSeventh
+            | ChordQuality::MinorMajorSeventh
+            | ChordQuality::HalfDiminishedSeventh
+            | ChordQuality::DiminishedSeventh
+            | ChordQuality::AugmentedSeventh
+            | ChordQuality::AugmentedMajorSeventh
+            | ChordQuality::DominantNinth
+            | ChordQuality::MajorNinth
+            | ChordQuality::MinorNinth
+            | ChordQuality::DominantEleventh
+            | ChordQuality::MinorEleventh
+            | ChordQuality::MajorEleventh
+            | ChordQuality::DominantThirteenth
+            | ChordQuality::MajorThirteenth
+            | ChordQuality::MinorThirteenth
+    )
+}
@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AugmentedSixth, Chord, ChordQuality, KraehenbuehlKnuth, MusicError, Note, ParseErrorKind,
+        RomanNumeral, Scale, ScaleType, Sound,
+    };
+
+    fn numeral(scale: &Scale, sounds: &str) -> String {
+        scale.roman_numeral(&sounds.parse().unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_diatonic() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        assert_eq!(numeral(&scale, "C3 E3 G3"), "I");
+        assert_eq!(numeral(&scale, "F3 A3 D4"), "ii6");
+        assert_eq!(numeral(&scale, "D3 G3 B3"), "V64");
+        assert_eq!(numeral(&scale, "G2 B3 D4 F4"), "V7");
+        assert_eq!(numeral(&scale, "D3 F3 G3 B3"), "V43");
+        assert_eq!(numeral(&scale, "B2 D3 F3 A3"), "viiø7");
+        assert_eq!(numeral(&scale, "C3 E3 G3 B3"), "IM7");
+        // Tertian seventh chords are preferred to added sixths.
+        assert_eq!(numeral(&scale, "C3 E3 G3 A3"), "vi65");
+        assert_eq!(numeral(&scale, "B2 D3 F3"), "vii°");
+    }
+
+    #[test]
+    fn test_minor() {
+        let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+        assert_eq!(numeral(&scale, "A2 C3 E3"), "i");
+        assert_eq!(numeral(&scale, "B2 D3 F3"), "ii°");
+        assert_eq!(numeral(&scale, "C3 E3 G3"), "III");
+        assert_eq!(numeral(&scale, "C3 E3 G#3"), "III+");
+        assert_eq!(numeral(&scale, "E3 G#3 B3 D4"), "V7");
+        assert_eq!(numeral(&scale, "G3 B3 D4"), "VII");
+        assert_eq!(numeral(&scale, "G#3 B3 D4 F4"), "vii°7");
+        assert_eq!(numeral(&scale, "F#3 A3 C4"), "vi°");
+        assert_eq!(numeral(&scale, "B2 D#3 F#3"), "V/V");
+        assert_eq!(numeral(&scale, "G3 B3 D4 F4"), "VII7");
+        assert_eq!(numeral(&scale, "C3 E3 G3 B♭3"), "V7/VI");
+        assert_eq!(numeral(&scale, "C#3 E3 G3 B♭3"), "vii°7/iv");
+    }
+
+    #[test]
+    fn test_chromatic() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        assert_eq!(numeral(&scale, "D3 F#3 A3"), "V/V");
+        assert_eq!(numeral(&scale, "C3 E3 G3 B♭3"), "V7/IV");
+        assert_eq!(numeral(&scale, "G#3 B3 D4 F4"), "vii°7/vi");
+        assert_eq!(numeral(&scale, "C#3 E3 G3 B♭3"), "vii°7/ii");
+        assert_eq!(numeral(&scale, "A♭3 C4 E♭4"), "♭VI");
+        assert_eq!(numeral(&scale, "B♭3 D4 F4"), "♭VII");
+        assert_eq!(numeral(&scale, "F3 A♭3 C4"), "iv");
+        assert_eq!(numeral(&scale, "F3 A♭3 D♭4"), "N6");
+        assert_eq!(numeral(&scale, "A♭3 C4 F#4"), "It+6");
+        assert_eq!(numeral(&scale, "A♭3 C4 D4 F#4"), "Fr+6");
+        assert_eq!(numeral(&scale, "A♭3 C4 E♭4 F#4"), "Ger+6");
+        // Spelled with a lowered seventh, the German sixth is an applied dominant seventh.
+        assert_eq!(numeral(&scale, "A♭3 C4 E♭4 G♭4"), "♭VI7");
+    }
+
+    #[test]
+    fn test_numeral_parts() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        let applied = scale.roman_numeral(&"F#3 A3 C4 D4".parse().unwrap());
+        assert_eq!(applied.degree(), 5);
+        assert_eq!(applied.target(), Some(5));
+        assert_eq!(applied.inversion(), 1);
+        assert_eq!(applied.to_string(), "V65/V");
+        assert_eq!(
+            applied,
+            RomanNumeral::init(5, ChordQuality::DominantSeventh)
+                .with_inversion(1)
+                .with_target(5, true)
+        );
+        let german = scale.roman_numeral(&"A♭3 C4 E♭4 F#4".parse().unwrap());
+        assert_eq!(
+            german,
+            RomanNumeral::from_augmented_sixth(AugmentedSixth::German)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid target 0.")]
+    fn test_invalid_target() {
+        let _ = RomanNumeral::init(5, ChordQuality::Major).with_target(0, true);
+    }
+
+    #[test]
+    fn test_analyze_harmonization() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        let melody = [
+            Note::E,
+            Note::D,
+            Note::C,
+            Note::D,
+            Note::E,
+            Note::E,
+            Note::E,
+        ]
+        .iter()
+        .map(|note| Sound::init(*note, 5))
+        .collect();
+        let chords = KraehenbuehlKnuth::init(melody, Some(scale)).harmonize();
+        let analysis = scale.analyze(&chords);
+        assert_eq!(analysis.len(), chords.len());
+        assert!(analysis.iter().all(|numeral| numeral.is_some()));
+        assert_eq!(
+            scale.analyze(&[Chord::init(0), "C3 E3 G3".parse().unwrap()]),
+            vec![None, Some(RomanNumeral::init(1, ChordQuality::Major))]
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for numeral in [
+            "I",
+            "ii6",
+            "V64",
+            "V7",
+            "V65",
+            "V43",
+            "V42",
+            "viiø7",
+            "vii°7",
+            "III+",
+            "IM7",
+            "iM7",
+            "♭VI",
+            "#iv°",
+            "V/V",
+            "V7/ii",
+            "vii°43/vi",
+            "N6",
+            "It+6",
+            "Fr+6",
+            "Ger+6",
+            "V9",
+            "IM9",
+            "ii11",
+            "V13",
+            "vi13",
+        ] {
+            let parsed = numeral.parse::<RomanNumeral>().unwrap();
+            assert_eq!(parsed.to_string(), numeral);
+        }
+        assert_eq!(
+            "viio6/5".parse::<RomanNumeral>().unwrap().to_string(),
+            "vii°65"
+        );
+        assert_eq!("V2".parse::<RomanNumeral>().unwrap().to_string(), "V42");
+        assert_eq!("Ger".parse::<RomanNumeral>().unwrap().to_string(), "Ger+6");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |numeral: &str| {
+            let error = numeral.parse::<RomanNumeral>().unwrap_err();
+            (error.kind(), error.position())
+        };
+        assert_eq!(error(""), (ParseErrorKind::Empty, 0));
+        assert_eq!(error("X"), (ParseErrorKind::InvalidRomanNumeral, 0));
+        assert_eq!(error("Iv"), (ParseErrorKind::InvalidRomanNumeral, 0));
+        assert_eq!(error("IIII"), (ParseErrorKind::InvalidRomanNumeral, 0));
+        assert_eq!(error("I°"), (ParseErrorKind::InvalidRomanNumeral, 1));
+        assert_eq!(error("IM"), (ParseErrorKind::InvalidRomanNumeral, 1));
+        assert_eq!(error("V10"), (ParseErrorKind::InvalidRomanNumeral, 1));
+        assert_eq!(error("V/X"), (ParseErrorKind::InvalidRomanNumeral, 2));
+        assert_eq!(error("It+7"), (ParseErrorKind::InvalidRomanNumeral, 3));
+        assert_eq!(error("V7)"), (ParseErrorKind::UnexpectedCharacter(')'), 2));
+        let flats = "b".repeat(130) + "I";
+        assert_eq!(error(&flats), (ParseErrorKind::InvalidRomanNumeral, 128));
+    }
+
+    #[test]
+    fn test_too_many_accidentals() {
+        let sharps = "#".repeat(127) + "I";
+        let numeral = sharps.parse::<RomanNumeral>().unwrap();
+        assert_eq!(numeral.alteration(), i8::MAX);
+        let scale = Scale::init(Note::Fs, ScaleType::Major);
+        assert_eq!(
+            scale.try_chord_symbol(&numeral),
+            Err(MusicError::TooManyAccidentals)
+        );
+        assert_eq!(
+            scale.try_progression(&sharps, 3),
+            Err(MusicError::TooManyAccidentals)
+        );
+        assert!(Scale::init(Note::C, ScaleType::Major)
+            .try_chord_symbol(&numeral)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_progression() {
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        let expected: Vec<Chord> = ["D3 F3 A3 C4", "D3 F#3 A3 C4", "G3 B3 D4 F4", "C3 E3 G3"]
+            .iter()
+            .map(|chord| chord.parse().unwrap())
+            .collect();
+        let chords = scale.progression("ii7-V7/V-V7-I", 3);
+        assert_eq!(chords.len(), expected.len());
+        for (chord, expected) in chords.iter().zip(&expected) {
+            assert!(chord.strict_eq(expected));
+        }
+        let chords = scale.progression("bVII, iv6, N6, Fr+6", 3);
+        for (chord, expected) in
+            chords
+                .iter()
+                .zip(["B♭3 D4 F4", "A♭2 F3 C4", "F2 D♭3 A♭3", "A♭3 C4 D4 F#4"])
+        {
+            assert!(chord.strict_eq(&expected.parse().unwrap()));
+        }
+        assert!(matches!(
+            scale.try_progression(" - ", 3),
+            Err(MusicError::Parse(error)) if error.kind() == ParseErrorKind::Empty
+        ));
+    }
+
+    #[test]
+    fn test_progression_analysis() {
+        for scale in [
+            Scale::init(Note::Bb, ScaleType::Major),
+            Scale::init(Note::Fs, ScaleType::HarmonicMinor),
+        ] {
+            let progression = "I6 ii65 V7/V V64 vii°7/vi ♭VI N6 It+6 Ger+6 V42 I6";
+            let progression = match scale.scale_type() {
+                ScaleType::Major => progression.to_string(),
+                _ => progression
+                    .replace("I6", "i6")
+                    .replace("ii65", "iiø65")
+                    .replace("/vi", "/VI")
+                    .replace("♭VI", "VI"),
+            };
+            let analysis: Vec<String> = scale
+                .analyze(&scale.progression(&progression, 3))
+                .iter()
+                .map(|numeral| numeral.unwrap().to_string())
+                .collect();
+            let expected: Vec<&str> = progression.split(' ').collect();
+            let expected: Vec<String> = expected
+                .iter()
+                .map(|numeral| numeral.parse::<RomanNumeral>().unwrap().to_string())
+                .collect();
+            assert_eq!(analysis, expected);
+        }
+    }
+}
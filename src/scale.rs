@@ -1,14 +1,17 @@
+mod degree;
+mod diatonic;
+mod pitch_class_set;
+mod scale_type;
 #[cfg(test)]
 mod unit_test;
-use crate::{Note, Sound};
+pub use degree::DegreeName;
+pub use diatonic::{DiatonicChord, Stacking};
+pub use pitch_class_set::PitchClassSet;
+pub use scale_type::ScaleType;
 
-#[derive(Debug)]
-pub enum ScaleType {
-    Major,
-    Minor,
-}
+use crate::{Letter, Note, Sound, SpelledNote};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Scale {
     note: Note,
     scale_type: ScaleType,
@@ -24,34 +27,239 @@ impl Default for Scale {
 }
 
 impl Scale {
+    /// Creates the scale of a type on a tonic, the deprecated `ScaleType::Minor`
+    /// giving the harmonic minor scale.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// #[allow(deprecated)]
+    /// let scale = Scale::init(Note::A, ScaleType::Minor);
+    /// assert_eq!(scale, Scale::init(Note::A, ScaleType::HarmonicMinor));
+    /// assert_eq!(scale.scale_type(), ScaleType::HarmonicMinor);
+    /// ```
     pub fn init(note: Note, scale_type: ScaleType) -> Self {
+        #[allow(deprecated)]
+        let scale_type = match scale_type {
+            ScaleType::Minor => ScaleType::HarmonicMinor,
+            scale_type => scale_type,
+        };
         Self { note, scale_type }
     }
 
+    /// Gives the tonic of the scale.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// assert_eq!(Scale::init(Note::Eb, ScaleType::Major).tonic(), Note::Eb);
+    /// ```
+    pub fn tonic(&self) -> Note {
+        self.note
+    }
+
+    /// Gives the type of the scale.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// assert_eq!(scale.scale_type(), ScaleType::HarmonicMinor);
+    /// ```
+    pub fn scale_type(&self) -> ScaleType {
+        self.scale_type
+    }
+
+    /// Gives the integer representatives of the notes of the scale, from the tonic.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::D, ScaleType::MinorPentatonic);
+    /// assert_eq!(scale.usize_notes(), vec![2, 5, 7, 9, 0]);
+    /// ```
     pub fn usize_notes(&self) -> Vec<usize> {
-        match self.scale_type {
-            ScaleType::Major => self.note.major_scale_from_tonic(),
-            ScaleType::Minor => self.note.minor_scale_from_tonic(),
-        }
+        let tonic = self.note.to_usize();
+        self.scale_type
+            .intervals()
+            .iter()
+            .map(|interval| (tonic + interval) % 12)
+            .collect()
     }
 
+    /// Tests whether or not a note belongs to the scale, whatever its spelling.
+    /// ```
+    /// use music::{Note, PitchClassSet, Scale, ScaleType};
+    /// let scale = Scale::init(Note::D, ScaleType::Custom(PitchClassSet::from_step_pattern("H A H W H A H")));
+    /// assert!(scale.contains(Note::Ds));
+    /// assert!(scale.contains(Note::Eb));
+    /// assert!(!scale.contains(Note::E));
+    /// ```
+    pub fn contains(&self, note: Note) -> bool {
+        self.scale_type
+            .pitch_class_set()
+            .contains(note.to_usize() + 12 - self.note.to_usize())
+    }
+
+    /// Gives the notes of the scale, spelled according to the key
+    /// (see [`Scale::spelled_notes`]).
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::Fs, ScaleType::Major);
+    /// assert!(scale.notes()[6].strict_eq(&Note::Es));
+    /// ```
     pub fn notes(&self) -> Vec<Note> {
-        self.usize_notes()
+        self.spelled_notes()
             .into_iter()
-            .map(Note::from_usize)
+            .map(|note| note.to_note())
+            .collect()
+    }
+
+    /// Gives the spelled notes of the scale. In scales of seven notes, each letter appears
+    /// once, starting from the letter of the tonic; the other scales are spelled as in the
+    /// harmonic chromatic scale (see [`Scale::spell`]).
+    /// ```
+    /// use music::{Letter, Note, Scale, ScaleType, SpelledNote};
+    /// let scale = Scale::init(Note::Gs, ScaleType::HarmonicMinor);
+    /// assert_eq!(scale.spelled_notes()[6], SpelledNote::init(Letter::F, 2));
+    /// let scale = Scale::init(Note::C, ScaleType::WholeTone);
+    /// assert_eq!(scale.spelled_notes()[4], SpelledNote::init(Letter::A, -1));
+    /// ```
+    pub fn spelled_notes(&self) -> Vec<SpelledNote> {
+        let tonic = SpelledNote::from(self.note);
+        let intervals = self.scale_type.intervals();
+        let is_heptatonic = intervals.len() == 7;
+        intervals
+            .iter()
+            .enumerate()
+            .map(|(degree, interval)| {
+                let steps = if is_heptatonic {
+                    degree
+                } else {
+                    CHROMATIC_STEPS[*interval]
+                };
+                let letter = Letter::from_index(tonic.letter().index() + steps);
+                spell_with_letter((tonic.to_usize() + interval) % 12, letter)
+            })
+            .collect()
+    }
+
+    /// Gives the number of sharps (positive) or flats (negative) of the key signature.
+    /// Church modes get the signature of their parent major scale, the other scales
+    /// that of the major or minor key on their tonic (see [`ScaleType::is_minor`]).
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// assert_eq!(Scale::init(Note::E, ScaleType::Major).key_signature(), 4);
+    /// assert_eq!(Scale::init(Note::F, ScaleType::HarmonicMinor).key_signature(), -4);
+    /// assert_eq!(Scale::init(Note::D, ScaleType::Dorian).key_signature(), 0);
+    /// assert_eq!(Scale::init(Note::G, ScaleType::Blues).key_signature(), -2);
+    /// ```
+    pub fn key_signature(&self) -> isize {
+        let tonic = SpelledNote::from(self.note);
+        let fifths =
+            [0, 2, 4, -1, 1, 3, 5][tonic.letter().index()] + 7 * tonic.accidental() as isize;
+        match self.scale_type {
+            ScaleType::Lydian => fifths + 1,
+            ScaleType::Major => fifths,
+            ScaleType::Mixolydian => fifths - 1,
+            ScaleType::Dorian => fifths - 2,
+            ScaleType::NaturalMinor => fifths - 3,
+            ScaleType::Phrygian => fifths - 4,
+            ScaleType::Locrian => fifths - 5,
+            scale_type if scale_type.is_minor() => fifths - 3,
+            _ => fifths,
+        }
+    }
+
+    /// Spells a note in the key of the scale. Notes of the scale get the spelling of
+    /// the scale, the other ones are spelled as in the harmonic chromatic scale
+    /// (lowered second, third, sixth and seventh degrees, raised fourth degree).
+    /// ```
+    /// use music::{Letter, Note, Scale, ScaleType, SpelledNote};
+    /// let scale = Scale::init(Note::D, ScaleType::Major);
+    /// assert_eq!(scale.spell(Note::Gb), SpelledNote::init(Letter::F, 1));
+    /// assert_eq!(scale.spell(Note::As), SpelledNote::init(Letter::B, -1));
+    /// assert_eq!(scale.spell(Note::Gs), SpelledNote::init(Letter::G, 1));
+    /// ```
+    pub fn spell(&self, note: Note) -> SpelledNote {
+        let num = note.to_usize();
+        if let Some(spelled) = self.scale_spelling(num) {
+            return spelled;
+        }
+        let tonic = SpelledNote::from(self.note);
+        let offset = (num + 12 - tonic.to_usize()) % 12;
+        spell_with_letter(
+            num,
+            Letter::from_index(tonic.letter().index() + CHROMATIC_STEPS[offset]),
+        )
+    }
+
+    /// Respells a sound in the key of the scale (see [`Scale::spell`]), keeping its pitch.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Sound};
+    /// let scale = Scale::init(Note::Cs, ScaleType::Major);
+    /// let sound = scale.spell_sound(Sound::init(Note::C, 5));
+    /// assert!(sound.strict_eq(&Sound::init(Note::Bs, 4)));
+    /// ```
+    pub fn spell_sound(&self, sound: Sound) -> Sound {
+        Sound::from_range_spelled(sound.range(), self.spell(sound.note()))
+    }
+
+    /// Respells a sequence of sounds in the key of the scale, keeping their pitches.
+    /// Notes of the scale get the spelling of the scale while chromatic notes are
+    /// spelled by context: raised when the following sound is higher,
+    /// lowered when it is lower, as in [`Scale::spell`] otherwise.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Sound};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let melody = vec![
+    ///     Sound::init(Note::G, 4),
+    ///     Sound::init(Note::Ab, 4),
+    ///     Sound::init(Note::A, 4),
+    ///     Sound::init(Note::Ab, 4),
+    ///     Sound::init(Note::G, 4),
+    /// ];
+    /// let spelled_melody = scale.spell_sounds(&melody);
+    /// assert!(spelled_melody[1].strict_eq(&Sound::init(Note::Gs, 4)));
+    /// assert!(spelled_melody[3].strict_eq(&Sound::init(Note::Ab, 4)));
+    /// ```
+    pub fn spell_sounds(&self, sounds: &[Sound]) -> Vec<Sound> {
+        let notes = self.spelled_notes();
+        sounds
+            .iter()
+            .enumerate()
+            .map(|(position, sound)| {
+                let num = sound.note().to_usize();
+                if self.scale_spelling(num).is_some() {
+                    return self.spell_sound(*sound);
+                }
+                let distance = |note: &SpelledNote| (num + 12 - note.to_usize()) % 12;
+                let spelled = match sounds.get(position + 1) {
+                    Some(next) if next > sound => notes.iter().min_by_key(|note| distance(note)),
+                    Some(next) if next < sound => {
+                        notes.iter().min_by_key(|note| 12 - distance(note))
+                    }
+                    _ => None,
+                };
+                match spelled {
+                    Some(note) => Sound::from_range_spelled(
+                        sound.range(),
+                        spell_with_letter(num, note.letter()),
+                    ),
+                    None => self.spell_sound(*sound),
+                }
+            })
             .collect()
     }
 
-    fn get_sound(tonic: Sound, current_note: Note) -> Sound {
-        let mut current_sound = Sound::init(current_note, tonic.octave());
-        if tonic > current_sound {
-            current_sound = Sound::init(current_note, tonic.octave() + 1);
+    fn scale_spelling(&self, num: usize) -> Option<SpelledNote> {
+        self.spelled_notes()
+            .into_iter()
+            .find(|note| note.to_usize() == num % 12)
+    }
+
+    fn get_sound(tonic: Sound, current_note: SpelledNote) -> Sound {
+        let mut octave = tonic.octave();
+        if (octave - 1) * 12 + current_note.semitones() < tonic.range() {
+            octave += 1;
         }
-        current_sound
+        Sound::init_spelled(current_note, octave)
     }
 
-    pub fn sounds(&self, octave: usize) -> Vec<Sound> {
-        let notes = self.notes();
+    pub fn sounds(&self, octave: isize) -> Vec<Sound> {
+        let notes = self.spelled_notes();
         let tonic = Sound::init(self.note, octave);
         notes
             .iter()
@@ -59,3 +267,13 @@ impl Scale {
             .collect()
     }
 }
+
+/// Letter steps above the tonic of the notes of the harmonic chromatic scale,
+/// indexed by their half tones above the tonic.
+pub(crate) const CHROMATIC_STEPS: [usize; 12] = [0, 1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6];
+
+/// Spells the note whose integer representative is `num` with the given letter.
+pub(crate) fn spell_with_letter(num: usize, letter: Letter) -> SpelledNote {
+    let accidental = (num as isize - letter.to_usize() as isize + 6).rem_euclid(12) - 6;
+    SpelledNote::init(letter, accidental as i8)
+}
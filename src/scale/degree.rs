@@ -0,0 +1,122 @@
+use std::fmt;
+
+use crate::{MusicError, Note, Scale, SpelledNote};
+
+/// Name of a degree of a seven-note scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DegreeName {
+    Tonic,
+    Supertonic,
+    Mediant,
+    Subdominant,
+    Dominant,
+    Submediant,
+    /// Seventh degree a half tone below the tonic.
+    LeadingTone,
+    /// Seventh degree a whole tone (or more) below the tonic.
+    Subtonic,
+}
+
+impl DegreeName {
+    /// Gives the name of the degree.
+    /// ```
+    /// use music::DegreeName;
+    /// assert_eq!(DegreeName::LeadingTone.name(), "leading tone");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            DegreeName::Tonic => "tonic",
+            DegreeName::Supertonic => "supertonic",
+            DegreeName::Mediant => "mediant",
+            DegreeName::Subdominant => "subdominant",
+            DegreeName::Dominant => "dominant",
+            DegreeName::Submediant => "submediant",
+            DegreeName::LeadingTone => "leading tone",
+            DegreeName::Subtonic => "subtonic",
+        }
+    }
+}
+
+impl fmt::Display for DegreeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Scale {
+    /// Gives the degree of a note in the scale (1 for the tonic), whatever its spelling.
+    /// ```
+    /// use music::{Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::Eb, ScaleType::Major);
+    /// assert_eq!(scale.degree_of(Note::G), Some(3));
+    /// assert_eq!(scale.degree_of(Note::As), Some(5));
+    /// assert_eq!(scale.degree_of(Note::E), None);
+    /// ```
+    pub fn degree_of(&self, note: Note) -> Option<usize> {
+        self.usize_notes()
+            .iter()
+            .position(|num| *num == note.to_usize())
+            .map(|position| position + 1)
+    }
+    /// Gives the note on a degree of the scale (1 for the tonic), the degrees going on
+    /// above the octave (the ninth degree of a seven-note scale being the supertonic).
+    /// # Panics
+    /// It panics when the degree is 0.
+    /// ```
+    /// use music::{Letter, Note, Scale, ScaleType, SpelledNote};
+    /// let scale = Scale::init(Note::Eb, ScaleType::Major);
+    /// assert_eq!(scale.note_at(5), SpelledNote::init(Letter::B, -1));
+    /// assert_eq!(scale.note_at(9), SpelledNote::init(Letter::F, 0));
+    /// ```
+    pub fn note_at(&self, degree: usize) -> SpelledNote {
+        self.try_note_at(degree)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives the note on a degree of the scale (see [`Scale::note_at`]),
+    /// failing when the degree is 0.
+    /// ```
+    /// use music::{Letter, MusicError, Note, Scale, ScaleType, SpelledNote};
+    /// let scale = Scale::init(Note::C, ScaleType::MinorPentatonic);
+    /// assert_eq!(scale.try_note_at(2), Ok(SpelledNote::init(Letter::E, -1)));
+    /// assert_eq!(scale.try_note_at(6), Ok(SpelledNote::init(Letter::C, 0)));
+    /// assert_eq!(scale.try_note_at(0), Err(MusicError::InvalidDegree));
+    /// ```
+    pub fn try_note_at(&self, degree: usize) -> Result<SpelledNote, MusicError> {
+        if degree == 0 {
+            return Err(MusicError::InvalidDegree);
+        }
+        let notes = self.spelled_notes();
+        Ok(notes[(degree - 1) % notes.len()])
+    }
+    /// Gives the name of a degree of a seven-note scale (see [`Scale::note_at`]),
+    /// the seventh degree being the leading tone or the subtonic according to its
+    /// distance from the tonic. Other scales have no degree names.
+    /// ```
+    /// use music::{DegreeName, Note, Scale, ScaleType};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// assert_eq!(scale.degree_name(5), Some(DegreeName::Dominant));
+    /// assert_eq!(scale.degree_name(7), Some(DegreeName::LeadingTone));
+    /// assert_eq!(scale.degree_name(8), Some(DegreeName::Tonic));
+    /// let scale = Scale::init(Note::A, ScaleType::NaturalMinor);
+    /// assert_eq!(scale.degree_name(7), Some(DegreeName::Subtonic));
+    /// let scale = Scale::init(Note::A, ScaleType::WholeTone);
+    /// assert_eq!(scale.degree_name(1), None);
+    /// ```
+    pub fn degree_name(&self, degree: usize) -> Option<DegreeName> {
+        let intervals = self.scale_type().intervals();
+        if degree == 0 || intervals.len() != 7 {
+            return None;
+        }
+        let name = match (degree - 1) % 7 {
+            0 => DegreeName::Tonic,
+            1 => DegreeName::Supertonic,
+            2 => DegreeName::Mediant,
+            3 => DegreeName::Subdominant,
+            4 => DegreeName::Dominant,
+            5 => DegreeName::Submediant,
+            _ if intervals[6] == 11 => DegreeName::LeadingTone,
+            _ => DegreeName::Subtonic,
+        };
+        Some(name)
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    roman::is_analyzable, Chord, ChordQuality, MusicError, RomanNumeral, Scale, Sound, SpelledNote,
+    Transpose,
+};
+
+/// Way of stacking the degrees of a scale into a chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stacking {
+    /// Root, third and fifth.
+    Triad,
+    /// Triad with the seventh.
+    Seventh,
+    /// Seventh chord with the ninth.
+    Ninth,
+    /// Ninth chord with the eleventh.
+    Eleventh,
+    /// Ninth chord with the thirteenth, the eleventh being left out.
+    Thirteenth,
+    /// Given number of sounds a fourth apart.
+    Quartal(usize),
+    /// Given number of sounds a second apart.
+    Secundal(usize),
+}
+
+impl Stacking {
+    /// Gives the scale steps of the sounds above the root, the root included.
+    /// ```
+    /// use music::Stacking;
+    /// assert_eq!(Stacking::Seventh.steps(), vec![0, 2, 4, 6]);
+    /// assert_eq!(Stacking::Thirteenth.steps(), vec![0, 2, 4, 6, 8, 12]);
+    /// assert_eq!(Stacking::Quartal(3).steps(), vec![0, 3, 6]);
+    /// assert_eq!(Stacking::Secundal(4).steps(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn steps(&self) -> Vec<usize> {
+        match self {
+            Stacking::Triad => vec![0, 2, 4],
+            Stacking::Seventh => vec![0, 2, 4, 6],
+            Stacking::Ninth => vec![0, 2, 4, 6, 8],
+            Stacking::Eleventh => vec![0, 2, 4, 6, 8, 10],
+            Stacking::Thirteenth => vec![0, 2, 4, 6, 8, 12],
+            Stacking::Quartal(count) => (0..*count).map(|index| 3 * index).collect(),
+            Stacking::Secundal(count) => (0..*count).collect(),
+        }
+    }
+}
+
+/// Chord built on a degree of a scale with the notes of the scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiatonicChord {
+    degree: usize,
+    chord: Chord,
+    quality: Option<ChordQuality>,
+    numeral: Option<RomanNumeral>,
+}
+
+impl DiatonicChord {
+    /// Gives the degree of the root of the chord (1 for the tonic).
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// assert_eq!(scale.diatonic_chord(5, Stacking::Triad, 3).degree(), 5);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+    /// Gives the chord, in root position.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let chord = scale.diatonic_chord(2, Stacking::Seventh, 3);
+    /// assert!(chord.chord().strict_eq(&"D3 F3 A3 C4".parse::<Chord>().unwrap()));
+    /// ```
+    pub fn chord(&self) -> &Chord {
+        &self.chord
+    }
+    /// Gives the quality of the chord, or `None` when no quality has its root on the degree.
+    /// ```
+    /// use music::{ChordQuality, Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let quality = |degree, stacking| scale.diatonic_chord(degree, stacking, 3).quality();
+    /// assert_eq!(quality(7, Stacking::Seventh), Some(ChordQuality::HalfDiminishedSeventh));
+    /// assert_eq!(quality(5, Stacking::Thirteenth), Some(ChordQuality::DominantThirteenth));
+    /// assert_eq!(quality(1, Stacking::Eleventh), Some(ChordQuality::MajorEleventh));
+    /// assert_eq!(quality(3, Stacking::Eleventh), None);
+    /// ```
+    pub fn quality(&self) -> Option<ChordQuality> {
+        self.quality
+    }
+    /// Gives the Roman numeral of the chord in the key of the scale
+    /// (see [`Scale::try_roman_numeral`]), or `None` when its quality is not tertian.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numeral = |degree, stacking| {
+    ///     scale
+    ///         .diatonic_chord(degree, stacking, 3)
+    ///         .numeral()
+    ///         .map(|numeral| numeral.to_string())
+    /// };
+    /// assert_eq!(numeral(2, Stacking::Ninth), Some("ii9".to_string()));
+    /// assert_eq!(numeral(1, Stacking::Quartal(3)), None);
+    /// ```
+    pub fn numeral(&self) -> Option<RomanNumeral> {
+        self.numeral
+    }
+}
+
+impl Scale {
+    /// Builds the chord stacked on a degree of the scale (see [`Scale::try_diatonic_chord`]).
+    /// # Panics
+    /// It panics when the degree is 0, when a stack has no sound
+    /// or when a sound is outside the pitch space.
+    /// ```
+    /// use music::{Chord, Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+    /// let chord = scale.diatonic_chord(5, Stacking::Seventh, 2);
+    /// assert!(chord.chord().strict_eq(&"E3 G#3 B3 D4".parse::<Chord>().unwrap()));
+    /// assert_eq!(chord.numeral().unwrap().to_string(), "V7");
+    /// ```
+    pub fn diatonic_chord(
+        &self,
+        degree: usize,
+        stacking: Stacking,
+        octave: isize,
+    ) -> DiatonicChord {
+        self.try_diatonic_chord(degree, stacking, octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Builds the chord stacked on a degree of the scale with the notes of the scale,
+    /// its root being the note of the degree (see [`Scale::note_at`]) above the tonic
+    /// in an octave. It fails when the degree is 0, when a stack has no sound
+    /// or when a sound is outside the pitch space.
+    /// ```
+    /// use music::{Chord, MusicError, Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::D, ScaleType::Dorian);
+    /// let chord = scale.try_diatonic_chord(1, Stacking::Quartal(4), 3).unwrap();
+    /// assert!(chord.chord().strict_eq(&"D3 G3 C4 F4".parse::<Chord>().unwrap()));
+    /// assert_eq!(
+    ///     scale.try_diatonic_chord(0, Stacking::Triad, 3),
+    ///     Err(MusicError::InvalidDegree)
+    /// );
+    /// assert_eq!(
+    ///     scale.try_diatonic_chord(1, Stacking::Secundal(0), 3),
+    ///     Err(MusicError::TooFewSounds)
+    /// );
+    /// ```
+    pub fn try_diatonic_chord(
+        &self,
+        degree: usize,
+        stacking: Stacking,
+        octave: isize,
+    ) -> Result<DiatonicChord, MusicError> {
+        if degree == 0 {
+            return Err(MusicError::InvalidDegree);
+        }
+        let steps = stacking.steps();
+        if steps.is_empty() {
+            return Err(MusicError::TooFewSounds);
+        }
+        let tonic = Sound::try_init_spelled(SpelledNote::from(self.tonic()), octave)?;
+        let root = tonic.try_transpose_diatonic(degree as isize - 1, self)?;
+        let sounds = steps
+            .iter()
+            .map(|step| root.try_transpose_diatonic(*step as isize, self))
+            .collect::<Result<Vec<Sound>, MusicError>>()?;
+        let chord = Chord::from_vec(sounds);
+        let root = root.spelled_note();
+        let quality = chord
+            .identify()
+            .into_iter()
+            .find(|candidate| candidate.root().to_usize() == root.to_usize())
+            .map(|candidate| candidate.quality());
+        let numeral = quality
+            .filter(|quality| is_analyzable(*quality))
+            .map(|quality| self.numeral(root, quality));
+        Ok(DiatonicChord {
+            degree,
+            chord,
+            quality,
+            numeral,
+        })
+    }
+    /// Builds the chords stacked on every degree of the scale
+    /// (see [`Scale::try_diatonic_chords`]).
+    /// # Panics
+    /// It panics when a stack has no sound or when a sound is outside the pitch space.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::C, ScaleType::Major);
+    /// let numerals: Vec<String> = scale
+    ///     .diatonic_chords(Stacking::Triad, 3)
+    ///     .iter()
+    ///     .map(|chord| chord.numeral().unwrap().to_string())
+    ///     .collect();
+    /// assert_eq!(numerals, vec!["I", "ii", "iii", "IV", "V", "vi", "vii°"]);
+    /// ```
+    pub fn diatonic_chords(&self, stacking: Stacking, octave: isize) -> Vec<DiatonicChord> {
+        self.try_diatonic_chords(stacking, octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Builds the chords stacked on every degree of the scale, from the tonic upwards
+    /// (see [`Scale::try_diatonic_chord`]).
+    /// ```
+    /// use music::{ChordQuality, Note, Scale, ScaleType, Stacking};
+    /// let scale = Scale::init(Note::G, ScaleType::MajorPentatonic);
+    /// let chords = scale.try_diatonic_chords(Stacking::Triad, 3).unwrap();
+    /// assert_eq!(chords.len(), 5);
+    /// assert_eq!(chords[0].quality(), Some(ChordQuality::Sixth));
+    /// assert!(scale.try_diatonic_chords(Stacking::Triad, 200).is_err());
+    /// ```
+    pub fn try_diatonic_chords(
+        &self,
+        stacking: Stacking,
+        octave: isize,
+    ) -> Result<Vec<DiatonicChord>, MusicError> {
+        (1..=self.spelled_notes().len())
+            .map(|degree| self.try_diatonic_chord(degree, stacking, octave))
+            .collect()
+    }
+}
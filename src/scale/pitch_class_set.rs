@@ -0,0 +1,258 @@
+use std::fmt;
+
+use crate::{MusicError, ParseError, ParseErrorKind, ScaleType};
+
+/// Set of pitch classes above the tonic of a scale, the tonic (0) always belonging to it.
+///
+/// It is stored as a 12-bit mask whose bit `n` is set when the pitch class `n`
+/// half tones above the tonic belongs to the set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PitchClassSet {
+    mask: u16,
+}
+
+impl PitchClassSet {
+    /// Creates a set from its 12-bit mask, bit `n` standing for `n` half tones above the tonic.
+    /// # Panics
+    /// It panics when the mask has bits above the twelfth one or does not contain the tonic.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_mask(0b1010_1011_0101);
+    /// assert_eq!(set.offsets(), vec![0, 2, 4, 5, 7, 9, 11]);
+    /// ```
+    pub fn from_mask(mask: u16) -> Self {
+        Self::try_from_mask(mask).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a set from its 12-bit mask, failing when the mask has bits above the twelfth
+    /// one or does not contain the tonic.
+    /// ```
+    /// use music::{MusicError, PitchClassSet};
+    /// assert!(PitchClassSet::try_from_mask(0b1001_0001).is_ok());
+    /// assert_eq!(
+    ///     PitchClassSet::try_from_mask(0b1001_0000),
+    ///     Err(MusicError::InvalidPitchClassSet)
+    /// );
+    /// assert_eq!(
+    ///     PitchClassSet::try_from_mask(1 << 12 | 1),
+    ///     Err(MusicError::InvalidPitchClassSet)
+    /// );
+    /// ```
+    pub fn try_from_mask(mask: u16) -> Result<Self, MusicError> {
+        if mask & 1 == 0 || mask >> 12 != 0 {
+            return Err(MusicError::InvalidPitchClassSet);
+        }
+        Ok(Self { mask })
+    }
+    /// Creates a set from the half tones of its degrees above the tonic.
+    /// # Panics
+    /// It panics when an offset is repeated, is not below 12, or when 0 is missing.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_offsets(&[0, 7, 3, 5, 10]);
+    /// assert_eq!(set.offsets(), vec![0, 3, 5, 7, 10]);
+    /// ```
+    pub fn from_offsets(offsets: &[usize]) -> Self {
+        Self::try_from_offsets(offsets).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a set from the half tones of its degrees above the tonic, in any order,
+    /// failing when an offset is repeated, is not below 12, or when 0 is missing.
+    /// ```
+    /// use music::{MusicError, PitchClassSet};
+    /// assert!(PitchClassSet::try_from_offsets(&[0, 1, 4, 5, 7, 8, 10]).is_ok());
+    /// let error = Err(MusicError::InvalidPitchClassSet);
+    /// assert_eq!(PitchClassSet::try_from_offsets(&[0, 4, 4, 7]), error);
+    /// assert_eq!(PitchClassSet::try_from_offsets(&[0, 4, 12]), error);
+    /// assert_eq!(PitchClassSet::try_from_offsets(&[2, 4, 7]), error);
+    /// ```
+    pub fn try_from_offsets(offsets: &[usize]) -> Result<Self, MusicError> {
+        let mut mask = 0u16;
+        for offset in offsets {
+            if *offset >= 12 || mask & 1 << offset != 0 {
+                return Err(MusicError::InvalidPitchClassSet);
+            }
+            mask |= 1 << offset;
+        }
+        Self::try_from_mask(mask)
+    }
+    /// Creates a set from the half tones between its consecutive degrees,
+    /// the last step going back to the tonic an octave higher.
+    /// # Panics
+    /// It panics when the steps are not positive or do not add up to an octave.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_steps(&[2, 1, 2, 1, 2, 1, 2, 1]);
+    /// assert_eq!(set.offsets(), vec![0, 2, 3, 5, 6, 8, 9, 11]);
+    /// ```
+    pub fn from_steps(steps: &[usize]) -> Self {
+        Self::try_from_steps(steps).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a set from the half tones between its consecutive degrees,
+    /// failing when the steps are not positive or do not add up to an octave.
+    /// ```
+    /// use music::{MusicError, PitchClassSet};
+    /// assert!(PitchClassSet::try_from_steps(&[3, 2, 2, 3, 2]).is_ok());
+    /// assert_eq!(
+    ///     PitchClassSet::try_from_steps(&[2, 2, 2]),
+    ///     Err(MusicError::InvalidScaleSteps)
+    /// );
+    /// ```
+    pub fn try_from_steps(steps: &[usize]) -> Result<Self, MusicError> {
+        if steps.contains(&0) || steps.iter().sum::<usize>() != 12 {
+            return Err(MusicError::InvalidScaleSteps);
+        }
+        let offsets: Vec<usize> = steps
+            .iter()
+            .scan(0, |offset, step| {
+                let current = *offset;
+                *offset += step;
+                Some(current)
+            })
+            .collect();
+        Self::try_from_offsets(&offsets)
+    }
+    /// Creates a set from a step pattern (see [`PitchClassSet::try_from_step_pattern`]).
+    /// # Panics
+    /// It panics when the pattern is invalid or does not add up to an octave.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_step_pattern("W W H W W W H");
+    /// assert_eq!(set, PitchClassSet::from_steps(&[2, 2, 1, 2, 2, 2, 1]));
+    /// ```
+    pub fn from_step_pattern(pattern: &str) -> Self {
+        Self::try_from_step_pattern(pattern).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a set from a step pattern, where W (or T) stands for a whole tone,
+    /// H (or S) for a half tone, A for an augmented second and a number for a number
+    /// of half tones, the steps being possibly separated by spaces, commas or dashes
+    /// (consecutive digits making up a single number). It fails when a character
+    /// is not a step or when the steps do not add up to an octave.
+    /// ```
+    /// use music::{MusicError, ParseErrorKind, PitchClassSet};
+    /// let set = PitchClassSet::try_from_step_pattern("H-A-H-W-H-A-H").unwrap();
+    /// assert_eq!(set.offsets(), vec![0, 1, 4, 5, 7, 8, 11]);
+    /// assert_eq!(PitchClassSet::try_from_step_pattern("3 2 2 3 2"), Ok(PitchClassSet::from_offsets(&[0, 3, 5, 7, 10])));
+    /// assert_eq!(PitchClassSet::try_from_step_pattern("H 11"), Ok(PitchClassSet::from_offsets(&[0, 1])));
+    /// let error = PitchClassSet::try_from_step_pattern("W W X");
+    /// assert!(matches!(
+    ///     error,
+    ///     Err(MusicError::Parse(error)) if error.kind() == ParseErrorKind::UnexpectedCharacter('X')
+    /// ));
+    /// assert_eq!(
+    ///     PitchClassSet::try_from_step_pattern("WWW"),
+    ///     Err(MusicError::InvalidScaleSteps)
+    /// );
+    /// ```
+    pub fn try_from_step_pattern(pattern: &str) -> Result<Self, MusicError> {
+        let mut steps = Vec::new();
+        let mut chars = pattern.chars().enumerate().peekable();
+        while let Some((position, c)) = chars.next() {
+            let step = match c {
+                'W' | 'w' | 'T' | 't' => 2,
+                'H' | 'h' | 'S' | 's' => 1,
+                'A' | 'a' => 3,
+                '0'..='9' => {
+                    let mut number = c as usize - '0' as usize;
+                    while let Some((_, digit)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        number = 10 * number + (digit as usize - '0' as usize);
+                        if number > 12 {
+                            return Err(MusicError::InvalidScaleSteps);
+                        }
+                    }
+                    number
+                }
+                ' ' | ',' | '-' => continue,
+                _ => {
+                    let kind = ParseErrorKind::UnexpectedCharacter(c);
+                    return Err(ParseError::init(pattern, position, kind).into());
+                }
+            };
+            steps.push(step);
+        }
+        if steps.is_empty() {
+            return Err(ParseError::init(pattern, 0, ParseErrorKind::Empty).into());
+        }
+        Self::try_from_steps(&steps)
+    }
+    /// Gives the 12-bit mask of the set.
+    /// ```
+    /// use music::PitchClassSet;
+    /// assert_eq!(PitchClassSet::from_offsets(&[0, 4, 7]).mask(), 0b1001_0001);
+    /// ```
+    pub fn mask(&self) -> u16 {
+        self.mask
+    }
+    /// Gives the half tones of the degrees above the tonic, in ascending order.
+    /// ```
+    /// use music::PitchClassSet;
+    /// assert_eq!(PitchClassSet::from_steps(&[4, 4, 4]).offsets(), vec![0, 4, 8]);
+    /// ```
+    pub fn offsets(&self) -> Vec<usize> {
+        (0..12).filter(|offset| self.contains(*offset)).collect()
+    }
+    /// Gives the half tones between consecutive degrees, the last step going back
+    /// to the tonic an octave higher.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_offsets(&[0, 2, 4, 7, 9]);
+    /// assert_eq!(set.steps(), vec![2, 2, 3, 2, 3]);
+    /// ```
+    pub fn steps(&self) -> Vec<usize> {
+        let offsets = self.offsets();
+        offsets
+            .iter()
+            .zip(offsets.iter().skip(1).chain(std::iter::once(&12)))
+            .map(|(offset, next)| next - offset)
+            .collect()
+    }
+    /// Tests whether or not a pitch class, given by its half tones above the tonic
+    /// (modulo 12), belongs to the set.
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_offsets(&[0, 4, 7]);
+    /// assert!(set.contains(4));
+    /// assert!(set.contains(19));
+    /// assert!(!set.contains(3));
+    /// ```
+    pub fn contains(&self, offset: usize) -> bool {
+        self.mask & 1 << (offset % 12) != 0
+    }
+    /// Gives the scale type of the catalog with the same pitch classes,
+    /// or a custom scale type when there is none.
+    /// ```
+    /// use music::{PitchClassSet, ScaleType};
+    /// let set = PitchClassSet::from_step_pattern("W H W W H W W");
+    /// assert_eq!(set.scale_type(), ScaleType::NaturalMinor);
+    /// let set = PitchClassSet::from_step_pattern("H H H 9");
+    /// assert_eq!(set.scale_type(), ScaleType::Custom(set));
+    /// ```
+    pub fn scale_type(&self) -> ScaleType {
+        ScaleType::ALL
+            .into_iter()
+            .find(|scale_type| scale_type.pitch_class_set() == *self)
+            .unwrap_or(ScaleType::Custom(*self))
+    }
+}
+
+impl fmt::Display for PitchClassSet {
+    /// Writes the step pattern of the set (see [`PitchClassSet::try_from_step_pattern`]).
+    /// ```
+    /// use music::PitchClassSet;
+    /// let set = PitchClassSet::from_offsets(&[0, 2, 3, 5, 7, 8, 11]);
+    /// assert_eq!(set.to_string(), "W H W W H A H");
+    /// assert_eq!(PitchClassSet::from_steps(&[5, 7]).to_string(), "5 7");
+    /// assert_eq!(PitchClassSet::from_steps(&[1, 11]).to_string(), "H 11");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let steps: Vec<String> = self
+            .steps()
+            .iter()
+            .map(|step| match step {
+                1 => "H".to_string(),
+                2 => "W".to_string(),
+                3 => "A".to_string(),
+                _ => step.to_string(),
+            })
+            .collect();
+        write!(f, "{}", steps.join(" "))
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+
+use crate::PitchClassSet;
+
+/// Type of a scale, given by the half tones of its degrees above the tonic.
+/// Besides the catalog, a custom scale type can have any set of pitch classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleType {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Locrian,
+    DorianFlatSecond,
+    LydianAugmented,
+    LydianDominant,
+    MixolydianFlatSixth,
+    LocrianNaturalSecond,
+    Altered,
+    LocrianNaturalSixth,
+    IonianAugmented,
+    DorianSharpFourth,
+    PhrygianDominant,
+    LydianSharpSecond,
+    Ultralocrian,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    MajorBlues,
+    WholeTone,
+    OctatonicHalfWhole,
+    OctatonicWholeHalf,
+    Chromatic,
+    BebopDominant,
+    BebopMajor,
+    BebopDorian,
+    BebopMelodicMinor,
+    MessiaenThird,
+    MessiaenFourth,
+    MessiaenFifth,
+    MessiaenSixth,
+    MessiaenSeventh,
+    /// Former name of the harmonic minor scale, left out of [`ScaleType::ALL`].
+    /// Scales built with it are harmonic minor scales (see [`crate::Scale::init`]),
+    /// but as a scale type it is not equal to [`ScaleType::HarmonicMinor`]
+    /// and it is never found by [`ScaleType::from_name`] or [`crate::PitchClassSet::scale_type`].
+    #[deprecated(note = "use ScaleType::HarmonicMinor or ScaleType::NaturalMinor")]
+    Minor,
+    Custom(PitchClassSet),
+}
+
+/// Description of a scale type: its name, the other accepted names,
+/// and the half tones of its degrees above the tonic.
+struct Description {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    intervals: &'static [usize],
+}
+
+impl ScaleType {
+    /// All the scale types: major and minor scales, church modes, modes of the melodic
+    /// and harmonic minor scales, pentatonic and blues scales, symmetric scales,
+    /// bebop scales and the remaining modes of limited transposition (custom scale types
+    /// excepted).
+    pub const ALL: [ScaleType; 38] = [
+        ScaleType::Major,
+        ScaleType::NaturalMinor,
+        ScaleType::HarmonicMinor,
+        ScaleType::MelodicMinor,
+        ScaleType::Dorian,
+        ScaleType::Phrygian,
+        ScaleType::Lydian,
+        ScaleType::Mixolydian,
+        ScaleType::Locrian,
+        ScaleType::DorianFlatSecond,
+        ScaleType::LydianAugmented,
+        ScaleType::LydianDominant,
+        ScaleType::MixolydianFlatSixth,
+        ScaleType::LocrianNaturalSecond,
+        ScaleType::Altered,
+        ScaleType::LocrianNaturalSixth,
+        ScaleType::IonianAugmented,
+        ScaleType::DorianSharpFourth,
+        ScaleType::PhrygianDominant,
+        ScaleType::LydianSharpSecond,
+        ScaleType::Ultralocrian,
+        ScaleType::MajorPentatonic,
+        ScaleType::MinorPentatonic,
+        ScaleType::Blues,
+        ScaleType::MajorBlues,
+        ScaleType::WholeTone,
+        ScaleType::OctatonicHalfWhole,
+        ScaleType::OctatonicWholeHalf,
+        ScaleType::Chromatic,
+        ScaleType::BebopDominant,
+        ScaleType::BebopMajor,
+        ScaleType::BebopDorian,
+        ScaleType::BebopMelodicMinor,
+        ScaleType::MessiaenThird,
+        ScaleType::MessiaenFourth,
+        ScaleType::MessiaenFifth,
+        ScaleType::MessiaenSixth,
+        ScaleType::MessiaenSeventh,
+    ];
+
+    #[allow(deprecated)]
+    fn description(&self) -> Option<Description> {
+        let (name, aliases, intervals): (_, &[&str], &[usize]) = match self {
+            ScaleType::Major => ("major", &["ionian"], &[0, 2, 4, 5, 7, 9, 11]),
+            ScaleType::NaturalMinor => (
+                "natural minor",
+                &["minor", "aeolian"],
+                &[0, 2, 3, 5, 7, 8, 10],
+            ),
+            ScaleType::HarmonicMinor | ScaleType::Minor => {
+                ("harmonic minor", &[], &[0, 2, 3, 5, 7, 8, 11])
+            }
+            ScaleType::MelodicMinor => ("melodic minor", &["jazz minor"], &[0, 2, 3, 5, 7, 9, 11]),
+            ScaleType::Dorian => ("dorian", &[], &[0, 2, 3, 5, 7, 9, 10]),
+            ScaleType::Phrygian => ("phrygian", &[], &[0, 1, 3, 5, 7, 8, 10]),
+            ScaleType::Lydian => ("lydian", &[], &[0, 2, 4, 6, 7, 9, 11]),
+            ScaleType::Mixolydian => ("mixolydian", &["dominant"], &[0, 2, 4, 5, 7, 9, 10]),
+            ScaleType::Locrian => ("locrian", &[], &[0, 1, 3, 5, 6, 8, 10]),
+            ScaleType::DorianFlatSecond => (
+                "dorian ♭2",
+                &["dorian b2", "phrygian ♮6", "phrygian natural 6"],
+                &[0, 1, 3, 5, 7, 9, 10],
+            ),
+            ScaleType::LydianAugmented => {
+                ("lydian augmented", &["lydian #5"], &[0, 2, 4, 6, 8, 9, 11])
+            }
+            ScaleType::LydianDominant => (
+                "lydian dominant",
+                &["lydian ♭7", "lydian b7", "overtone", "acoustic"],
+                &[0, 2, 4, 6, 7, 9, 10],
+            ),
+            ScaleType::MixolydianFlatSixth => (
+                "mixolydian ♭6",
+                &["mixolydian b6", "aeolian dominant", "melodic major"],
+                &[0, 2, 4, 5, 7, 8, 10],
+            ),
+            ScaleType::LocrianNaturalSecond => (
+                "locrian ♮2",
+                &[
+                    "locrian natural 2",
+                    "half diminished",
+                    "aeolian ♭5",
+                    "aeolian b5",
+                ],
+                &[0, 2, 3, 5, 6, 8, 10],
+            ),
+            ScaleType::Altered => (
+                "altered",
+                &["super locrian", "diminished whole tone"],
+                &[0, 1, 3, 4, 6, 8, 10],
+            ),
+            ScaleType::LocrianNaturalSixth => (
+                "locrian ♮6",
+                &["locrian natural 6"],
+                &[0, 1, 3, 5, 6, 9, 10],
+            ),
+            ScaleType::IonianAugmented => {
+                ("ionian augmented", &["ionian #5"], &[0, 2, 4, 5, 8, 9, 11])
+            }
+            ScaleType::DorianSharpFourth => (
+                "dorian #4",
+                &["ukrainian dorian", "romanian minor"],
+                &[0, 2, 3, 6, 7, 9, 10],
+            ),
+            ScaleType::PhrygianDominant => (
+                "phrygian dominant",
+                &["spanish phrygian", "freygish"],
+                &[0, 1, 4, 5, 7, 8, 10],
+            ),
+            ScaleType::LydianSharpSecond => ("lydian #2", &[], &[0, 3, 4, 6, 7, 9, 11]),
+            ScaleType::Ultralocrian => (
+                "ultralocrian",
+                &["altered diminished"],
+                &[0, 1, 3, 4, 6, 8, 9],
+            ),
+            ScaleType::MajorPentatonic => ("major pentatonic", &["pentatonic"], &[0, 2, 4, 7, 9]),
+            ScaleType::MinorPentatonic => ("minor pentatonic", &[], &[0, 3, 5, 7, 10]),
+            ScaleType::Blues => ("blues", &["minor blues"], &[0, 3, 5, 6, 7, 10]),
+            ScaleType::MajorBlues => ("major blues", &[], &[0, 2, 3, 4, 7, 9]),
+            ScaleType::WholeTone => ("whole tone", &["messiaen mode 1"], &[0, 2, 4, 6, 8, 10]),
+            ScaleType::OctatonicHalfWhole => (
+                "octatonic half-whole",
+                &[
+                    "half-whole diminished",
+                    "dominant diminished",
+                    "messiaen mode 2",
+                ],
+                &[0, 1, 3, 4, 6, 7, 9, 10],
+            ),
+            ScaleType::OctatonicWholeHalf => (
+                "octatonic whole-half",
+                &["octatonic", "diminished", "whole-half diminished"],
+                &[0, 2, 3, 5, 6, 8, 9, 11],
+            ),
+            ScaleType::Chromatic => ("chromatic", &[], &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+            ScaleType::BebopDominant => ("bebop dominant", &["bebop"], &[0, 2, 4, 5, 7, 9, 10, 11]),
+            ScaleType::BebopMajor => ("bebop major", &[], &[0, 2, 4, 5, 7, 8, 9, 11]),
+            ScaleType::BebopDorian => {
+                ("bebop dorian", &["bebop minor"], &[0, 2, 3, 4, 5, 7, 9, 10])
+            }
+            ScaleType::BebopMelodicMinor => {
+                ("bebop melodic minor", &[], &[0, 2, 3, 5, 7, 8, 9, 11])
+            }
+            ScaleType::MessiaenThird => ("messiaen mode 3", &[], &[0, 2, 3, 4, 6, 7, 8, 10, 11]),
+            ScaleType::MessiaenFourth => ("messiaen mode 4", &[], &[0, 1, 2, 5, 6, 7, 8, 11]),
+            ScaleType::MessiaenFifth => ("messiaen mode 5", &[], &[0, 1, 5, 6, 7, 11]),
+            ScaleType::MessiaenSixth => ("messiaen mode 6", &[], &[0, 2, 4, 5, 6, 8, 10, 11]),
+            ScaleType::MessiaenSeventh => {
+                ("messiaen mode 7", &[], &[0, 1, 2, 3, 5, 6, 7, 8, 9, 11])
+            }
+            ScaleType::Custom(_) => return None,
+        };
+        Some(Description {
+            name,
+            aliases,
+            intervals,
+        })
+    }
+    /// Gives the name of the scale type, "custom" for custom scale types.
+    /// ```
+    /// use music::{PitchClassSet, ScaleType};
+    /// assert_eq!(ScaleType::LydianDominant.name(), "lydian dominant");
+    /// let set = PitchClassSet::from_offsets(&[0, 1, 4, 6, 8, 10, 11]);
+    /// assert_eq!(ScaleType::Custom(set).name(), "custom");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.description()
+            .map_or("custom", |description| description.name)
+    }
+    /// Gives the other names which are commonly used for the scale type.
+    /// ```
+    /// use music::ScaleType;
+    /// assert!(ScaleType::Altered.aliases().contains(&"super locrian"));
+    /// ```
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.description()
+            .map_or(&[], |description| description.aliases)
+    }
+    /// Gives the half tones of the degrees above the tonic, starting with 0.
+    /// ```
+    /// use music::ScaleType;
+    /// assert_eq!(ScaleType::HarmonicMinor.intervals(), vec![0, 2, 3, 5, 7, 8, 11]);
+    /// assert_eq!(ScaleType::WholeTone.intervals().len(), 6);
+    /// ```
+    pub fn intervals(&self) -> Vec<usize> {
+        self.description().map_or_else(
+            || self.pitch_class_set().offsets(),
+            |description| description.intervals.to_vec(),
+        )
+    }
+    /// Gives the set of pitch classes of the scale type.
+    /// ```
+    /// use music::{PitchClassSet, ScaleType};
+    /// let set = ScaleType::MajorPentatonic.pitch_class_set();
+    /// assert_eq!(set, PitchClassSet::from_step_pattern("W W A W A"));
+    /// ```
+    pub fn pitch_class_set(&self) -> PitchClassSet {
+        match self {
+            ScaleType::Custom(set) => *set,
+            _ => PitchClassSet::from_offsets(&self.intervals()),
+        }
+    }
+    /// Tests whether or not the scale has a minor third and no major third above its tonic,
+    /// its harmony being then that of a minor key.
+    /// ```
+    /// use music::ScaleType;
+    /// assert!(ScaleType::Dorian.is_minor());
+    /// assert!(!ScaleType::Mixolydian.is_minor());
+    /// assert!(!ScaleType::OctatonicHalfWhole.is_minor());
+    /// ```
+    pub fn is_minor(&self) -> bool {
+        let intervals = self.intervals();
+        intervals.contains(&3) && !intervals.contains(&4)
+    }
+    /// Finds the scale type with a name (or one of its aliases), ignoring case,
+    /// spaces, hyphens and underscores.
+    /// ```
+    /// use music::ScaleType;
+    /// assert_eq!(ScaleType::from_name("Super-Locrian"), Some(ScaleType::Altered));
+    /// assert_eq!(ScaleType::from_name("aeolian"), Some(ScaleType::NaturalMinor));
+    /// assert_eq!(ScaleType::from_name("wholetone"), Some(ScaleType::WholeTone));
+    /// assert_eq!(ScaleType::from_name("xyz"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<ScaleType> {
+        let name = normalize(name);
+        ScaleType::ALL.into_iter().find(|scale_type| {
+            normalize(scale_type.name()) == name
+                || scale_type
+                    .aliases()
+                    .iter()
+                    .any(|alias| normalize(alias) == name)
+        })
+    }
+}
+
+impl fmt::Display for ScaleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleType::Custom(set) => write!(f, "custom ({set})"),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// Lowers the case of a name and removes its spaces, hyphens and underscores.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::{Note, Scale, ScaleType, Sound};
+    use crate::{
+        Chord, DegreeName, KraehenbuehlKnuth, Letter, MusicError, Note, PitchClassSet,
+        RomanNumeral, Scale, ScaleType, Sound, SpelledNote, Stacking, Transpose,
+    };
 
     #[test]
     fn test_notes() {
@@ -21,7 +24,7 @@ mod tests {
         ];
         assert_eq!(scale.sounds(4), expected_scale_sounds);
 
-        let scale = Scale::init(Note::A, ScaleType::Minor);
+        let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
         let expected_scale_sounds = vec![
             Sound::init(Note::A, 2),
             Sound::init(Note::B, 2),
@@ -33,4 +36,347 @@ mod tests {
         ];
         assert_eq!(scale.sounds(2), expected_scale_sounds);
     }
+    #[test]
+    fn test_spelling() {
+        let scale = Scale::init(Note::Fs, ScaleType::Major);
+        let expected_notes = [
+            Note::Fs,
+            Note::Gs,
+            Note::As,
+            Note::B,
+            Note::Cs,
+            Note::Ds,
+            Note::Es,
+        ];
+        for (note, expected_note) in scale.notes().iter().zip(expected_notes) {
+            assert!(note.strict_eq(&expected_note));
+        }
+        assert_eq!(scale.key_signature(), 6);
+
+        let scale = Scale::init(Note::Gb, ScaleType::Major);
+        assert_eq!(scale.spelled_notes()[3], SpelledNote::init(Letter::C, -1));
+        assert!(scale.sounds(4)[3].strict_eq(&Sound::init(Note::Cb, 5)));
+
+        let scale = Scale::init(Note::Eb, ScaleType::Major);
+        let melody = vec![
+            Sound::init(Note::C, 5),
+            Sound::init(Note::As, 4),
+            Sound::init(Note::Gs, 4),
+            Sound::init(Note::G, 4),
+        ];
+        let mut harmonizer = KraehenbuehlKnuth::init(melody, Some(scale));
+        for chord in harmonizer.harmonize() {
+            assert!(chord
+                .spelled_notes()
+                .iter()
+                .all(|note| note.accidental() <= 0));
+        }
+    }
+    #[test]
+    fn test_scale_types() {
+        for scale_type in ScaleType::ALL {
+            let intervals = scale_type.intervals();
+            assert_eq!(intervals[0], 0);
+            assert!(intervals.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(intervals.iter().all(|interval| *interval < 12));
+            assert_eq!(ScaleType::from_name(scale_type.name()), Some(scale_type));
+            for alias in scale_type.aliases() {
+                assert_eq!(ScaleType::from_name(alias), Some(scale_type));
+            }
+        }
+        assert_eq!(
+            ScaleType::from_name("Phrygian Dominant"),
+            Some(ScaleType::PhrygianDominant)
+        );
+        assert_eq!(ScaleType::PhrygianDominant.to_string(), "phrygian dominant");
+        #[allow(deprecated)]
+        let minor = ScaleType::Minor;
+        assert_eq!(minor.intervals(), ScaleType::HarmonicMinor.intervals());
+        assert_eq!(
+            Scale::init(Note::A, minor),
+            Scale::init(Note::A, ScaleType::HarmonicMinor)
+        );
+        assert_ne!(minor, ScaleType::HarmonicMinor);
+        assert_eq!(
+            minor.pitch_class_set().scale_type(),
+            ScaleType::HarmonicMinor
+        );
+        assert!(!ScaleType::ALL.contains(&minor));
+    }
+    #[test]
+    fn test_modes() {
+        // The modes of a scale start on each of its degrees.
+        let rotate = |scale_type: ScaleType, degree: usize| -> Vec<usize> {
+            let intervals = scale_type.intervals();
+            let mut mode: Vec<usize> = intervals
+                .iter()
+                .map(|interval| (interval + 12 - intervals[degree]) % 12)
+                .collect();
+            mode.rotate_left(degree);
+            mode
+        };
+        let families = [
+            (
+                ScaleType::Major,
+                [
+                    ScaleType::Major,
+                    ScaleType::Dorian,
+                    ScaleType::Phrygian,
+                    ScaleType::Lydian,
+                    ScaleType::Mixolydian,
+                    ScaleType::NaturalMinor,
+                    ScaleType::Locrian,
+                ],
+            ),
+            (
+                ScaleType::MelodicMinor,
+                [
+                    ScaleType::MelodicMinor,
+                    ScaleType::DorianFlatSecond,
+                    ScaleType::LydianAugmented,
+                    ScaleType::LydianDominant,
+                    ScaleType::MixolydianFlatSixth,
+                    ScaleType::LocrianNaturalSecond,
+                    ScaleType::Altered,
+                ],
+            ),
+            (
+                ScaleType::HarmonicMinor,
+                [
+                    ScaleType::HarmonicMinor,
+                    ScaleType::LocrianNaturalSixth,
+                    ScaleType::IonianAugmented,
+                    ScaleType::DorianSharpFourth,
+                    ScaleType::PhrygianDominant,
+                    ScaleType::LydianSharpSecond,
+                    ScaleType::Ultralocrian,
+                ],
+            ),
+        ];
+        for (parent, modes) in families {
+            for (degree, mode) in modes.iter().enumerate() {
+                assert_eq!(rotate(parent, degree), mode.intervals());
+            }
+        }
+        assert_eq!(
+            rotate(ScaleType::MajorPentatonic, 4),
+            ScaleType::MinorPentatonic.intervals()
+        );
+        assert_eq!(
+            rotate(ScaleType::OctatonicHalfWhole, 1),
+            ScaleType::OctatonicWholeHalf.intervals()
+        );
+    }
+    #[test]
+    fn test_catalog_spelling() {
+        let spelled = |note: Note, scale_type: ScaleType| -> Vec<String> {
+            Scale::init(note, scale_type)
+                .spelled_notes()
+                .iter()
+                .map(|note| note.to_string())
+                .collect()
+        };
+        assert_eq!(
+            spelled(Note::A, ScaleType::NaturalMinor),
+            ["A", "B", "C", "D", "E", "F", "G"]
+        );
+        assert_eq!(
+            spelled(Note::A, ScaleType::MelodicMinor),
+            ["A", "B", "C", "D", "E", "F#", "G#"]
+        );
+        assert_eq!(
+            spelled(Note::G, ScaleType::Altered),
+            ["G", "A♭", "B♭", "C♭", "D♭", "E♭", "F"]
+        );
+        assert_eq!(
+            spelled(Note::C, ScaleType::OctatonicHalfWhole),
+            ["C", "D♭", "E♭", "E", "F#", "G", "A", "B♭"]
+        );
+        assert_eq!(
+            spelled(Note::E, ScaleType::MinorPentatonic),
+            ["E", "G", "A", "B", "D"]
+        );
+        assert_eq!(
+            Scale::init(Note::C, ScaleType::Chromatic).sounds(4).len(),
+            12
+        );
+        assert_eq!(Scale::init(Note::E, ScaleType::Phrygian).key_signature(), 0);
+        assert_eq!(Scale::init(Note::Bb, ScaleType::Lydian).key_signature(), -1);
+    }
+    #[test]
+    fn test_catalog_lookup() {
+        for scale_type in ScaleType::ALL {
+            let set = scale_type.pitch_class_set();
+            assert_eq!(set.scale_type(), scale_type);
+            assert_eq!(PitchClassSet::from_mask(set.mask()), set);
+            assert_eq!(PitchClassSet::from_steps(&set.steps()), set);
+            assert_eq!(PitchClassSet::from_step_pattern(&set.to_string()), set);
+        }
+    }
+    #[test]
+    fn test_custom_scale() {
+        // Enigmatic scale.
+        let set = PitchClassSet::from_step_pattern("H A W W W H H");
+        let scale_type = set.scale_type();
+        assert_eq!(scale_type, ScaleType::Custom(set));
+        assert_eq!(scale_type.to_string(), "custom (H A W W W H H)");
+        let scale = Scale::init(Note::C, scale_type);
+        let spelled: Vec<String> = scale
+            .spelled_notes()
+            .iter()
+            .map(|note| note.to_string())
+            .collect();
+        assert_eq!(spelled, ["C", "D♭", "E", "F#", "G#", "A#", "B"]);
+        assert!(scale.sounds(4)[6].strict_eq(&Sound::init(Note::B, 4)));
+        assert!(scale.contains(Note::Ab));
+        assert!(!scale.contains(Note::A));
+        // Custom scales of other sizes are spelled chromatically.
+        let scale = Scale::init(
+            Note::E,
+            ScaleType::Custom(PitchClassSet::from_steps(&[5, 2, 5])),
+        );
+        assert_eq!(
+            scale.spelled_notes(),
+            [
+                SpelledNote::init(Letter::E, 0),
+                SpelledNote::init(Letter::A, 0),
+                SpelledNote::init(Letter::B, 0),
+            ]
+        );
+        assert_eq!(scale.key_signature(), 4);
+        assert_eq!(
+            PitchClassSet::try_from_steps(&[0, 12]),
+            Err(MusicError::InvalidScaleSteps)
+        );
+        assert!(matches!(
+            PitchClassSet::try_from_step_pattern(" - "),
+            Err(MusicError::Parse(_))
+        ));
+    }
+    #[test]
+    fn test_step_pattern_round_trip() {
+        for mask in (1..4096u16).filter(|mask| mask & 1 != 0) {
+            let set = PitchClassSet::try_from_mask(mask).unwrap();
+            assert_eq!(
+                PitchClassSet::try_from_step_pattern(&set.to_string()),
+                Ok(set)
+            );
+        }
+        assert_eq!(
+            PitchClassSet::try_from_step_pattern("10,2"),
+            Ok(PitchClassSet::from_offsets(&[0, 10]))
+        );
+        assert_eq!(
+            PitchClassSet::try_from_step_pattern("H 123"),
+            Err(MusicError::InvalidScaleSteps)
+        );
+    }
+    #[test]
+    fn test_degrees() {
+        for scale_type in [ScaleType::Major, ScaleType::MelodicMinor, ScaleType::Blues] {
+            let scale = Scale::init(Note::Db, scale_type);
+            for (position, note) in scale.notes().iter().enumerate() {
+                assert_eq!(scale.degree_of(*note), Some(position + 1));
+                assert!(scale.note_at(position + 1).to_note().strict_eq(note));
+            }
+        }
+        let scale = Scale::init(Note::G, ScaleType::Mixolydian);
+        let names: Vec<String> = (1..=7)
+            .map(|degree| scale.degree_name(degree).unwrap().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "tonic",
+                "supertonic",
+                "mediant",
+                "subdominant",
+                "dominant",
+                "submediant",
+                "subtonic"
+            ]
+        );
+        assert_eq!(scale.degree_name(0), None);
+        assert_eq!(
+            Scale::init(Note::G, ScaleType::Major).degree_name(14),
+            Some(DegreeName::LeadingTone)
+        );
+    }
+    #[test]
+    fn test_diatonic_steps() {
+        // A melody moved up a third of the pentatonic scale, across octaves.
+        let scale = Scale::init(Note::G, ScaleType::MajorPentatonic);
+        let melody: Vec<Sound> = scale.sounds(4);
+        let moved = melody.transpose_diatonic(2, &scale);
+        let expected = [
+            Sound::init(Note::B, 4),
+            Sound::init(Note::D, 5),
+            Sound::init(Note::E, 5),
+            Sound::init(Note::G, 5),
+            Sound::init(Note::A, 5),
+        ];
+        assert_eq!(moved, expected);
+        assert_eq!(
+            Sound::init(Note::F, 4).try_transpose_diatonic(1, &scale),
+            Err(MusicError::SoundNotInScale(Sound::init(Note::F, 4)))
+        );
+    }
+    #[test]
+    fn test_diatonic_chords() {
+        let numerals = |scale: &Scale, stacking| -> Vec<String> {
+            scale
+                .diatonic_chords(stacking, 3)
+                .iter()
+                .map(|chord| match chord.numeral() {
+                    Some(numeral) => numeral.to_string(),
+                    None => "-".to_string(),
+                })
+                .collect()
+        };
+        let scale = Scale::init(Note::C, ScaleType::Major);
+        assert_eq!(
+            numerals(&scale, Stacking::Seventh),
+            ["IM7", "ii7", "iii7", "IVM7", "V7", "vi7", "viiø7"]
+        );
+        assert_eq!(
+            numerals(&scale, Stacking::Ninth),
+            ["IM9", "ii9", "-", "IVM9", "V9", "vi9", "-"]
+        );
+        assert_eq!(
+            numerals(&scale, Stacking::Eleventh),
+            ["IM11", "ii11", "-", "-", "V11", "vi11", "-"]
+        );
+        for chord in scale.diatonic_chords(Stacking::Eleventh, 3) {
+            if let Some(numeral) = chord.numeral() {
+                let parsed: RomanNumeral = numeral.to_string().parse().unwrap();
+                assert_eq!(parsed.quality(), chord.quality().unwrap());
+            }
+        }
+        assert_eq!(
+            numerals(&scale, Stacking::Thirteenth),
+            ["IM13", "ii13", "-", "IVM13", "V13", "-", "-"]
+        );
+        let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+        assert_eq!(
+            numerals(&scale, Stacking::Seventh),
+            ["iM7", "iiø7", "III+M7", "iv7", "V7", "VIM7", "vii°7"]
+        );
+        // The numerals parse back into the same chords.
+        for chord in scale.diatonic_chords(Stacking::Triad, 3) {
+            let numeral = chord.numeral().unwrap();
+            let parsed = scale.chord(&numeral.to_string().parse().unwrap(), 3);
+            assert_eq!(parsed.sounds()[0].note(), chord.chord().sounds()[0].note());
+            assert_eq!(numeral.quality(), chord.quality().unwrap());
+        }
+        let chord = scale.diatonic_chord(9, Stacking::Secundal(3), 3);
+        assert_eq!(chord.degree(), 9);
+        assert!(chord
+            .chord()
+            .strict_eq(&"B4 C5 D5".parse::<Chord>().unwrap()));
+        assert_eq!(chord.quality(), None);
+        assert_eq!(
+            scale.try_diatonic_chord(1, Stacking::Quartal(0), 3),
+            Err(MusicError::TooFewSounds)
+        );
+    }
 }
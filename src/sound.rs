@@ -1,18 +1,46 @@
 #[cfg(test)]
 mod unit_test;
 
-use crate::Note;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::spelling::parse_prefix;
+use crate::tuning::{ratio_to_cents, Tuning, A4_RANGE};
+use crate::{Letter, MusicError, Note, ParseError, ParseErrorKind, SpelledNote};
+
+/// Conventions to name a sound with its octave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PitchNotation {
+    /// Scientific pitch notation, middle C being C4.
+    Scientific,
+    /// Helmholtz pitch notation, middle C being c'.
+    Helmholtz,
+}
 
 /// Representation of a piano key sound.
+///
+/// The octave is attached to the letter of the note, so that B#3 sounds like C4
+/// and C♭4 sounds like B3, as in the scientific pitch notation.
+/// Octaves range from `Sound::MIN_OCTAVE` to `Sound::MAX_OCTAVE`,
+/// so that sub-contra sounds such as A0 can be represented.
 #[derive(Debug, Clone, Copy, Eq)]
 pub struct Sound {
-    note: Note,
-    octave: usize,
+    note: SpelledNote,
+    octave: isize,
 }
 impl Sound {
+    /// Lowest octave of the pitch space.
+    pub const MIN_OCTAVE: isize = i8::MIN as isize;
+    /// Highest octave of the pitch space.
+    pub const MAX_OCTAVE: isize = i8::MAX as isize;
+    /// Range of the lowest sound of the pitch space, the C of `Sound::MIN_OCTAVE`.
+    pub const MIN_RANGE: isize = (Self::MIN_OCTAVE - 1) * 12;
+    /// Range of the highest sound of the pitch space, the B of `Sound::MAX_OCTAVE`.
+    pub const MAX_RANGE: isize = (Self::MAX_OCTAVE - 1) * 12 + 11;
+
     /// Creates a representation of a piano key/sound.
     /// # Panics
-    /// It panics when the octave is set to 0.
+    /// It panics when the sound is outside the pitch space.
     /// ```
     /// use music::Sound;
     /// use music::Note;
@@ -21,9 +49,60 @@ impl Sound {
     /// assert_eq!(sound.note(), note);
     /// assert_eq!(sound.octave(), 2);
     /// ```
-    pub fn init(note: Note, octave: usize) -> Self {
-        assert!(octave > 0);
-        Self { note, octave }
+    pub fn init(note: Note, octave: isize) -> Self {
+        Self::init_spelled(SpelledNote::from(note), octave)
+    }
+    /// Creates a representation of a piano key/sound from a spelled note.
+    /// # Panics
+    /// It panics when the sound is outside the pitch space.
+    /// ```
+    /// use music::{Letter, Sound, SpelledNote};
+    /// let f_double_sharp = SpelledNote::init(Letter::F, 2);
+    /// let sound = Sound::init_spelled(f_double_sharp, 3);
+    /// assert_eq!(sound.spelled_note(), f_double_sharp);
+    /// assert_eq!(sound.range(), 31);
+    /// ```
+    pub fn init_spelled(note: SpelledNote, octave: isize) -> Self {
+        Self::try_init_spelled(note, octave).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a representation of a piano key/sound,
+    /// failing when the sound is outside the pitch space.
+    /// ```
+    /// use music::{MusicError, Note, Sound};
+    /// assert_eq!(Sound::try_init(Note::A, 0), Ok(Sound::init(Note::A, 0)));
+    /// assert_eq!(Sound::try_init(Note::A, 200), Err(MusicError::InvalidOctave(200)));
+    /// assert_eq!(Sound::try_init(Note::Cb, Sound::MIN_OCTAVE), Err(MusicError::BelowLowestSound));
+    /// ```
+    pub fn try_init(note: Note, octave: isize) -> Result<Self, MusicError> {
+        Self::try_init_spelled(SpelledNote::from(note), octave)
+    }
+    /// Creates a representation of a piano key/sound from a spelled note,
+    /// failing when the sound is outside the pitch space.
+    /// ```
+    /// use music::{Letter, MusicError, Sound, SpelledNote};
+    /// let b_sharp = SpelledNote::init(Letter::B, 1);
+    /// assert!(Sound::try_init_spelled(b_sharp, -3).is_ok());
+    /// assert_eq!(
+    ///     Sound::try_init_spelled(b_sharp, Sound::MAX_OCTAVE),
+    ///     Err(MusicError::AboveHighestSound)
+    /// );
+    /// ```
+    pub fn try_init_spelled(note: SpelledNote, octave: isize) -> Result<Self, MusicError> {
+        if !(Self::MIN_OCTAVE..=Self::MAX_OCTAVE).contains(&octave) {
+            return Err(MusicError::InvalidOctave(octave));
+        }
+        let sound = Self { note, octave };
+        Self::check_range(sound.range())?;
+        Ok(sound)
+    }
+    fn check_range(range: isize) -> Result<(), MusicError> {
+        if range < Self::MIN_RANGE {
+            Err(MusicError::BelowLowestSound)
+        } else if range > Self::MAX_RANGE {
+            Err(MusicError::AboveHighestSound)
+        } else {
+            Ok(())
+        }
     }
     /// Gives the note of the sound.
     /// ```
@@ -33,6 +112,15 @@ impl Sound {
     /// assert_eq!(sound.note(), Note::C);
     /// ```
     pub fn note(&self) -> Note {
+        self.note.to_note()
+    }
+    /// Gives the spelled note of the sound.
+    /// ```
+    /// use music::{Letter, Note, Sound, SpelledNote};
+    /// let sound = Sound::init(Note::Ab, 4);
+    /// assert_eq!(sound.spelled_note(), SpelledNote::init(Letter::A, -1));
+    /// ```
+    pub fn spelled_note(&self) -> SpelledNote {
         self.note
     }
     /// Gives the octave of the sound.
@@ -42,11 +130,11 @@ impl Sound {
     /// let sound = Sound::init(Note::G, 3);
     /// assert_eq!(sound.octave(), 3);
     /// ```
-    pub fn octave(&self) -> usize {
+    pub fn octave(&self) -> isize {
         self.octave
     }
-    /// Gives the range of the key/sound, from 0 to +∞ (usize::MAX instead),
-    /// 0 representing the lowest C note.
+    /// Gives the signed range of the key/sound in half tones,
+    /// 0 representing the C of octave 1 (the lowest C of a piano).
     /// ```
     /// use music::Sound;
     /// use music::Note;
@@ -54,27 +142,285 @@ impl Sound {
     /// assert_eq!(sound.range(), 18);
     /// let sound = Sound::init(Note::C, 2);
     /// assert_eq!(sound.range(), 12);
+    /// let sound = Sound::init(Note::Cb, 2);
+    /// assert_eq!(sound.range(), 11);
+    /// let sound = Sound::init(Note::A, 0);
+    /// assert_eq!(sound.range(), -3);
     /// ```
-    pub fn range(&self) -> usize {
-        (self.octave - 1) * 12 + self.note.to_usize()
+    pub fn range(&self) -> isize {
+        (self.octave - 1) * 12 + self.note.semitones()
     }
     /// Returns the corresponding sound of a range.
+    /// # Panics
+    /// It panics when the range is outside the pitch space.
     /// ```
     /// use music::Sound;
     /// use music::Note;
     /// let range = 28;
     /// let expected_sound = Sound::init(Note::E, 3);
     /// assert_eq!(Sound::from_range(range), expected_sound);
+    /// assert_eq!(Sound::from_range(-1), Sound::init(Note::B, 0));
+    /// ```
+    pub fn from_range(range: isize) -> Self {
+        Self::try_from_range(range).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Returns the corresponding sound of a range,
+    /// failing when the range is outside the pitch space.
+    /// ```
+    /// use music::{MusicError, Sound};
+    /// assert!(Sound::try_from_range(-30).is_ok());
+    /// assert_eq!(Sound::try_from_range(isize::MIN), Err(MusicError::BelowLowestSound));
+    /// ```
+    pub fn try_from_range(range: isize) -> Result<Self, MusicError> {
+        Self::check_range(range)?;
+        let octave = range.div_euclid(12) + 1;
+        let note = SpelledNote::from(Note::from_usize(range.rem_euclid(12) as usize));
+        Ok(Self { note, octave })
+    }
+    /// Returns the sound of a range spelled with the given note, falling back to
+    /// `Sound::from_range` when the note does not match the range or when the
+    /// spelling would need an octave outside the pitch space.
+    pub(crate) fn from_range_spelled(range: isize, note: SpelledNote) -> Self {
+        let octave = (range - note.semitones()).div_euclid(12) + 1;
+        match Self::try_init_spelled(note, octave) {
+            Ok(sound) if sound.range() == range => sound,
+            _ => Self::from_range(range),
+        }
+    }
+    /// Gives the MIDI note number of the sound, middle C (C4) being 60,
+    /// failing when the sound is outside the MIDI range (C-1 to G9).
+    /// ```
+    /// use music::{MusicError, Note, Sound};
+    /// assert_eq!(Sound::init(Note::C, 4).to_midi(), Ok(60));
+    /// assert_eq!(Sound::init(Note::A, 0).to_midi(), Ok(21));
+    /// assert_eq!(Sound::init(Note::Cb, -1).to_midi(), Err(MusicError::OutOfMidiRange));
+    /// ```
+    pub fn to_midi(&self) -> Result<u8, MusicError> {
+        u8::try_from(self.range() + 24)
+            .ok()
+            .filter(|number| *number <= 127)
+            .ok_or(MusicError::OutOfMidiRange)
+    }
+    /// Returns the sound of a MIDI note number, middle C (C4) being 60.
+    /// # Panics
+    /// It panics when the number is greater than 127.
+    /// ```
+    /// use music::{Note, Sound};
+    /// assert_eq!(Sound::from_midi(69), Sound::init(Note::A, 4));
+    /// ```
+    pub fn from_midi(number: u8) -> Self {
+        Self::try_from_midi(number).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Returns the sound of a MIDI note number, middle C (C4) being 60,
+    /// failing when the number is greater than 127.
+    /// ```
+    /// use music::{MusicError, Note, Sound};
+    /// assert_eq!(Sound::try_from_midi(0), Ok(Sound::init(Note::C, -1)));
+    /// assert_eq!(Sound::try_from_midi(128), Err(MusicError::OutOfMidiRange));
+    /// ```
+    pub fn try_from_midi(number: u8) -> Result<Self, MusicError> {
+        if number > 127 {
+            return Err(MusicError::OutOfMidiRange);
+        }
+        Self::try_from_range(number as isize - 24)
+    }
+    /// Names the sound in the scientific pitch notation, e.g. "C#4" or "B♭3".
     /// ```
-    pub fn from_range(range: usize) -> Self {
-        let octave = (range / 12) + 1;
-        let note = Note::from_usize(range % 12);
-        Self { note, octave }
+    /// use music::{Note, Sound};
+    /// assert_eq!(Sound::init(Note::Bb, 3).to_scientific(), "B♭3");
+    /// assert_eq!(Sound::init(Note::A, -1).to_scientific(), "A-1");
+    /// ```
+    pub fn to_scientific(&self) -> String {
+        format!("{}{}", self.note, self.octave)
+    }
+    /// Parses a sound named in the scientific pitch notation, see `Sound::from_str`.
+    /// ```
+    /// use music::{Note, Sound};
+    /// assert_eq!(Sound::from_scientific("C#4"), Ok(Sound::init(Note::Cs, 4)));
+    /// ```
+    pub fn from_scientific(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+    /// Names the sound in the Helmholtz pitch notation: lowercase letters with primes
+    /// from octave 3 upwards (c, c', c''), uppercase letters with commas below (C, C,, C,,).
+    /// ```
+    /// use music::{Note, Sound};
+    /// assert_eq!(Sound::init(Note::C, 4).to_helmholtz(), "c'");
+    /// assert_eq!(Sound::init(Note::Fs, 3).to_helmholtz(), "f#");
+    /// assert_eq!(Sound::init(Note::Bb, 2).to_helmholtz(), "B♭");
+    /// assert_eq!(Sound::init(Note::C, 0).to_helmholtz(), "C,,");
+    /// ```
+    pub fn to_helmholtz(&self) -> String {
+        let note = self.note.to_string();
+        if self.octave >= 3 {
+            format!(
+                "{}{}",
+                note.to_lowercase(),
+                "'".repeat((self.octave - 3) as usize)
+            )
+        } else {
+            format!("{note}{}", ",".repeat((2 - self.octave) as usize))
+        }
+    }
+    /// Parses a sound named in the Helmholtz pitch notation, accepting both
+    /// ASCII marks (' and ,) and typographic ones (′ and ͵).
+    /// ```
+    /// use music::{Note, Sound};
+    /// assert_eq!(Sound::from_helmholtz("c'"), Ok(Sound::init(Note::C, 4)));
+    /// assert_eq!(Sound::from_helmholtz("bb"), Ok(Sound::init(Note::Bb, 3)));
+    /// assert_eq!(Sound::from_helmholtz("A,,"), Ok(Sound::init(Note::A, 0)));
+    /// assert!(Sound::from_helmholtz("A'").is_err());
+    /// ```
+    pub fn from_helmholtz(s: &str) -> Result<Self, ParseError> {
+        let (note, read) = parse_prefix(s)?;
+        let is_lowercase = s.starts_with(|c: char| c.is_lowercase());
+        let mut octave: isize = if is_lowercase { 3 } else { 2 };
+        for (position, c) in s.chars().enumerate().skip(read) {
+            octave += match c {
+                '\'' | '′' if is_lowercase => 1,
+                ',' | '͵' if !is_lowercase => -1,
+                _ => {
+                    return Err(ParseError::init(
+                        s,
+                        position,
+                        ParseErrorKind::UnexpectedCharacter(c),
+                    ))
+                }
+            };
+        }
+        Self::try_init_spelled(note, octave)
+            .map_err(|_| ParseError::init(s, read, ParseErrorKind::InvalidOctave))
+    }
+    /// Names the sound according to a pitch notation.
+    /// ```
+    /// use music::{Note, PitchNotation, Sound};
+    /// let sound = Sound::init(Note::Gs, 5);
+    /// assert_eq!(sound.to_notation(PitchNotation::Scientific), "G#5");
+    /// assert_eq!(sound.to_notation(PitchNotation::Helmholtz), "g#''");
+    /// ```
+    pub fn to_notation(&self, notation: PitchNotation) -> String {
+        match notation {
+            PitchNotation::Scientific => self.to_scientific(),
+            PitchNotation::Helmholtz => self.to_helmholtz(),
+        }
+    }
+    /// Gives the frequency (in Hz) of the sound in a tuning system.
+    /// # Panics
+    /// It panics when the tuning leaves the sound untuned.
+    /// ```
+    /// use music::tuning::{EqualTemperament, RegularTemperament};
+    /// use music::{Note, Sound};
+    /// let sound = Sound::init(Note::A, 5);
+    /// assert_eq!(sound.frequency(&EqualTemperament::default()), 880.0);
+    /// let sound = Sound::init(Note::E, 5);
+    /// let frequency = sound.frequency(&RegularTemperament::pythagorean(440.0));
+    /// assert!((frequency - 660.0).abs() < 1e-9);
+    /// ```
+    pub fn frequency<T: Tuning + ?Sized>(&self, tuning: &T) -> f64 {
+        tuning.frequency(self)
+    }
+    /// Gives the frequency (in Hz) of the sound in a tuning system,
+    /// failing when the tuning leaves the sound untuned.
+    /// ```
+    /// use music::tuning::{ScalaScale, ScalaTuning};
+    /// use music::{MusicError, Note, Sound};
+    /// let tuning = ScalaTuning::from(ScalaScale::init("Octave", vec![1200.0]));
+    /// let frequency = Sound::init(Note::A, 4).try_frequency(&tuning).unwrap();
+    /// assert!((frequency - 440.0).abs() < 1e-9);
+    /// let sound = Sound::init(Note::Gs, 9);
+    /// assert_eq!(sound.try_frequency(&tuning), Err(MusicError::UnmappedSound(sound)));
+    /// ```
+    pub fn try_frequency<T: Tuning + ?Sized>(&self, tuning: &T) -> Result<f64, MusicError> {
+        tuning.try_frequency(self)
+    }
+    /// Finds the sound of a tuning system that is the nearest to a frequency (in Hz),
+    /// together with the signed deviation (in cents) of the frequency from that sound.
+    ///
+    /// Enharmonic spellings are compared, so that tunings distinguishing them
+    /// (e.g. the Pythagorean tuning) give back the spelling of an in-tune sound.
+    /// Sounds left untuned by the tuning are skipped.
+    /// ```
+    /// use music::tuning::{EqualTemperament, RegularTemperament};
+    /// use music::{MusicError, Note, Sound};
+    /// let tuning = EqualTemperament::default();
+    /// let (sound, cents) = Sound::from_frequency(440.0, &tuning).unwrap();
+    /// assert_eq!(sound, Sound::init(Note::A, 4));
+    /// assert_eq!(cents, 0.0);
+    /// let (sound, cents) = Sound::from_frequency(445.0, &tuning).unwrap();
+    /// assert_eq!(sound, Sound::init(Note::A, 4));
+    /// assert!((cents - 19.56).abs() < 0.01);
+    /// let tuning = RegularTemperament::pythagorean(440.0);
+    /// let a_flat = Sound::init(Note::Ab, 4);
+    /// let (sound, _) = Sound::from_frequency(a_flat.frequency(&tuning), &tuning).unwrap();
+    /// assert!(sound.strict_eq(&a_flat));
+    /// assert_eq!(Sound::from_frequency(-1.0, &tuning), Err(MusicError::InvalidFrequency));
+    /// ```
+    pub fn from_frequency<T: Tuning + ?Sized>(
+        frequency: f64,
+        tuning: &T,
+    ) -> Result<(Self, f64), MusicError> {
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return Err(MusicError::InvalidFrequency);
+        }
+        let reference = Self::init(Note::A, 4).try_frequency(tuning)?;
+        // First estimate within the equal temperament, refined below.
+        let estimate = (A4_RANGE as f64 + 12.0 * (frequency / reference).log2()).round();
+        if estimate.is_nan() {
+            return Err(MusicError::InvalidFrequency);
+        } else if estimate < Self::MIN_RANGE as f64 {
+            return Err(MusicError::BelowLowestSound);
+        } else if estimate > Self::MAX_RANGE as f64 {
+            return Err(MusicError::AboveHighestSound);
+        }
+        let estimate = estimate as isize;
+        (estimate - 1..=estimate + 1)
+            .flat_map(Self::spellings)
+            .filter_map(|sound| {
+                let tuned = sound.try_frequency(tuning).ok()?;
+                Some((sound, ratio_to_cents(frequency / tuned)))
+            })
+            .min_by(|(_, one), (_, two)| one.abs().total_cmp(&two.abs()))
+            .ok_or(MusicError::UnmappedSound(Self::from_range(estimate)))
+    }
+    /// Gives the sounds of a range spelled with at most two accidentals,
+    /// starting with the spelling of `Sound::from_range`.
+    fn spellings(range: isize) -> Vec<Self> {
+        let Ok(sound) = Self::try_from_range(range) else {
+            return Vec::new();
+        };
+        let mut sounds = vec![sound];
+        for index in 0..7 {
+            let letter = Letter::from_index(index);
+            for accidental in -2..=2 {
+                let offset = range - letter.to_usize() as isize - accidental as isize;
+                if offset.rem_euclid(12) != 0 {
+                    continue;
+                }
+                let note = SpelledNote::init(letter, accidental);
+                match Self::try_init_spelled(note, offset.div_euclid(12) + 1) {
+                    Ok(other) if !other.strict_eq(&sound) => sounds.push(other),
+                    _ => {}
+                }
+            }
+        }
+        sounds
+    }
+    /// Tests whether or not two sounds have the same spelling and octave,
+    /// contrary to `==` which only compares pitches.
+    /// ```
+    /// use music::{Note, Sound};
+    /// let g_sharp = Sound::init(Note::Gs, 4);
+    /// let a_flat = Sound::init(Note::Ab, 4);
+    /// assert_eq!(g_sharp, a_flat);
+    /// assert!(!g_sharp.strict_eq(&a_flat));
+    /// ```
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        (self.note == other.note) & (self.octave == other.octave)
     }
 }
 impl PartialEq for Sound {
     fn eq(&self, other: &Self) -> bool {
-        (self.note == other.note) & (self.octave == other.octave)
+        self.range() == other.range()
     }
 }
 impl PartialOrd for Sound {
@@ -87,3 +433,51 @@ impl Ord for Sound {
         self.range().cmp(&other.range())
     }
 }
+/// Displays the sound in the scientific pitch notation,
+/// or in the Helmholtz pitch notation with the alternate flag.
+/// ```
+/// use music::{Note, Sound};
+/// let sound = Sound::init(Note::Eb, 2);
+/// assert_eq!(format!("{sound}"), "E♭2");
+/// assert_eq!(format!("{sound:#}"), "E♭");
+/// ```
+impl fmt::Display for Sound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_helmholtz())
+        } else {
+            write!(f, "{}", self.to_scientific())
+        }
+    }
+}
+impl FromStr for Sound {
+    type Err = ParseError;
+    /// Parses a spelled note followed by its octave, e.g. "C#4", "Ebb3" or "A-1".
+    /// ```
+    /// use music::{Letter, Note, Sound, SpelledNote};
+    /// assert_eq!("C#4".parse(), Ok(Sound::init(Note::Cs, 4)));
+    /// let e_double_flat_3 = Sound::init_spelled(SpelledNote::init(Letter::E, -2), 3);
+    /// assert!("Ebb3".parse::<Sound>().unwrap().strict_eq(&e_double_flat_3));
+    /// assert_eq!("A-1".parse(), Ok(Sound::init(Note::A, -1)));
+    /// assert!("C".parse::<Sound>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (note, read) = parse_prefix(s)?;
+        let octave: String = s.chars().skip(read).collect();
+        let error = |kind| Err(ParseError::init(s, read, kind));
+        match octave.chars().next() {
+            None => return error(ParseErrorKind::MissingOctave),
+            Some(c) if !c.is_ascii_digit() && c != '-' => {
+                return error(ParseErrorKind::InvalidAccidental(c))
+            }
+            _ => (),
+        }
+        match octave
+            .parse::<isize>()
+            .map(|octave| Self::try_init_spelled(note, octave))
+        {
+            Ok(Ok(sound)) => Ok(sound),
+            _ => error(ParseErrorKind::InvalidOctave),
+        }
+    }
+}
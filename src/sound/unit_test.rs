@@ -1,8 +1,10 @@
 #[cfg(test)]
+#[allow(clippy::op_ref)]
 mod tests {
     use super::super::Sound;
     use crate::accidental::{Flat, Natural, Sharp};
     use crate::note::Note;
+    use crate::{MusicError, PitchNotation};
 
     #[test]
     fn test_sound_algebra() {
@@ -46,4 +48,49 @@ mod tests {
         assert_eq!(accidental + sound, expected_sound);
         assert_eq!(&accidental + &sound, expected_sound);
     }
+    #[test]
+    fn test_sound_fallible_algebra() {
+        let sound = Sound::init(Note::D, 1);
+        assert_eq!(
+            Flat::init(2).try_add_sound(&sound),
+            Ok(Sound::init(Note::C, 1))
+        );
+        assert_eq!(
+            Flat::init(3).try_add_sound(&sound),
+            Ok(Sound::init(Note::B, 0))
+        );
+        let lowest = Sound::from_range(Sound::MIN_RANGE);
+        assert_eq!(
+            Flat::init(1).try_add_sound(&lowest),
+            Err(MusicError::BelowLowestSound)
+        );
+        assert_eq!(
+            Sharp::init(3).try_add_sound(&sound),
+            Ok(Sound::init(Note::F, 1))
+        );
+        assert_eq!(Natural::init(1).try_add_sound(&sound), Ok(sound));
+    }
+    #[test]
+    fn test_sound_notations() {
+        for number in 0..=127 {
+            let sound = Sound::from_midi(number);
+            assert_eq!(sound.to_midi(), Ok(number));
+            assert_eq!(Sound::from_scientific(&sound.to_scientific()), Ok(sound));
+            assert_eq!(Sound::from_helmholtz(&sound.to_helmholtz()), Ok(sound));
+        }
+        let middle_c = Sound::init(Note::C, 4);
+        assert_eq!(middle_c.to_midi(), Ok(60));
+        assert_eq!(middle_c.range() + 24, 60);
+        assert_eq!(Sound::from_midi(127), Sound::init(Note::G, 9));
+        assert_eq!(
+            Sound::init(Note::Gs, 9).to_midi(),
+            Err(MusicError::OutOfMidiRange)
+        );
+        let sound = Sound::init(Note::Cs, 1);
+        assert_eq!(sound.to_notation(PitchNotation::Scientific), "C#1");
+        assert_eq!(sound.to_notation(PitchNotation::Helmholtz), "C#,");
+        assert_eq!(format!("{sound:#}"), "C#,");
+        assert_eq!(Sound::from_helmholtz("e♭′′"), Ok(Sound::init(Note::Eb, 5)));
+        assert!(Sound::from_helmholtz("c,").is_err());
+    }
 }
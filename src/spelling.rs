@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod unit_test;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Note, ParseError, ParseErrorKind};
+
+/// Implementation of the seven natural letter names of a note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+impl Letter {
+    /// Gives the position of the letter in the sequence C, D, E, F, G, A, B.
+    /// ```
+    /// use music::Letter;
+    /// assert_eq!(Letter::C.index(), 0);
+    /// assert_eq!(Letter::B.index(), 6);
+    /// ```
+    pub fn index(&self) -> usize {
+        match self {
+            Letter::C => 0,
+            Letter::D => 1,
+            Letter::E => 2,
+            Letter::F => 3,
+            Letter::G => 4,
+            Letter::A => 5,
+            Letter::B => 6,
+        }
+    }
+    /// Converts an integer to a letter.
+    /// It computes the remainder of `index` in the euclidean division by 7 and maps it to a letter.
+    /// ```
+    /// use music::Letter;
+    /// assert_eq!(Letter::from_index(3), Letter::F);
+    /// assert_eq!(Letter::from_index(8), Letter::D);
+    /// ```
+    pub fn from_index(index: usize) -> Self {
+        match index % 7 {
+            0 => Letter::C,
+            1 => Letter::D,
+            2 => Letter::E,
+            3 => Letter::F,
+            4 => Letter::G,
+            5 => Letter::A,
+            6 => Letter::B,
+            _ => panic!("Not implemented."),
+        }
+    }
+    /// Converts the natural note named by the letter to its integer representative
+    /// that is beetween 0 and 11.
+    /// ```
+    /// use music::Letter;
+    /// assert_eq!(Letter::E.to_usize(), 4);
+    /// assert_eq!(Letter::A.to_usize(), 9);
+    /// ```
+    pub fn to_usize(&self) -> usize {
+        [0, 2, 4, 5, 7, 9, 11][self.index()]
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Implementation of a spelled note: a letter name together with a signed
+/// number of accidentals (positive for sharps, negative for flats).
+///
+/// Contrary to [`Note`], two spellings of the same pitch class are different
+/// values, e.g. G# and A♭, and any number of accidentals can be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpelledNote {
+    letter: Letter,
+    accidental: i8,
+}
+
+impl SpelledNote {
+    /// Creates a spelled note from a letter and a signed number of accidentals.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// let f_double_sharp = SpelledNote::init(Letter::F, 2);
+    /// assert_eq!(f_double_sharp.letter(), Letter::F);
+    /// assert_eq!(f_double_sharp.accidental(), 2);
+    /// ```
+    pub fn init(letter: Letter, accidental: i8) -> Self {
+        Self { letter, accidental }
+    }
+    /// Gives the letter of the spelled note.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// assert_eq!(SpelledNote::init(Letter::B, -1).letter(), Letter::B);
+    /// ```
+    pub fn letter(&self) -> Letter {
+        self.letter
+    }
+    /// Gives the signed number of accidentals, positive for sharps and negative for flats.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// assert_eq!(SpelledNote::init(Letter::E, -2).accidental(), -2);
+    /// ```
+    pub fn accidental(&self) -> i8 {
+        self.accidental
+    }
+    /// Gives the signed distance in half tones between the spelled note and the C
+    /// of the same octave, e.g. -1 for C♭ and 12 for B#.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// assert_eq!(SpelledNote::init(Letter::C, -1).semitones(), -1);
+    /// assert_eq!(SpelledNote::init(Letter::B, 1).semitones(), 12);
+    /// ```
+    pub fn semitones(&self) -> isize {
+        self.letter.to_usize() as isize + self.accidental as isize
+    }
+    /// Converts the spelled note to its integer representative
+    /// that is beetween 0 and 11.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// assert_eq!(SpelledNote::init(Letter::B, 1).to_usize(), 0);
+    /// assert_eq!(SpelledNote::init(Letter::E, -2).to_usize(), 2);
+    /// ```
+    pub fn to_usize(&self) -> usize {
+        self.semitones().rem_euclid(12) as usize
+    }
+    /// Converts the spelled note to a `Note`, keeping the spelling whenever
+    /// `Note` can represent it (at most one accidental) and falling back to
+    /// `Note::from_usize` otherwise.
+    /// ```
+    /// use music::{Letter, Note, SpelledNote};
+    /// assert!(SpelledNote::init(Letter::E, 1).to_note().strict_eq(&Note::Es));
+    /// assert!(SpelledNote::init(Letter::F, 2).to_note().strict_eq(&Note::G));
+    /// ```
+    pub fn to_note(&self) -> Note {
+        match (self.letter, self.accidental) {
+            (Letter::C, -1) => Note::Cb,
+            (Letter::C, 0) => Note::C,
+            (Letter::C, 1) => Note::Cs,
+            (Letter::D, -1) => Note::Db,
+            (Letter::D, 0) => Note::D,
+            (Letter::D, 1) => Note::Ds,
+            (Letter::E, -1) => Note::Eb,
+            (Letter::E, 0) => Note::E,
+            (Letter::E, 1) => Note::Es,
+            (Letter::F, -1) => Note::Fb,
+            (Letter::F, 0) => Note::F,
+            (Letter::F, 1) => Note::Fs,
+            (Letter::G, -1) => Note::Gb,
+            (Letter::G, 0) => Note::G,
+            (Letter::G, 1) => Note::Gs,
+            (Letter::A, -1) => Note::Ab,
+            (Letter::A, 0) => Note::A,
+            (Letter::A, 1) => Note::As,
+            (Letter::B, -1) => Note::Bb,
+            (Letter::B, 0) => Note::B,
+            (Letter::B, 1) => Note::Bs,
+            _ => Note::from_usize(self.to_usize()),
+        }
+    }
+    /// Tests whether or not two spelled notes share the same pitch class.
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// let g_sharp = SpelledNote::init(Letter::G, 1);
+    /// let a_flat = SpelledNote::init(Letter::A, -1);
+    /// assert!(g_sharp.is_enharmonic(&a_flat));
+    /// assert_ne!(g_sharp, a_flat);
+    /// ```
+    pub fn is_enharmonic(&self, other: &Self) -> bool {
+        self.to_usize() == other.to_usize()
+    }
+}
+
+impl From<Note> for SpelledNote {
+    fn from(note: Note) -> Self {
+        let (letter, accidental) = match note {
+            Note::A => (Letter::A, 0),
+            Note::As => (Letter::A, 1),
+            Note::Bb => (Letter::B, -1),
+            Note::B => (Letter::B, 0),
+            Note::Bs => (Letter::B, 1),
+            Note::Cb => (Letter::C, -1),
+            Note::C => (Letter::C, 0),
+            Note::Cs => (Letter::C, 1),
+            Note::Db => (Letter::D, -1),
+            Note::D => (Letter::D, 0),
+            Note::Ds => (Letter::D, 1),
+            Note::Eb => (Letter::E, -1),
+            Note::E => (Letter::E, 0),
+            Note::Es => (Letter::E, 1),
+            Note::Fb => (Letter::F, -1),
+            Note::F => (Letter::F, 0),
+            Note::Fs => (Letter::F, 1),
+            Note::Gb => (Letter::G, -1),
+            Note::G => (Letter::G, 0),
+            Note::Gs => (Letter::G, 1),
+            Note::Ab => (Letter::A, -1),
+        };
+        Self { letter, accidental }
+    }
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let accidental = if self.accidental >= 0 {
+            "#".repeat(self.accidental as usize)
+        } else {
+            "♭".repeat(self.accidental.unsigned_abs() as usize)
+        };
+        write!(f, "{}{accidental}", self.letter)
+    }
+}
+
+impl FromStr for SpelledNote {
+    type Err = ParseError;
+    /// Parses a letter followed by any number of accidentals
+    /// ('#', '♯', 'x', '𝄪', 'b', '♭', '𝄫' or '♮').
+    /// ```
+    /// use music::{Letter, SpelledNote};
+    /// assert_eq!("Ebb".parse(), Ok(SpelledNote::init(Letter::E, -2)));
+    /// assert_eq!("F𝄪".parse(), Ok(SpelledNote::init(Letter::F, 2)));
+    /// assert!("Ez".parse::<SpelledNote>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (note, read) = parse_prefix(s)?;
+        match s.chars().nth(read) {
+            Some(c) => Err(ParseError::init(
+                s,
+                read,
+                ParseErrorKind::InvalidAccidental(c),
+            )),
+            None => Ok(note),
+        }
+    }
+}
+
+/// Parses the spelled note at the start of `input`,
+/// returning it together with the number of characters read.
+pub(crate) fn parse_prefix(input: &str) -> Result<(SpelledNote, usize), ParseError> {
+    let mut chars = input.chars();
+    let letter = match chars.next() {
+        None => return Err(ParseError::init(input, 0, ParseErrorKind::Empty)),
+        Some(c) => match c.to_ascii_uppercase() {
+            'C' => Letter::C,
+            'D' => Letter::D,
+            'E' => Letter::E,
+            'F' => Letter::F,
+            'G' => Letter::G,
+            'A' => Letter::A,
+            'B' => Letter::B,
+            _ => return Err(ParseError::init(input, 0, ParseErrorKind::InvalidLetter(c))),
+        },
+    };
+    let mut accidental: i8 = 0;
+    let mut read = 1;
+    for c in chars {
+        let alteration = match c {
+            '#' | '♯' => 1,
+            'x' | '𝄪' => 2,
+            'b' | '♭' => -1,
+            '𝄫' => -2,
+            '♮' => 0,
+            _ => break,
+        };
+        accidental = accidental
+            .checked_add(alteration)
+            .ok_or_else(|| ParseError::init(input, read, ParseErrorKind::UnsupportedAccidental))?;
+        read += 1;
+    }
+    Ok((SpelledNote::init(letter, accidental), read))
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{Letter, SpelledNote};
+    use crate::{Chord, Interval, Note, Sound};
+
+    #[test]
+    fn test_spelled_note_conversions() {
+        let notes = [
+            Note::A,
+            Note::As,
+            Note::Bb,
+            Note::B,
+            Note::Bs,
+            Note::Cb,
+            Note::C,
+            Note::Cs,
+            Note::Db,
+            Note::D,
+            Note::Ds,
+            Note::Eb,
+            Note::E,
+            Note::Es,
+            Note::Fb,
+            Note::F,
+            Note::Fs,
+            Note::Gb,
+            Note::G,
+            Note::Gs,
+            Note::Ab,
+        ];
+        for note in notes {
+            let spelled = SpelledNote::from(note);
+            assert_eq!(spelled.to_usize(), note.to_usize());
+            assert!(spelled.to_note().strict_eq(&note));
+        }
+        let e_double_flat = SpelledNote::init(Letter::E, -2);
+        assert_eq!(e_double_flat.to_note(), Note::D);
+        assert_eq!(format!("{e_double_flat}"), "E♭♭");
+        assert_eq!(format!("{}", SpelledNote::init(Letter::F, 2)), "F##");
+    }
+    #[test]
+    fn test_spelled_sounds() {
+        let b_sharp_3 = Sound::init(Note::Bs, 3);
+        let c_4 = Sound::init(Note::C, 4);
+        assert_eq!(b_sharp_3, c_4);
+        assert!(!b_sharp_3.strict_eq(&c_4));
+        assert_eq!(Sound::init(Note::Cb, 4), Sound::init(Note::B, 3));
+
+        let f_double_sharp_4 = Sound::init_spelled(SpelledNote::init(Letter::F, 2), 4);
+        let g_4 = Sound::init(Note::G, 4);
+        let interval = Interval::init(c_4, f_double_sharp_4);
+        assert_eq!(interval, Interval::init(c_4, g_4));
+        assert!(!interval.strict_eq(&Interval::init(c_4, g_4)));
+        assert_eq!(interval.distance(), 7);
+
+        let chord = Chord::from_vec(vec![b_sharp_3, f_double_sharp_4]);
+        assert_eq!(
+            chord.spelled_notes(),
+            vec![
+                SpelledNote::init(Letter::B, 1),
+                SpelledNote::init(Letter::F, 2)
+            ]
+        );
+    }
+}
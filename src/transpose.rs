@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod unit_test;
+use crate::scale::{spell_with_letter, CHROMATIC_STEPS};
+use crate::{Chord, Interval, Letter, MusicError, Note, Scale, Sound, SpelledNote};
+
+/// Moves pitched values up or down, either by a number of half tones
+/// or by a number of steps of a scale.
+///
+/// Accidentals are one way to produce a transposition amount,
+/// see for instance `Sharp::semitones`.
+pub trait Transpose: Sized {
+    /// Transposes by a signed number of half tones,
+    /// failing when the result cannot be represented.
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError>;
+    /// Transposes by a signed number of steps of `scale`,
+    /// failing when a pitch does not belong to the scale
+    /// or when the result cannot be represented.
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError>;
+    /// Transposes by a signed number of half tones,
+    /// clamping the result to the bounds of the pitch space.
+    /// ```
+    /// use music::{Note, Sound, Transpose};
+    /// let sound = Sound::init(Note::A, 3);
+    /// assert_eq!(sound.saturating_transpose(isize::MIN), Sound::from_range(Sound::MIN_RANGE));
+    /// ```
+    fn saturating_transpose(&self, semitones: isize) -> Self;
+    /// Transposes by a signed number of half tones,
+    /// wrapping around at the bounds of the pitch space.
+    /// ```
+    /// use music::{Note, Sound, Transpose};
+    /// let highest = Sound::from_range(Sound::MAX_RANGE);
+    /// assert_eq!(highest.wrapping_transpose(1), Sound::from_range(Sound::MIN_RANGE));
+    /// ```
+    fn wrapping_transpose(&self, semitones: isize) -> Self;
+    /// Transposes by a signed number of half tones,
+    /// returning `None` when the result cannot be represented.
+    /// ```
+    /// use music::{Note, Sound, Transpose};
+    /// let sound = Sound::init(Note::C, 1);
+    /// assert_eq!(sound.checked_transpose(-3), Some(Sound::init(Note::A, 0)));
+    /// assert_eq!(sound.checked_transpose(isize::MIN), None);
+    /// ```
+    fn checked_transpose(&self, semitones: isize) -> Option<Self> {
+        self.try_transpose(semitones).ok()
+    }
+    /// Transposes by a signed number of half tones.
+    /// # Panics
+    /// It panics when `try_transpose` fails.
+    /// ```
+    /// use music::{Note, Sound, Transpose};
+    /// let sound = Sound::init(Note::A, 3);
+    /// assert_eq!(sound.transpose(3), Sound::init(Note::C, 4));
+    /// assert_eq!(sound.transpose(-9), Sound::init(Note::C, 3));
+    /// ```
+    fn transpose(&self, semitones: isize) -> Self {
+        self.try_transpose(semitones)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Transposes by a signed number of steps of `scale`.
+    /// # Panics
+    /// It panics when `try_transpose_diatonic` fails.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, Sound, Transpose};
+    /// let scale = Scale::init(Note::Eb, ScaleType::Major);
+    /// let sound = Sound::init(Note::Bb, 3);
+    /// assert_eq!(sound.transpose_diatonic(2, &scale), Sound::init(Note::D, 4));
+    /// assert_eq!(sound.transpose_diatonic(-8, &scale), Sound::init(Note::Ab, 2));
+    /// ```
+    fn transpose_diatonic(&self, steps: isize, scale: &Scale) -> Self {
+        self.try_transpose_diatonic(steps, scale)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Transpose for Note {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        let num = (self.to_usize() as isize + semitones).rem_euclid(12);
+        Ok(Note::from_usize(num as usize))
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        self.transpose(semitones)
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        self.transpose(semitones)
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        let notes = scale.notes();
+        let position = notes
+            .iter()
+            .position(|note| note == self)
+            .ok_or(MusicError::NoteNotInScale(*self))?;
+        let position = (position as isize + steps).rem_euclid(notes.len() as isize);
+        Ok(notes[position as usize])
+    }
+}
+
+impl Transpose for Sound {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        if semitones == 0 {
+            return Ok(*self);
+        }
+        let range = self
+            .range()
+            .checked_add(semitones)
+            .ok_or(if semitones < 0 {
+                MusicError::BelowLowestSound
+            } else {
+                MusicError::AboveHighestSound
+            })?;
+        Sound::try_from_range(range)?;
+        Ok(Sound::from_range_spelled(
+            range,
+            transposed_spelling(self.spelled_note(), semitones),
+        ))
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        if semitones == 0 {
+            return *self;
+        }
+        let range = self.range().saturating_add(semitones);
+        Sound::from_range_spelled(
+            range.clamp(Sound::MIN_RANGE, Sound::MAX_RANGE),
+            transposed_spelling(self.spelled_note(), semitones),
+        )
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        if semitones == 0 {
+            return *self;
+        }
+        let span = (Sound::MAX_RANGE - Sound::MIN_RANGE + 1) as i128;
+        let shift = (self.range() - Sound::MIN_RANGE) as i128 + semitones as i128;
+        Sound::from_range_spelled(
+            Sound::MIN_RANGE + shift.rem_euclid(span) as isize,
+            transposed_spelling(self.spelled_note(), semitones),
+        )
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        let notes = scale.spelled_notes();
+        let offsets = scale.usize_notes();
+        let len = notes.len() as isize;
+        let tonic = notes[0].to_usize() as isize;
+        let degree = notes
+            .iter()
+            .position(|note| note.to_usize() == self.note().to_usize())
+            .ok_or(MusicError::SoundNotInScale(*self))?;
+        // Half tones between the tonic and the degree, going up.
+        let offset = |degree: usize| (offsets[degree] as isize - tonic).rem_euclid(12);
+        let cycle = (self.range() - tonic - offset(degree)).div_euclid(12);
+        let position = (cycle * len + degree as isize).saturating_add(steps);
+        let degree = position.rem_euclid(len) as usize;
+        let range = position
+            .div_euclid(len)
+            .saturating_mul(12)
+            .saturating_add(tonic + offset(degree));
+        Sound::try_from_range(range)?;
+        Ok(Sound::from_range_spelled(range, notes[degree]))
+    }
+}
+
+impl Transpose for Interval {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        let sound_one = self.first_sound().try_transpose(semitones)?;
+        let sound_two = self.second_sound().try_transpose(semitones)?;
+        Ok(Interval::init(sound_one, sound_two))
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        let sound_one = self.first_sound().saturating_transpose(semitones);
+        let sound_two = self.second_sound().saturating_transpose(semitones);
+        Interval::init(sound_one, sound_two)
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        let sound_one = self.first_sound().wrapping_transpose(semitones);
+        let sound_two = self.second_sound().wrapping_transpose(semitones);
+        Interval::init(sound_one, sound_two)
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        let sound_one = self.first_sound().try_transpose_diatonic(steps, scale)?;
+        let sound_two = self.second_sound().try_transpose_diatonic(steps, scale)?;
+        Ok(Interval::init(sound_one, sound_two))
+    }
+}
+
+impl Transpose for Chord {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        self.sounds().try_transpose(semitones).map(Chord::from_vec)
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        Chord::from_vec(self.sounds().saturating_transpose(semitones))
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        Chord::from_vec(self.sounds().wrapping_transpose(semitones))
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        self.sounds()
+            .try_transpose_diatonic(steps, scale)
+            .map(Chord::from_vec)
+    }
+}
+
+impl Transpose for Scale {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        let tonic = self.tonic().try_transpose(semitones)?;
+        Ok(Scale::init(tonic, self.scale_type()))
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        Scale::init(self.tonic().transpose(semitones), self.scale_type())
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        Scale::init(self.tonic().transpose(semitones), self.scale_type())
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        let tonic = self.tonic().try_transpose_diatonic(steps, scale)?;
+        Ok(Scale::init(tonic, self.scale_type()))
+    }
+}
+
+/// Melodies (and any sequence of transposable values) are transposed element-wise.
+impl<T: Transpose> Transpose for Vec<T> {
+    fn try_transpose(&self, semitones: isize) -> Result<Self, MusicError> {
+        self.iter()
+            .map(|value| value.try_transpose(semitones))
+            .collect()
+    }
+    fn saturating_transpose(&self, semitones: isize) -> Self {
+        self.iter()
+            .map(|value| value.saturating_transpose(semitones))
+            .collect()
+    }
+    fn wrapping_transpose(&self, semitones: isize) -> Self {
+        self.iter()
+            .map(|value| value.wrapping_transpose(semitones))
+            .collect()
+    }
+    fn try_transpose_diatonic(&self, steps: isize, scale: &Scale) -> Result<Self, MusicError> {
+        self.iter()
+            .map(|value| value.try_transpose_diatonic(steps, scale))
+            .collect()
+    }
+}
+
+/// Spells a note transposed by a number of half tones, moving its letter as in the
+/// harmonic chromatic scale, so that whole octaves keep the spelling (D#4 up an octave
+/// is D#5) and three half tones above D# is F#. Notes which would need more than
+/// two accidentals are spelled as in `Note::from_usize`.
+fn transposed_spelling(note: SpelledNote, semitones: isize) -> SpelledNote {
+    let steps = CHROMATIC_STEPS[semitones.rem_euclid(12) as usize];
+    let num = (note.to_usize() as isize + semitones).rem_euclid(12) as usize;
+    let spelled = spell_with_letter(num, Letter::from_index(note.letter().index() + steps));
+    if spelled.accidental().abs() > 2 {
+        SpelledNote::from(Note::from_usize(num))
+    } else {
+        spelled
+    }
+}
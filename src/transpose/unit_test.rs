@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use super::super::Transpose;
+    use crate::{Chord, Interval, MusicError, Note, Scale, ScaleType, Sound};
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(Note::B.transpose(2), Note::Cs);
+        assert_eq!(Note::C.transpose(-1), Note::B);
+        let sound = Sound::init(Note::C, 1);
+        assert_eq!(sound.try_transpose(-1), Ok(Sound::init(Note::B, 0)));
+        assert_eq!(
+            sound.try_transpose(isize::MAX),
+            Err(MusicError::AboveHighestSound)
+        );
+        let lowest = Sound::from_range(Sound::MIN_RANGE);
+        let highest = Sound::from_range(Sound::MAX_RANGE);
+        assert_eq!(lowest.try_transpose(-1), Err(MusicError::BelowLowestSound));
+        assert_eq!(lowest.saturating_transpose(-1), lowest);
+        assert_eq!(lowest.wrapping_transpose(-1), highest);
+        assert_eq!(
+            sound.wrapping_transpose(-2 * (Sound::MAX_RANGE - Sound::MIN_RANGE + 1)),
+            sound
+        );
+        let chord = Chord::from_vec(vec![sound, highest]);
+        assert_eq!(chord.checked_transpose(1), None);
+        assert_eq!(
+            chord.saturating_transpose(1),
+            Chord::from_vec(vec![Sound::init(Note::Cs, 1), highest])
+        );
+        let interval = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::E, 4));
+        let expected_interval = Interval::init(Sound::init(Note::D, 4), Sound::init(Note::Fs, 4));
+        assert_eq!(interval.transpose(2), expected_interval);
+        let chord = "C4 E4 G4".parse::<Chord>().unwrap();
+        assert_eq!(chord.transpose(-12), "C3 E3 G3".parse::<Chord>().unwrap());
+        let scale = Scale::init(Note::C, ScaleType::HarmonicMinor);
+        assert_eq!(
+            scale.transpose(7),
+            Scale::init(Note::G, ScaleType::HarmonicMinor)
+        );
+        let melody = vec![Sound::init(Note::C, 4), Sound::init(Note::G, 4)];
+        assert_eq!(
+            melody.transpose(5),
+            vec![Sound::init(Note::F, 4), Sound::init(Note::C, 5)]
+        );
+        // The spelling follows the transposition.
+        let d_sharp = "D#4".parse::<Sound>().unwrap();
+        let up = |sound: Sound, semitones| sound.transpose(semitones);
+        assert!(up(d_sharp, 12).strict_eq(&"D#5".parse().unwrap()));
+        assert!(up(d_sharp, -24).strict_eq(&"D#2".parse().unwrap()));
+        assert!(up(d_sharp, 3).strict_eq(&"F#4".parse().unwrap()));
+        assert!(up(d_sharp, 7).strict_eq(&"A#4".parse().unwrap()));
+        assert!(up("Eb4".parse().unwrap(), 4).strict_eq(&"G4".parse().unwrap()));
+        assert!(up("Bb3".parse().unwrap(), 1).strict_eq(&"Cb4".parse().unwrap()));
+        assert!(d_sharp
+            .saturating_transpose(12)
+            .strict_eq(&"D#5".parse().unwrap()));
+    }
+    #[test]
+    fn test_transpose_diatonic() {
+        let scale = Scale::init(Note::A, ScaleType::HarmonicMinor);
+        assert_eq!(Note::E.transpose_diatonic(2, &scale), Note::Gs);
+        assert_eq!(
+            Note::Fs.try_transpose_diatonic(1, &scale),
+            Err(MusicError::NoteNotInScale(Note::Fs))
+        );
+        let sound = Sound::init(Note::Gs, 3);
+        let expected_sound = Sound::init(Note::A, 3);
+        assert_eq!(sound.transpose_diatonic(1, &scale), expected_sound);
+        assert_eq!(
+            sound.transpose_diatonic(-6, &scale),
+            expected_sound.transpose(-12)
+        );
+        assert_eq!(
+            Sound::init(Note::C, 1).try_transpose_diatonic(-3, &scale),
+            Ok(Sound::init(Note::Gs, 0))
+        );
+        assert_eq!(
+            Sound::init(Note::C, 1).try_transpose_diatonic(isize::MIN, &scale),
+            Err(MusicError::BelowLowestSound)
+        );
+        let chord = "A3 C4 E4".parse::<Chord>().unwrap();
+        let expected_chord = "B3 D4 F4".parse::<Chord>().unwrap();
+        assert_eq!(chord.transpose_diatonic(1, &scale), expected_chord);
+        let scale = Scale::init(Note::Fs, ScaleType::Major);
+        let sound = Sound::init(Note::Ds, 4).transpose_diatonic(1, &scale);
+        assert!(sound.strict_eq(&Sound::init(Note::Es, 4)));
+        let sound = Sound::init(Note::Cs, 5).transpose_diatonic(-1, &scale);
+        assert!(sound.strict_eq(&Sound::init(Note::B, 4)));
+    }
+}
@@ -0,0 +1,53 @@
+mod equal_temperament;
+mod just_intonation;
+mod regular_temperament;
+mod scala;
+#[cfg(test)]
+mod unit_test;
+mod well_temperament;
+
+pub use equal_temperament::EqualTemperament;
+pub use just_intonation::JustIntonation;
+pub use regular_temperament::RegularTemperament;
+pub use scala::{KeyboardMapping, ScalaScale, ScalaTuning};
+pub use well_temperament::WellTemperament;
+
+use crate::{MusicError, Sound};
+
+/// Standard frequency (in Hz) of A4.
+pub const A4_FREQUENCY: f64 = 440.0;
+
+/// Range of A4, the usual reference sound of tunings.
+pub(crate) const A4_RANGE: isize = 45;
+
+/// Implementation of a tuning system, mapping sounds to frequencies.
+pub trait Tuning {
+    /// Gives the frequency (in Hz) of a sound,
+    /// failing when the tuning leaves the sound untuned.
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError>;
+    /// Gives the frequency (in Hz) of a sound (see [`Tuning::try_frequency`]).
+    /// # Panics
+    /// It panics when the tuning leaves the sound untuned.
+    fn frequency(&self, sound: &Sound) -> f64 {
+        self.try_frequency(sound)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+/// Converts a frequency ratio to cents.
+/// ```
+/// use music::tuning::ratio_to_cents;
+/// assert!((ratio_to_cents(2.0) - 1200.0).abs() < 1e-9);
+/// ```
+pub fn ratio_to_cents(ratio: f64) -> f64 {
+    1200.0 * ratio.log2()
+}
+
+/// Converts cents to a frequency ratio.
+/// ```
+/// use music::tuning::cents_to_ratio;
+/// assert!((cents_to_ratio(700.0) - 1.4983).abs() < 1e-4);
+/// ```
+pub fn cents_to_ratio(cents: f64) -> f64 {
+    (cents / 1200.0).exp2()
+}
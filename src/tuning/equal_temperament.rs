@@ -0,0 +1,41 @@
+use super::{Tuning, A4_FREQUENCY, A4_RANGE};
+use crate::{MusicError, Sound};
+
+/// Twelve-tone equal temperament, tuned from the frequency of A4.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqualTemperament {
+    reference: f64,
+}
+
+impl EqualTemperament {
+    /// Creates an equal temperament where A4 has the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{EqualTemperament, Tuning};
+    /// use music::{Note, Sound};
+    /// let tuning = EqualTemperament::init(415.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::A, 3)), 207.5);
+    /// ```
+    pub fn init(reference: f64) -> Self {
+        Self { reference }
+    }
+    /// Gives the frequency (in Hz) of A4.
+    /// ```
+    /// use music::tuning::EqualTemperament;
+    /// assert_eq!(EqualTemperament::default().reference(), 440.0);
+    /// ```
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+}
+
+impl Default for EqualTemperament {
+    fn default() -> Self {
+        Self::init(A4_FREQUENCY)
+    }
+}
+
+impl Tuning for EqualTemperament {
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        Ok(self.reference * ((sound.range() - A4_RANGE) as f64 / 12.0).exp2())
+    }
+}
@@ -0,0 +1,75 @@
+use super::{EqualTemperament, Tuning, A4_FREQUENCY};
+use crate::{MusicError, Note, Sound};
+
+/// Ratios of the 5-limit just intonation, from the tonic to the major seventh.
+const RATIOS: [f64; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    45.0 / 32.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+/// 5-limit just intonation relative to a tonic.
+///
+/// Every tonic keeps its equal temperament frequency (given the frequency of A4),
+/// the other sounds are tuned by pure ratios from the tonic below them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JustIntonation {
+    tonic: Note,
+    reference: f64,
+}
+
+impl JustIntonation {
+    /// Creates a just intonation on `tonic`, A4 having the frequency `reference` (in Hz)
+    /// in the equal temperament.
+    /// ```
+    /// use music::tuning::{JustIntonation, Tuning};
+    /// use music::{Note, Sound};
+    /// let tuning = JustIntonation::init(Note::A, 440.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::Cs, 5)), 550.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::E, 4)), 330.0);
+    /// ```
+    pub fn init(tonic: Note, reference: f64) -> Self {
+        Self { tonic, reference }
+    }
+    /// Gives the tonic of the tuning.
+    /// ```
+    /// use music::tuning::JustIntonation;
+    /// use music::Note;
+    /// assert_eq!(JustIntonation::init(Note::D, 440.0).tonic(), Note::D);
+    /// ```
+    pub fn tonic(&self) -> Note {
+        self.tonic
+    }
+    /// Gives the equal temperament frequency (in Hz) of A4.
+    /// ```
+    /// use music::tuning::JustIntonation;
+    /// use music::Note;
+    /// assert_eq!(JustIntonation::init(Note::D, 440.0).reference(), 440.0);
+    /// ```
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+}
+
+impl Default for JustIntonation {
+    fn default() -> Self {
+        Self::init(Note::C, A4_FREQUENCY)
+    }
+}
+
+impl Tuning for JustIntonation {
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        let offset = (sound.range() - self.tonic.to_usize() as isize).rem_euclid(12);
+        let tonic = Sound::from_range(sound.range() - offset);
+        Ok(EqualTemperament::init(self.reference).try_frequency(&tonic)? * RATIOS[offset as usize])
+    }
+}
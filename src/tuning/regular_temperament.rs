@@ -0,0 +1,90 @@
+use super::{cents_to_ratio, Tuning, A4_FREQUENCY};
+use crate::{MusicError, Note, Sound};
+
+/// Tuning where every note is reached from A4 by a chain of equal fifths,
+/// such as the Pythagorean tuning or the quarter-comma meantone.
+///
+/// Notes are placed on the line of fifths according to their spelling,
+/// so that G# and A♭ get different frequencies unless the fifth is 700 cents wide.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularTemperament {
+    fifth: f64,
+    reference: f64,
+}
+
+impl RegularTemperament {
+    /// Creates a regular temperament from the size of its fifth (in cents),
+    /// A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{EqualTemperament, RegularTemperament, Tuning};
+    /// use music::{Note, Sound};
+    /// let tuning = RegularTemperament::init(700.0, 440.0);
+    /// let sound = Sound::init(Note::Eb, 5);
+    /// let expected_frequency = EqualTemperament::default().frequency(&sound);
+    /// assert!((tuning.frequency(&sound) - expected_frequency).abs() < 1e-9);
+    /// ```
+    pub fn init(fifth: f64, reference: f64) -> Self {
+        Self { fifth, reference }
+    }
+    /// Creates the Pythagorean tuning, made of pure fifths (3/2),
+    /// A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{RegularTemperament, Tuning};
+    /// use music::{Note, Sound};
+    /// let tuning = RegularTemperament::pythagorean(440.0);
+    /// assert!((tuning.frequency(&Sound::init(Note::E, 5)) - 660.0).abs() < 1e-9);
+    /// ```
+    pub fn pythagorean(reference: f64) -> Self {
+        Self::init(1200.0 * 1.5f64.log2(), reference)
+    }
+    /// Creates the quarter-comma meantone, whose fifths are tempered
+    /// so that major thirds are pure (5/4), A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{RegularTemperament, Tuning};
+    /// use music::{Note, Sound};
+    /// let tuning = RegularTemperament::quarter_comma_meantone(440.0);
+    /// assert!((tuning.frequency(&Sound::init(Note::Cs, 5)) - 550.0).abs() < 1e-9);
+    /// ```
+    pub fn quarter_comma_meantone(reference: f64) -> Self {
+        Self::init(300.0 * 5f64.log2(), reference)
+    }
+    /// Gives the size of the fifth (in cents).
+    /// ```
+    /// use music::tuning::RegularTemperament;
+    /// assert_eq!(RegularTemperament::init(696.0, 440.0).fifth(), 696.0);
+    /// ```
+    pub fn fifth(&self) -> f64 {
+        self.fifth
+    }
+    /// Gives the frequency (in Hz) of A4.
+    /// ```
+    /// use music::tuning::RegularTemperament;
+    /// assert_eq!(RegularTemperament::pythagorean(442.0).reference(), 442.0);
+    /// ```
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+    /// Gives the position (in cents) of a sound relative to the lowest C,
+    /// reached by fifths and octaves.
+    fn cents(&self, sound: &Sound) -> f64 {
+        let note = sound.spelled_note();
+        let fifths = [0, 2, 4, -1, 1, 3, 5][note.letter().index()] + 7 * note.accidental() as isize;
+        let raw = fifths as f64 * self.fifth;
+        let equal = 100.0 * note.semitones() as f64;
+        let octaves = ((equal - raw) / 1200.0).round();
+        1200.0 * (sound.octave() - 1) as f64 + raw + 1200.0 * octaves
+    }
+}
+
+impl Default for RegularTemperament {
+    fn default() -> Self {
+        Self::pythagorean(A4_FREQUENCY)
+    }
+}
+
+impl Tuning for RegularTemperament {
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        let a_4 = Sound::init(Note::A, 4);
+        Ok(self.reference * cents_to_ratio(self.cents(sound) - self.cents(&a_4)))
+    }
+}
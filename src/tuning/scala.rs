@@ -0,0 +1,441 @@
+use std::str::FromStr;
+
+use super::{cents_to_ratio, ratio_to_cents, Tuning, A4_FREQUENCY};
+use crate::{MusicError, ScalaError, ScalaErrorKind, Sound};
+
+/// Meaningful lines of a Scala file, skipping the comments (starting with '!').
+struct Lines<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    last: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn init(input: &'a str) -> Self {
+        Self {
+            lines: input.lines().enumerate(),
+            last: 0,
+        }
+    }
+    /// Gives the next line which is not a comment, with its number (counted from 1).
+    fn next(&mut self) -> Result<(usize, &'a str), ScalaError> {
+        for (index, line) in self.lines.by_ref() {
+            self.last = index + 1;
+            if !line.starts_with('!') {
+                return Ok((index + 1, line));
+            }
+        }
+        Err(ScalaError::init(self.last + 1, ScalaErrorKind::MissingLine))
+    }
+    /// Gives the first field of the next line which is not a comment, with its number.
+    fn next_field(&mut self) -> Result<(usize, &'a str), ScalaError> {
+        let (number, line) = self.next()?;
+        Ok((number, line.split_whitespace().next().unwrap_or("")))
+    }
+    /// Parses the first field of the next line which is not a comment.
+    fn next_parsed<T: FromStr>(&mut self, kind: ScalaErrorKind) -> Result<T, ScalaError> {
+        let (number, field) = self.next_field()?;
+        field.parse().map_err(|_| ScalaError::init(number, kind))
+    }
+    /// Parses a MIDI note number (0 to 127) in the first field of the next line
+    /// which is not a comment, with its number.
+    fn next_key(&mut self) -> Result<(usize, u8), ScalaError> {
+        let (number, field) = self.next_field()?;
+        let key = field
+            .parse::<u8>()
+            .ok()
+            .filter(|key| *key <= 127)
+            .ok_or(ScalaError::init(number, ScalaErrorKind::InvalidInteger))?;
+        Ok((number, key))
+    }
+}
+
+/// Parses a pitch of a Scala scale to cents,
+/// a value with a '.' being cents and any other value being a ratio.
+fn parse_pitch(field: &str) -> Option<f64> {
+    if field.contains('.') {
+        return field.parse::<f64>().ok().filter(|cents| cents.is_finite());
+    }
+    let (numerator, denominator) = field.split_once('/').unwrap_or((field, "1"));
+    let numerator = numerator.parse::<u64>().ok()?;
+    let denominator = denominator.parse::<u64>().ok()?;
+    (numerator > 0 && denominator > 0)
+        .then(|| ratio_to_cents(numerator as f64 / denominator as f64))
+}
+
+/// Implementation of a Scala scale (.scl file).
+///
+/// The scale is made of pitches (in cents) above an implicit unison,
+/// the last one being the period at which the scale repeats (usually the octave).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaScale {
+    description: String,
+    cents: Vec<f64>,
+}
+
+impl ScalaScale {
+    /// Creates a scale from its description and its pitches (in cents), the period being the last one.
+    /// # Panics
+    /// It panics when there is no pitch.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = ScalaScale::init("Whole tone", vec![200.0, 400.0, 600.0, 800.0, 1000.0, 1200.0]);
+    /// assert_eq!(scale.len(), 6);
+    /// ```
+    pub fn init(description: &str, cents: Vec<f64>) -> Self {
+        Self::try_init(description, cents).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Creates a scale from its description and its pitches (in cents), the period being
+    /// the last one, failing when there is no pitch.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// use music::MusicError;
+    /// assert!(ScalaScale::try_init("Octave", vec![1200.0]).is_ok());
+    /// assert_eq!(ScalaScale::try_init("Empty", vec![]), Err(MusicError::EmptyScale));
+    /// ```
+    pub fn try_init(description: &str, cents: Vec<f64>) -> Result<Self, MusicError> {
+        if cents.is_empty() {
+            return Err(MusicError::EmptyScale);
+        }
+        Ok(Self {
+            description: description.to_string(),
+            cents,
+        })
+    }
+    /// Gives the description of the scale.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = "! pelog.scl\n!\nPelog\n 2\n!\n 120.0\n 2/1\n".parse::<ScalaScale>().unwrap();
+    /// assert_eq!(scale.description(), "Pelog");
+    /// ```
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    /// Gives the pitches (in cents) of the scale, the period being the last one.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = "Fifths\n2\n3/2\n2\n".parse::<ScalaScale>().unwrap();
+    /// assert_eq!(scale.cents()[1], 1200.0);
+    /// ```
+    pub fn cents(&self) -> &[f64] {
+        &self.cents
+    }
+    /// Gives the number of notes of the scale in a period.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = ScalaScale::init("Equal", (1..=19).map(|n| n as f64 * 1200.0 / 19.0).collect());
+    /// assert_eq!(scale.len(), 19);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.cents.len()
+    }
+    /// Gives the period (in cents) of the scale.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = "Bohlen-Pierce\n1\n3/1\n".parse::<ScalaScale>().unwrap();
+    /// assert!((scale.period() - 1901.955).abs() < 1e-3);
+    /// ```
+    pub fn period(&self) -> f64 {
+        self.cents[self.cents.len() - 1]
+    }
+    /// Gives the pitch (in cents) of a degree of the scale, 0 being the unison
+    /// and negative degrees going below it.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let scale = ScalaScale::init("Tritones", vec![600.0, 1200.0]);
+    /// assert_eq!(scale.degree_cents(3), 1800.0);
+    /// assert_eq!(scale.degree_cents(-1), -600.0);
+    /// ```
+    pub fn degree_cents(&self, degree: isize) -> f64 {
+        let len = self.len() as isize;
+        let step = match degree.rem_euclid(len) {
+            0 => 0.0,
+            step => self.cents[step as usize - 1],
+        };
+        degree.div_euclid(len) as f64 * self.period() + step
+    }
+}
+
+impl FromStr for ScalaScale {
+    type Err = ScalaError;
+    /// Parses the content of a .scl file: a description line,
+    /// the number of notes, then one pitch per line,
+    /// either cents (e.g. "701.955") or a ratio (e.g. "3/2" or "2").
+    /// Lines starting with '!' are comments.
+    /// ```
+    /// use music::tuning::ScalaScale;
+    /// let content = "! just.scl\n!\nJust fourth and fifth\n 3\n!\n 4/3\n 701.955 fifth\n 2/1\n";
+    /// let scale = content.parse::<ScalaScale>().unwrap();
+    /// assert_eq!(scale.len(), 3);
+    /// assert!((scale.cents()[0] - 498.045).abs() < 1e-3);
+    /// assert_eq!(scale.period(), 1200.0);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = Lines::init(s);
+        let (_, description) = lines.next()?;
+        let (number, count) = lines.next_field()?;
+        let count = count
+            .parse::<usize>()
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or(ScalaError::init(number, ScalaErrorKind::InvalidNoteCount))?;
+        let mut cents = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (number, field) = lines.next_field()?;
+            let pitch =
+                parse_pitch(field).ok_or(ScalaError::init(number, ScalaErrorKind::InvalidPitch))?;
+            cents.push(pitch);
+        }
+        Ok(Self::init(description.trim(), cents))
+    }
+}
+
+/// Implementation of a Scala keyboard mapping (.kbm file),
+/// telling which degree of a scale each MIDI key plays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardMapping {
+    first_key: u8,
+    last_key: u8,
+    middle_key: u8,
+    reference_key: u8,
+    reference_frequency: f64,
+    octave_degree: usize,
+    keys: Vec<Option<usize>>,
+}
+
+impl KeyboardMapping {
+    /// Creates a mapping where consecutive keys of the whole MIDI range play consecutive degrees,
+    /// `middle_key` playing the unison and `reference_key` having the frequency `reference_frequency` (in Hz).
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// let mapping = KeyboardMapping::linear(60, 69, 440.0);
+    /// assert_eq!(mapping, KeyboardMapping::default());
+    /// ```
+    pub fn linear(middle_key: u8, reference_key: u8, reference_frequency: f64) -> Self {
+        Self {
+            first_key: 0,
+            last_key: 127,
+            middle_key,
+            reference_key,
+            reference_frequency,
+            octave_degree: 0,
+            keys: Vec::new(),
+        }
+    }
+    /// Gives the first and the last retuned keys.
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// assert_eq!(KeyboardMapping::default().key_range(), (0, 127));
+    /// ```
+    pub fn key_range(&self) -> (u8, u8) {
+        (self.first_key, self.last_key)
+    }
+    /// Gives the key playing the unison of the scale.
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// assert_eq!(KeyboardMapping::linear(62, 69, 440.0).middle_key(), 62);
+    /// ```
+    pub fn middle_key(&self) -> u8 {
+        self.middle_key
+    }
+    /// Gives the key whose frequency is given.
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// assert_eq!(KeyboardMapping::linear(60, 57, 220.0).reference_key(), 57);
+    /// ```
+    pub fn reference_key(&self) -> u8 {
+        self.reference_key
+    }
+    /// Gives the frequency (in Hz) of the reference key.
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// assert_eq!(KeyboardMapping::linear(60, 57, 220.0).reference_frequency(), 220.0);
+    /// ```
+    pub fn reference_frequency(&self) -> f64 {
+        self.reference_frequency
+    }
+    /// Gives the scale degree reached when going up by a whole mapping,
+    /// irrelevant for linear mappings.
+    /// ```
+    /// let mapping = "7\n0\n127\n60\n69\n440.0\n12\n0\n2\n4\n5\n7\n9\n11\n"
+    ///     .parse::<music::tuning::KeyboardMapping>()
+    ///     .unwrap();
+    /// assert_eq!(mapping.octave_degree(), 12);
+    /// ```
+    pub fn octave_degree(&self) -> usize {
+        self.octave_degree
+    }
+    /// Gives the scale degrees played by the keys from the middle key on,
+    /// `None` for unmapped keys. It is empty for linear mappings.
+    /// ```
+    /// let mapping = "3\n0\n127\n60\n69\n440.0\n2\n0\nx\n1\n"
+    ///     .parse::<music::tuning::KeyboardMapping>()
+    ///     .unwrap();
+    /// assert_eq!(mapping.keys(), &[Some(0), None, Some(1)]);
+    /// ```
+    pub fn keys(&self) -> &[Option<usize>] {
+        &self.keys
+    }
+    /// Gives the scale degree played by a key, ignoring the range of retuned keys.
+    fn degree(&self, key: isize) -> Option<isize> {
+        let offset = key - self.middle_key as isize;
+        if self.keys.is_empty() {
+            return Some(offset);
+        }
+        let size = self.keys.len() as isize;
+        let degree = self.keys[offset.rem_euclid(size) as usize]?;
+        Some(offset.div_euclid(size) * self.octave_degree as isize + degree as isize)
+    }
+}
+
+impl Default for KeyboardMapping {
+    fn default() -> Self {
+        Self::linear(60, 69, A4_FREQUENCY)
+    }
+}
+
+impl FromStr for KeyboardMapping {
+    type Err = ScalaError;
+    /// Parses the content of a .kbm file: the size of the mapping,
+    /// the first and last retuned keys, the middle key, the reference key,
+    /// the reference frequency, the degree of the formal octave,
+    /// then one scale degree (or 'x' for unmapped keys) per key of the mapping.
+    /// Lines starting with '!' are comments.
+    /// ```
+    /// use music::tuning::KeyboardMapping;
+    /// let content = "! white.kbm\n7\n0\n127\n60\n69\n432.0\n12\n! mapping\n0\n2\n4\n5\n7\n9\n11\n";
+    /// let mapping = content.parse::<KeyboardMapping>().unwrap();
+    /// assert_eq!(mapping.reference_frequency(), 432.0);
+    /// assert_eq!(mapping.keys().len(), 7);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = Lines::init(s);
+        let size = lines.next_parsed::<usize>(ScalaErrorKind::InvalidInteger)?;
+        let (_, first_key) = lines.next_key()?;
+        let (number, last_key) = lines.next_key()?;
+        if first_key > last_key {
+            return Err(ScalaError::init(number, ScalaErrorKind::InvalidKeyRange));
+        }
+        let (_, middle_key) = lines.next_key()?;
+        let (reference_line, reference_key) = lines.next_key()?;
+        let (number, field) = lines.next_field()?;
+        let reference_frequency = field
+            .parse::<f64>()
+            .ok()
+            .filter(|frequency| frequency.is_finite() && *frequency > 0.0)
+            .ok_or(ScalaError::init(number, ScalaErrorKind::InvalidFrequency))?;
+        let octave_degree = lines.next_parsed::<usize>(ScalaErrorKind::InvalidInteger)?;
+        let mut keys = Vec::with_capacity(size);
+        for _ in 0..size {
+            let (number, field) = lines.next_field()?;
+            let degree = match field {
+                "x" | "X" => None,
+                field => Some(
+                    field
+                        .parse::<usize>()
+                        .map_err(|_| ScalaError::init(number, ScalaErrorKind::InvalidMapping))?,
+                ),
+            };
+            keys.push(degree);
+        }
+        let mapping = Self {
+            first_key,
+            last_key,
+            middle_key,
+            reference_key,
+            reference_frequency,
+            octave_degree,
+            keys,
+        };
+        if mapping.degree(mapping.reference_key as isize).is_none() {
+            return Err(ScalaError::init(
+                reference_line,
+                ScalaErrorKind::UnmappedReference,
+            ));
+        }
+        Ok(mapping)
+    }
+}
+
+/// Tuning given by a Scala scale and a keyboard mapping,
+/// each sound playing the key of its MIDI note number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaTuning {
+    scale: ScalaScale,
+    mapping: KeyboardMapping,
+}
+
+impl ScalaTuning {
+    /// Creates a tuning from a scale and a keyboard mapping.
+    /// ```
+    /// use music::tuning::{KeyboardMapping, ScalaScale, ScalaTuning, Tuning};
+    /// use music::{Note, Sound};
+    /// let scale = "Quarter tones\n24\n".to_string()
+    ///     + &(1..=24).map(|n| format!("{}.0\n", 50 * n)).collect::<String>();
+    /// let scale = scale.parse::<ScalaScale>().unwrap();
+    /// let tuning = ScalaTuning::init(scale, KeyboardMapping::linear(60, 60, 261.0));
+    /// let frequency = tuning.frequency(&Sound::init(Note::C, 5));
+    /// assert!((frequency - 261.0 * 2f64.sqrt()).abs() < 1e-9);
+    /// ```
+    pub fn init(scale: ScalaScale, mapping: KeyboardMapping) -> Self {
+        Self { scale, mapping }
+    }
+    /// Gives the scale of the tuning.
+    /// ```
+    /// use music::tuning::{ScalaScale, ScalaTuning};
+    /// let scale = ScalaScale::init("Octave", vec![1200.0]);
+    /// let tuning = ScalaTuning::from(scale.clone());
+    /// assert_eq!(tuning.scale(), &scale);
+    /// ```
+    pub fn scale(&self) -> &ScalaScale {
+        &self.scale
+    }
+    /// Gives the keyboard mapping of the tuning.
+    /// ```
+    /// use music::tuning::{KeyboardMapping, ScalaScale, ScalaTuning};
+    /// let tuning = ScalaTuning::from(ScalaScale::init("Octave", vec![1200.0]));
+    /// assert_eq!(tuning.mapping(), &KeyboardMapping::default());
+    /// ```
+    pub fn mapping(&self) -> &KeyboardMapping {
+        &self.mapping
+    }
+}
+
+impl From<ScalaScale> for ScalaTuning {
+    /// Uses the scale with the default keyboard mapping,
+    /// C4 playing the unison and A4 being at 440 Hz.
+    fn from(scale: ScalaScale) -> Self {
+        Self::init(scale, KeyboardMapping::default())
+    }
+}
+
+impl Tuning for ScalaTuning {
+    /// Gives the frequency (in Hz) of a sound,
+    /// failing when its key is not retuned or is mapped to no degree.
+    /// ```
+    /// use music::tuning::{KeyboardMapping, ScalaScale, ScalaTuning, Tuning};
+    /// use music::{MusicError, Note, Sound};
+    /// let scale = ScalaScale::init("Pentatonic", vec![200.0, 400.0, 700.0, 900.0, 1200.0]);
+    /// let mapping = "12\n0\n127\n60\n69\n440.0\n5\n0\nx\n1\nx\n2\nx\nx\n3\nx\n4\nx\nx\n"
+    ///     .parse::<KeyboardMapping>()
+    ///     .unwrap();
+    /// let tuning = ScalaTuning::init(scale, mapping);
+    /// assert_eq!(tuning.try_frequency(&Sound::init(Note::A, 4)), Ok(440.0));
+    /// let sound = Sound::init(Note::F, 4);
+    /// assert_eq!(tuning.try_frequency(&sound), Err(MusicError::UnmappedSound(sound)));
+    /// ```
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        let unmapped = MusicError::UnmappedSound(*sound);
+        let key = sound.to_midi().map_err(|_| unmapped.clone())?;
+        if key < self.mapping.first_key || key > self.mapping.last_key {
+            return Err(unmapped);
+        }
+        let degree = self.mapping.degree(key as isize).ok_or(unmapped.clone())?;
+        let reference = self
+            .mapping
+            .degree(self.mapping.reference_key as isize)
+            .ok_or(unmapped)?;
+        let cents = self.scale.degree_cents(degree) - self.scale.degree_cents(reference);
+        Ok(self.mapping.reference_frequency * cents_to_ratio(cents))
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        ratio_to_cents, EqualTemperament, JustIntonation, KeyboardMapping, RegularTemperament,
+        ScalaScale, ScalaTuning, Tuning, WellTemperament,
+    };
+    use crate::{Interval, MusicError, Note, ScalaError, ScalaErrorKind, Sound};
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-6,
+            "{value} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_tunings_agree_on_reference() {
+        let a_4 = Sound::init(Note::A, 4);
+        let tunings: Vec<Box<dyn Tuning>> = vec![
+            Box::new(EqualTemperament::init(442.0)),
+            Box::new(RegularTemperament::pythagorean(442.0)),
+            Box::new(RegularTemperament::quarter_comma_meantone(442.0)),
+            Box::new(JustIntonation::init(Note::A, 442.0)),
+            Box::new(WellTemperament::werckmeister_iii(442.0)),
+            Box::new(WellTemperament::vallotti(442.0)),
+        ];
+        for tuning in tunings.iter() {
+            assert_close(a_4.frequency(tuning.as_ref()), 442.0);
+            let octave = Interval::init(a_4, Sound::init(Note::A, 5));
+            assert_close(octave.ratio(tuning.as_ref()), 2.0);
+        }
+    }
+    #[test]
+    fn test_tuning_intervals() {
+        let c_4 = Sound::init(Note::C, 4);
+        let fifth = Interval::init(c_4, Sound::init(Note::G, 4));
+        let third = Interval::init(c_4, Sound::init(Note::E, 4));
+        let pythagorean = RegularTemperament::pythagorean(440.0);
+        assert_close(fifth.ratio(&pythagorean), 1.5);
+        assert_close(third.ratio(&pythagorean), 81.0 / 64.0);
+        let meantone = RegularTemperament::quarter_comma_meantone(440.0);
+        assert_close(third.ratio(&meantone), 1.25);
+        let just = JustIntonation::init(Note::C, 440.0);
+        assert_close(fifth.ratio(&just), 1.5);
+        assert_close(third.cents(&just), ratio_to_cents(1.25));
+        let equal = EqualTemperament::default();
+        assert_close(fifth.cents(&equal), 700.0);
+        let descending = Interval::init(Sound::init(Note::G, 4), c_4);
+        assert_close(descending.cents(&equal), -700.0);
+    }
+    #[test]
+    fn test_tuning_spelling() {
+        let g_sharp = Sound::init(Note::Gs, 4);
+        let a_flat = Sound::init(Note::Ab, 4);
+        let pythagorean = RegularTemperament::pythagorean(440.0);
+        let comma = Interval::init(a_flat, g_sharp).cents(&pythagorean);
+        assert_close(comma, ratio_to_cents(531441.0 / 524288.0));
+        let meantone = RegularTemperament::quarter_comma_meantone(440.0);
+        assert!(Interval::init(g_sharp, a_flat).cents(&meantone) > 40.0);
+        let equal = EqualTemperament::default();
+        assert_eq!(g_sharp.frequency(&equal), a_flat.frequency(&equal));
+        let werckmeister = WellTemperament::werckmeister_iii(440.0);
+        assert_eq!(
+            g_sharp.frequency(&werckmeister),
+            a_flat.frequency(&werckmeister)
+        );
+    }
+    #[test]
+    fn test_from_frequency() {
+        let tunings: Vec<Box<dyn Tuning>> = vec![
+            Box::new(EqualTemperament::init(415.0)),
+            Box::new(RegularTemperament::pythagorean(440.0)),
+            Box::new(RegularTemperament::quarter_comma_meantone(440.0)),
+            Box::new(JustIntonation::init(Note::D, 440.0)),
+            Box::new(WellTemperament::vallotti(440.0)),
+        ];
+        for tuning in tunings.iter() {
+            for range in -30..100 {
+                let sound = Sound::from_range(range);
+                let frequency = sound.frequency(tuning.as_ref());
+                let (found, cents) = Sound::from_frequency(frequency, tuning.as_ref()).unwrap();
+                assert!(found.strict_eq(&sound), "{found} is not {sound}");
+                assert_close(cents, 0.0);
+                assert_close(found.frequency(tuning.as_ref()), frequency);
+            }
+        }
+        let equal = EqualTemperament::default();
+        let (sound, cents) = Sound::from_frequency(430.0, &equal).unwrap();
+        assert_eq!(sound, Sound::init(Note::A, 4));
+        assert_close(cents, ratio_to_cents(430.0 / 440.0));
+        let (sound, cents) = Sound::from_frequency(16.0, &equal).unwrap();
+        assert_eq!(sound, Sound::init(Note::C, 0));
+        assert!(cents < 0.0);
+        for frequency in [0.0, -440.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                Sound::from_frequency(frequency, &equal),
+                Err(MusicError::InvalidFrequency)
+            );
+        }
+        assert_eq!(
+            Sound::from_frequency(1e-300, &equal),
+            Err(MusicError::BelowLowestSound)
+        );
+        assert_eq!(
+            Sound::from_frequency(1e300, &equal),
+            Err(MusicError::AboveHighestSound)
+        );
+    }
+    #[test]
+    fn test_scala() {
+        let content = "! meanquar.scl\n!\n1/4-comma meantone scale. Pietro Aaron's temperament (1523)\n 12\n!\n 76.04900\n 193.15686\n 310.26471\n 5/4\n 503.42157\n 579.47057\n 696.57843\n 25/16\n 889.73529\n 1006.84314\n 1082.89214\n 2/1\n";
+        let scale = content.parse::<ScalaScale>().unwrap();
+        assert_eq!(
+            scale.description(),
+            "1/4-comma meantone scale. Pietro Aaron's temperament (1523)"
+        );
+        assert_eq!(scale.len(), 12);
+        assert_close(scale.cents()[3], ratio_to_cents(1.25));
+        let mapping = "! a440.kbm\n0\n0\n127\n60\n69\n440.0\n12\n".parse::<KeyboardMapping>();
+        let tuning = ScalaTuning::init(scale.clone(), mapping.unwrap());
+        let meantone = RegularTemperament::quarter_comma_meantone(440.0);
+        let notes = [
+            Note::C,
+            Note::Cs,
+            Note::D,
+            Note::Eb,
+            Note::E,
+            Note::F,
+            Note::Fs,
+            Note::G,
+            Note::Gs,
+            Note::A,
+            Note::Bb,
+            Note::B,
+        ];
+        for octave in 1..9 {
+            for note in notes {
+                let sound = Sound::init(note, octave);
+                let interval = Interval::init(Sound::init(Note::C, 4), sound);
+                assert!((interval.cents(&tuning) - interval.cents(&meantone)).abs() < 1e-4);
+            }
+        }
+        assert_close(Sound::init(Note::A, 4).frequency(&tuning), 440.0);
+        let (sound, cents) = Sound::from_frequency(550.0, &tuning).unwrap();
+        assert_eq!(sound, Sound::init(Note::Cs, 5));
+        assert!(cents.abs() < 1e-3);
+        let tuning = ScalaTuning::from(scale);
+        assert_eq!(
+            Sound::init(Note::Gs, 9).try_frequency(&tuning),
+            Err(MusicError::UnmappedSound(Sound::init(Note::Gs, 9)))
+        );
+        assert_eq!(
+            tuning.try_frequency(&Sound::init(Note::B, -2)),
+            Err(MusicError::UnmappedSound(Sound::init(Note::B, -2)))
+        );
+    }
+    #[test]
+    fn test_scala_keyboard_mapping() {
+        let scale = ScalaScale::init(
+            "Major",
+            vec![200.0, 400.0, 500.0, 700.0, 900.0, 1100.0, 1200.0],
+        );
+        let mapping = "12\n48\n72\n60\n69\n440.0\n7\n0\nx\n1\nx\n2\n3\nx\n4\nx\n5\nx\n6\n";
+        let tuning = ScalaTuning::init(scale, mapping.parse().unwrap());
+        assert_close(Sound::init(Note::A, 4).frequency(&tuning), 440.0);
+        assert_close(Sound::init(Note::A, 3).frequency(&tuning), 220.0);
+        let fifth = Interval::init(Sound::init(Note::C, 4), Sound::init(Note::G, 4));
+        assert_close(fifth.cents(&tuning), 700.0);
+        let sound = Sound::init(Note::Cs, 4);
+        assert_eq!(
+            tuning.try_frequency(&sound),
+            Err(MusicError::UnmappedSound(sound))
+        );
+        let sound = Sound::init(Note::C, 6);
+        assert_eq!(
+            tuning.try_frequency(&sound),
+            Err(MusicError::UnmappedSound(sound))
+        );
+        assert_close(
+            Sound::init(Note::C, 5).frequency(&tuning),
+            tuning.try_frequency(&Sound::init(Note::C, 4)).unwrap() * 2.0,
+        );
+    }
+    #[test]
+    fn test_scala_errors() {
+        let error = |content: &str| content.parse::<ScalaScale>().unwrap_err();
+        assert_eq!(error(""), ScalaError::init(1, ScalaErrorKind::MissingLine));
+        assert_eq!(
+            error("! comment\nName\nmany\n"),
+            ScalaError::init(3, ScalaErrorKind::InvalidNoteCount)
+        );
+        assert_eq!(
+            error("Name\n0\n"),
+            ScalaError::init(2, ScalaErrorKind::InvalidNoteCount)
+        );
+        assert_eq!(
+            error("Name\n3\n100.0\n! comment\n"),
+            ScalaError::init(5, ScalaErrorKind::MissingLine)
+        );
+        for pitch in ["abc", "3/0", "0", "-3/2", "1.2.3", "3/2/1"] {
+            assert_eq!(
+                error(&format!("Name\n2\n{pitch}\n2/1\n")),
+                ScalaError::init(3, ScalaErrorKind::InvalidPitch)
+            );
+        }
+        assert!("Name\n1\n-5.0\n".parse::<ScalaScale>().is_ok());
+        let error = |content: &str| content.parse::<KeyboardMapping>().unwrap_err();
+        assert_eq!(
+            error("0\n0\n128\n60\n69\n440.0\n12\n"),
+            ScalaError::init(3, ScalaErrorKind::InvalidInteger)
+        );
+        assert_eq!(
+            error("0\n64\n63\n60\n69\n440.0\n12\n"),
+            ScalaError::init(3, ScalaErrorKind::InvalidKeyRange)
+        );
+        assert_eq!(
+            error("0\n0\n127\n60\n69\n-440.0\n12\n"),
+            ScalaError::init(6, ScalaErrorKind::InvalidFrequency)
+        );
+        assert_eq!(
+            error("2\n0\n127\n60\n69\n440.0\n12\n0\n-1\n"),
+            ScalaError::init(9, ScalaErrorKind::InvalidMapping)
+        );
+        assert_eq!(
+            error("2\n0\n127\n60\n69\n440.0\n12\n0\n"),
+            ScalaError::init(9, ScalaErrorKind::MissingLine)
+        );
+        assert_eq!(
+            error("2\n0\n127\n60\n69\n440.0\n1\n0\nx\n"),
+            ScalaError::init(5, ScalaErrorKind::UnmappedReference)
+        );
+        let error = MusicError::from(error("x\n"));
+        assert_eq!(error.to_string(), "invalid integer at line 1");
+    }
+}
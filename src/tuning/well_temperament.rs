@@ -0,0 +1,90 @@
+use super::{cents_to_ratio, Tuning, A4_FREQUENCY, A4_RANGE};
+use crate::{MusicError, Sound};
+
+/// Tuning given by the position (in cents) of each of the twelve notes from C,
+/// such as the well temperaments of the baroque era.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WellTemperament {
+    cents: [f64; 12],
+    reference: f64,
+}
+
+impl WellTemperament {
+    /// Creates a tuning from the positions (in cents) of the notes C, C#, ..., B
+    /// relative to C, A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{Tuning, WellTemperament};
+    /// use music::{Note, Sound};
+    /// let cents = [0., 100., 200., 300., 400., 500., 600., 700., 800., 900., 1000., 1100.];
+    /// let tuning = WellTemperament::init(cents, 440.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::A, 5)), 880.0);
+    /// ```
+    pub fn init(cents: [f64; 12], reference: f64) -> Self {
+        Self { cents, reference }
+    }
+    /// Creates Werckmeister's temperament III, A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{Tuning, WellTemperament};
+    /// use music::{Note, Sound};
+    /// let tuning = WellTemperament::werckmeister_iii(440.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::A, 4)), 440.0);
+    /// ```
+    pub fn werckmeister_iii(reference: f64) -> Self {
+        Self::init(
+            [
+                0.0, 90.225, 192.18, 294.135, 390.225, 498.045, 588.27, 696.09, 792.18, 888.27,
+                996.09, 1092.18,
+            ],
+            reference,
+        )
+    }
+    /// Creates Vallotti's temperament, A4 having the frequency `reference` (in Hz).
+    /// ```
+    /// use music::tuning::{Tuning, WellTemperament};
+    /// use music::{Note, Sound};
+    /// let tuning = WellTemperament::vallotti(440.0);
+    /// assert_eq!(tuning.frequency(&Sound::init(Note::A, 3)), 220.0);
+    /// ```
+    pub fn vallotti(reference: f64) -> Self {
+        Self::init(
+            [
+                0.0, 94.135, 196.09, 298.045, 392.18, 501.955, 592.18, 698.045, 796.09, 894.135,
+                1000.0, 1090.225,
+            ],
+            reference,
+        )
+    }
+    /// Gives the positions (in cents) of the notes C, C#, ..., B relative to C.
+    /// ```
+    /// use music::tuning::WellTemperament;
+    /// assert_eq!(WellTemperament::vallotti(440.0).cents()[10], 1000.0);
+    /// ```
+    pub fn cents(&self) -> [f64; 12] {
+        self.cents
+    }
+    /// Gives the frequency (in Hz) of A4.
+    /// ```
+    /// use music::tuning::WellTemperament;
+    /// assert_eq!(WellTemperament::vallotti(430.0).reference(), 430.0);
+    /// ```
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+    /// Gives the position (in cents) of a range relative to the lowest C.
+    fn position(&self, range: isize) -> f64 {
+        1200.0 * range.div_euclid(12) as f64 + self.cents[range.rem_euclid(12) as usize]
+    }
+}
+
+impl Default for WellTemperament {
+    fn default() -> Self {
+        Self::werckmeister_iii(A4_FREQUENCY)
+    }
+}
+
+impl Tuning for WellTemperament {
+    fn try_frequency(&self, sound: &Sound) -> Result<f64, MusicError> {
+        let cents = self.position(sound.range()) - self.position(A4_RANGE);
+        Ok(self.reference * cents_to_ratio(cents))
+    }
+}
@@ -0,0 +1,494 @@
+mod checker;
+#[cfg(test)]
+mod unit_test;
+
+pub use checker::{Violation, VoiceLeadingChecker, VoiceLeadingRule};
+
+use crate::{Chord, MusicError, Note, Sound, SpelledNote};
+
+/// Way of measuring how far the voices move from one chord to the next,
+/// the sounds of both chords being paired voice by voice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the distances (in half tones) covered by the voices.
+    Taxicab,
+    /// Square root of the sum of the squared distances (in half tones) covered by the voices,
+    /// which favors moving every voice a little over moving one voice a lot.
+    Euclidean,
+}
+
+impl Metric {
+    /// Measures the motion of the voices from one chord to the next,
+    /// the `i`-th sound of each chord belonging to the `i`-th voice.
+    /// ```
+    /// use music::{Chord, Metric};
+    /// let c_major = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let f_major = "C4 F4 A4".parse::<Chord>().unwrap();
+    /// assert_eq!(Metric::Taxicab.distance(&c_major, &f_major), 3.0);
+    /// assert_eq!(Metric::Euclidean.distance(&c_major, &f_major), 5.0_f64.sqrt());
+    /// ```
+    pub fn distance(&self, from: &Chord, to: &Chord) -> f64 {
+        let motions = from
+            .sounds()
+            .iter()
+            .zip(to.sounds())
+            .map(|(one, two)| (two.range() - one.range()).abs() as f64);
+        match self {
+            Metric::Taxicab => motions.sum(),
+            Metric::Euclidean => motions.map(|motion| motion * motion).sum::<f64>().sqrt(),
+        }
+    }
+}
+
+/// Optimizer choosing the voicings of chords so that the voices move as little as possible,
+/// the `i`-th sound of each chord belonging to the `i`-th voice.
+///
+/// The cost of moving from one chord to the next is the distance given by a [`Metric`],
+/// plus a penalty for every pair of voices that cross
+/// and for every pair of voices that move in parallel unisons, fifths or octaves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceLeading {
+    metric: Metric,
+    crossing_penalty: f64,
+    parallel_penalty: f64,
+    low: Sound,
+    high: Sound,
+    keep_bass: bool,
+}
+
+impl VoiceLeading {
+    /// Creates an optimizer with a metric and without any penalty,
+    /// the voices staying between E2 and G5 and the bass of every chord being kept.
+    /// ```
+    /// use music::{Metric, VoiceLeading};
+    /// let voice_leading = VoiceLeading::init(Metric::Euclidean);
+    /// assert_eq!(voice_leading.metric(), Metric::Euclidean);
+    /// assert_eq!(voice_leading.crossing_penalty(), 0.0);
+    /// ```
+    pub fn init(metric: Metric) -> Self {
+        Self {
+            metric,
+            crossing_penalty: 0.0,
+            parallel_penalty: 0.0,
+            low: Sound::init(Note::E, 2),
+            high: Sound::init(Note::G, 5),
+            keep_bass: true,
+        }
+    }
+    /// Gives the same optimizer with another penalty for every pair of voices that cross.
+    /// ```
+    /// use music::VoiceLeading;
+    /// let voice_leading = VoiceLeading::default().with_crossing_penalty(2.5);
+    /// assert_eq!(voice_leading.crossing_penalty(), 2.5);
+    /// ```
+    pub fn with_crossing_penalty(mut self, penalty: f64) -> Self {
+        self.crossing_penalty = penalty;
+        self
+    }
+    /// Gives the same optimizer with another penalty for every pair of voices
+    /// moving in parallel unisons, fifths or octaves.
+    /// ```
+    /// use music::VoiceLeading;
+    /// let voice_leading = VoiceLeading::default().with_parallel_penalty(100.0);
+    /// assert_eq!(voice_leading.parallel_penalty(), 100.0);
+    /// ```
+    pub fn with_parallel_penalty(mut self, penalty: f64) -> Self {
+        self.parallel_penalty = penalty;
+        self
+    }
+    /// Gives the same optimizer with the voices staying between `low` and `high` (included).
+    /// ```
+    /// use music::{Note, Sound, VoiceLeading};
+    /// let (low, high) = (Sound::init(Note::C, 3), Sound::init(Note::C, 5));
+    /// let voice_leading = VoiceLeading::default().with_range(low, high);
+    /// assert_eq!(voice_leading.range(), (low, high));
+    /// ```
+    pub fn with_range(mut self, low: Sound, high: Sound) -> Self {
+        self.low = low;
+        self.high = high;
+        self
+    }
+    /// Gives the same optimizer, keeping the lowest note of every chord in the bass or not.
+    /// ```
+    /// use music::VoiceLeading;
+    /// let voice_leading = VoiceLeading::default().with_keep_bass(false);
+    /// assert!(!voice_leading.keeps_bass());
+    /// ```
+    pub fn with_keep_bass(mut self, keep_bass: bool) -> Self {
+        self.keep_bass = keep_bass;
+        self
+    }
+    /// Gives the metric measuring the motion of the voices.
+    /// ```
+    /// use music::{Metric, VoiceLeading};
+    /// assert_eq!(VoiceLeading::default().metric(), Metric::Taxicab);
+    /// ```
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+    /// Gives the penalty for every pair of voices that cross.
+    /// ```
+    /// use music::VoiceLeading;
+    /// assert_eq!(VoiceLeading::default().crossing_penalty(), 6.0);
+    /// ```
+    pub fn crossing_penalty(&self) -> f64 {
+        self.crossing_penalty
+    }
+    /// Gives the penalty for every pair of voices moving in parallel unisons, fifths or octaves.
+    /// ```
+    /// use music::VoiceLeading;
+    /// assert_eq!(VoiceLeading::default().parallel_penalty(), 6.0);
+    /// ```
+    pub fn parallel_penalty(&self) -> f64 {
+        self.parallel_penalty
+    }
+    /// Gives the lowest and the highest sounds the voices may reach.
+    /// ```
+    /// use music::{Note, Sound, VoiceLeading};
+    /// let (low, high) = VoiceLeading::default().range();
+    /// assert_eq!(low, Sound::init(Note::E, 2));
+    /// assert_eq!(high, Sound::init(Note::G, 5));
+    /// ```
+    pub fn range(&self) -> (Sound, Sound) {
+        (self.low, self.high)
+    }
+    /// Tells whether or not the lowest note of every chord stays in the bass.
+    /// ```
+    /// use music::VoiceLeading;
+    /// assert!(VoiceLeading::default().keeps_bass());
+    /// ```
+    pub fn keeps_bass(&self) -> bool {
+        self.keep_bass
+    }
+    /// Computes the cost of moving from one chord to the next,
+    /// the `i`-th sound of each chord belonging to the `i`-th voice.
+    /// ```
+    /// use music::{Chord, Metric, VoiceLeading};
+    /// let voice_leading = VoiceLeading::init(Metric::Taxicab).with_parallel_penalty(10.0);
+    /// let c_major = "C3 G3 C4 E4".parse::<Chord>().unwrap();
+    /// let d_minor = "D3 A3 D4 F4".parse::<Chord>().unwrap();
+    /// // 7 half tones, then parallel fifths and octaves between the bass and two voices.
+    /// assert_eq!(voice_leading.cost(&c_major, &d_minor), 27.0);
+    /// ```
+    pub fn cost(&self, from: &Chord, to: &Chord) -> f64 {
+        self.metric.distance(from, to)
+            + self.crossing_penalty * crossings(from, to) as f64
+            + self.parallel_penalty * parallels(from, to) as f64
+    }
+    /// Gives the voicing of a chord that follows another chord at the lowest cost
+    /// (see [`VoiceLeading::cost`]), with as many voices as the first chord.
+    ///
+    /// Notes of the second chord are doubled (the root first) when it has fewer notes
+    /// than there are voices, and two adjacent voices may cross, at the cost of the
+    /// crossing penalty (see [`VoiceLeading::try_progression`]).
+    /// It fails when the second chord has more notes than there are voices
+    /// or when no voicing fits the range.
+    /// ```
+    /// use music::{Chord, MusicError, VoiceLeading};
+    /// let voice_leading = VoiceLeading::default();
+    /// let c_major = "C3 G3 C4 E4".parse::<Chord>().unwrap();
+    /// let g_seventh = Chord::from_symbol("G7/B", 3);
+    /// let next = voice_leading.try_next(&c_major, &g_seventh).unwrap();
+    /// assert!(next.strict_eq(&"B2 G3 D4 F4".parse().unwrap()));
+    /// let ninth = Chord::from_symbol("C9", 3);
+    /// assert_eq!(voice_leading.try_next(&c_major, &ninth), Err(MusicError::TooManyNotes));
+    /// ```
+    pub fn try_next(&self, from: &Chord, to: &Chord) -> Result<Chord, MusicError> {
+        self.voicings(to, from.len())?
+            .into_iter()
+            .map(|voicing| {
+                let chord = Chord::from_vec(voicing);
+                (self.cost(from, &chord), chord)
+            })
+            .min_by(|(one, _), (two, _)| one.total_cmp(two))
+            .map(|(_, chord)| chord)
+            .ok_or(MusicError::NoVoicing)
+    }
+    /// Gives the voicing of a chord that follows another chord at the lowest cost
+    /// (see [`VoiceLeading::try_next`]).
+    /// # Panics
+    /// It panics when the second chord has more notes than there are voices
+    /// or when no voicing fits the range.
+    /// ```
+    /// use music::{Chord, VoiceLeading};
+    /// let c_major = "C4 E4 G4".parse::<Chord>().unwrap();
+    /// let f_major = Chord::from_symbol("F/C", 3);
+    /// let next = VoiceLeading::default().next(&c_major, &f_major);
+    /// assert!(next.strict_eq(&"C4 F4 A4".parse().unwrap()));
+    /// ```
+    pub fn next(&self, from: &Chord, to: &Chord) -> Chord {
+        self.try_next(from, to)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Voices a progression at the lowest total cost (see [`VoiceLeading::cost`])
+    /// by dynamic programming. The first chord is kept as it is
+    /// and gives the number of voices; two adjacent voices of the other chords may cross,
+    /// at the cost of the crossing penalty.
+    ///
+    /// It fails when a chord has more notes than there are voices
+    /// or when no voicing of a chord fits the range.
+    /// ```
+    /// use music::{Chord, MusicError, VoiceLeading};
+    /// let voice_leading = VoiceLeading::default();
+    /// let chords: Vec<Chord> = ["C4 E4 G4", "A3 C4 E4", "F3 A3 C4", "G3 B3 D4"]
+    ///     .iter()
+    ///     .map(|chord| chord.parse().unwrap())
+    ///     .collect();
+    /// let voiced = voice_leading.try_progression(&chords).unwrap();
+    /// assert!(voiced[1].strict_eq(&"A3 E4 C5".parse().unwrap()));
+    /// assert!(voiced[2].strict_eq(&"F3 A4 C5".parse().unwrap()));
+    /// assert!(voiced[3].strict_eq(&"G3 D4 B4".parse().unwrap()));
+    /// assert_eq!(voice_leading.try_progression(&[]), Ok(vec![]));
+    /// ```
+    pub fn try_progression(&self, chords: &[Chord]) -> Result<Vec<Chord>, MusicError> {
+        let Some(first) = chords.first() else {
+            return Ok(Vec::new());
+        };
+        let voices = first.len();
+        // For every voicing of the current chord: the total cost of reaching it
+        // and the index of the voicing of the previous chord it comes from.
+        let mut layers: Vec<Vec<Chord>> = vec![vec![first.clone()]];
+        let mut costs: Vec<f64> = vec![0.0];
+        let mut origins: Vec<Vec<usize>> = Vec::with_capacity(chords.len());
+        for chord in chords[1..].iter() {
+            let voicings: Vec<Chord> = self
+                .voicings(chord, voices)?
+                .into_iter()
+                .map(Chord::from_vec)
+                .collect();
+            let previous = &layers[layers.len() - 1];
+            let mut next_costs = Vec::with_capacity(voicings.len());
+            let mut next_origins = Vec::with_capacity(voicings.len());
+            for voicing in voicings.iter() {
+                let (origin, cost) = previous
+                    .iter()
+                    .zip(costs.iter())
+                    .map(|(from, cost)| cost + self.cost(from, voicing))
+                    .enumerate()
+                    .min_by(|(_, one), (_, two)| one.total_cmp(two))
+                    .ok_or(MusicError::NoVoicing)?;
+                next_costs.push(cost);
+                next_origins.push(origin);
+            }
+            layers.push(voicings);
+            costs = next_costs;
+            origins.push(next_origins);
+        }
+        let (mut index, _) = costs
+            .iter()
+            .enumerate()
+            .min_by(|(_, one), (_, two)| one.total_cmp(two))
+            .ok_or(MusicError::NoVoicing)?;
+        let mut progression = Vec::with_capacity(chords.len());
+        for (layer, origins) in layers[1..].iter().zip(origins.iter()).rev() {
+            progression.push(layer[index].clone());
+            index = origins[index];
+        }
+        progression.push(first.clone());
+        progression.reverse();
+        Ok(progression)
+    }
+    /// Voices a progression at the lowest total cost (see [`VoiceLeading::try_progression`]).
+    /// # Panics
+    /// It panics when a chord has more notes than there are voices
+    /// or when no voicing of a chord fits the range.
+    /// ```
+    /// use music::{Chord, VoiceLeading};
+    /// let chords = vec![Chord::from_symbol("Dm7", 3), Chord::from_symbol("G7", 3)];
+    /// let voiced = VoiceLeading::default().progression(&chords);
+    /// assert!(voiced[1].strict_eq(&"G2 F3 B3 D4".parse().unwrap()));
+    /// ```
+    pub fn progression(&self, chords: &[Chord]) -> Vec<Chord> {
+        self.try_progression(chords)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Voices a progression of chord symbols at the lowest total cost
+    /// (see [`VoiceLeading::try_progression`]), the first chord being built
+    /// in close position with its root in an octave (see [`Chord::try_from_symbol`]).
+    /// It fails when a symbol is invalid, when a chord has more notes than the first one
+    /// or when no voicing of a chord fits the range.
+    /// ```
+    /// use music::{Chord, MusicError, VoiceLeading};
+    /// let voice_leading = VoiceLeading::default();
+    /// let voiced = voice_leading.try_realize(&["Cmaj7", "Am7", "Dm7", "G7"], 3).unwrap();
+    /// assert_eq!(voiced.len(), 4);
+    /// assert!(voiced.iter().all(|chord| chord.len() == 4));
+    /// assert!(matches!(voice_leading.try_realize(&["C", "Hm"], 3), Err(MusicError::Parse(_))));
+    /// ```
+    pub fn try_realize(&self, symbols: &[&str], octave: isize) -> Result<Vec<Chord>, MusicError> {
+        let chords = symbols
+            .iter()
+            .map(|symbol| Chord::try_from_symbol(symbol, octave))
+            .collect::<Result<Vec<Chord>, MusicError>>()?;
+        self.try_progression(&chords)
+    }
+    /// Voices a progression of chord symbols at the lowest total cost
+    /// (see [`VoiceLeading::try_realize`]).
+    /// # Panics
+    /// It panics when a symbol is invalid, when a chord has more notes than the first one
+    /// or when no voicing of a chord fits the range.
+    /// ```
+    /// use music::VoiceLeading;
+    /// let voiced = VoiceLeading::default().realize(&["C", "F/C", "G/B", "C"], 4);
+    /// assert_eq!(voiced[3], voiced[0]);
+    /// ```
+    pub fn realize(&self, symbols: &[&str], octave: isize) -> Vec<Chord> {
+        self.try_realize(symbols, octave)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Gives every voicing of a chord with a number of voices: each sound lies within
+    /// the range, adjacent voices above the bass are at most an octave apart, and the bass
+    /// is kept if needed. Each voicing comes from the lowest sound, then with two adjacent
+    /// voices crossed, so that the crossing penalty decides whether the voices cross.
+    fn voicings(&self, chord: &Chord, voices: usize) -> Result<Vec<Vec<Sound>>, MusicError> {
+        let bass = chord
+            .sounds()
+            .iter()
+            .min()
+            .ok_or(MusicError::EmptyChord)?
+            .spelled_note();
+        // Voicings built from the bass upwards, with the notes left to place,
+        // the spacing being checked voice by voice to prune the search.
+        let mut partials: Vec<(Vec<Sound>, Vec<SpelledNote>)> =
+            vec![(Vec::new(), doubled_notes(chord, voices)?)];
+        for voice in 0..voices {
+            let mut next = Vec::new();
+            for (sounds, left) in partials.iter() {
+                for (index, note) in left.iter().enumerate() {
+                    let is_first = left[..index]
+                        .iter()
+                        .all(|other| other.to_usize() != note.to_usize());
+                    if !is_first
+                        || voice == 0 && self.keep_bass && note.to_usize() != bass.to_usize()
+                    {
+                        continue;
+                    }
+                    for sound in self.placements(note) {
+                        let fits = match sounds.last() {
+                            None => true,
+                            Some(last) => {
+                                sound >= *last && (voice < 2 || sound.range() - last.range() <= 12)
+                            }
+                        };
+                        if fits {
+                            let mut sounds = sounds.clone();
+                            sounds.push(sound);
+                            let mut left = left.clone();
+                            left.remove(index);
+                            next.push((sounds, left));
+                        }
+                    }
+                }
+            }
+            partials = next;
+        }
+        let mut voicings: Vec<Vec<Sound>> =
+            partials.into_iter().map(|(sounds, _)| sounds).collect();
+        if voicings.is_empty() {
+            return Err(MusicError::NoVoicing);
+        }
+        voicings.sort();
+        let mut assignments = Vec::with_capacity(voicings.len() * voices);
+        for voicing in voicings {
+            assignments.push(voicing.clone());
+            for index in 1..voicing.len() {
+                if voicing[index - 1] != voicing[index] {
+                    let mut crossed = voicing.clone();
+                    crossed.swap(index - 1, index);
+                    assignments.push(crossed);
+                }
+            }
+        }
+        Ok(assignments)
+    }
+    /// Gives the sounds of a note that lie within the range.
+    fn placements(&self, note: &SpelledNote) -> Vec<Sound> {
+        (self.low.octave() - 1..=self.high.octave() + 1)
+            .filter_map(|octave| Sound::try_init_spelled(*note, octave).ok())
+            .filter(|sound| *sound >= self.low && *sound <= self.high)
+            .collect()
+    }
+}
+
+impl Default for VoiceLeading {
+    /// Measures the motion with the taxicab metric, penalizing crossings and parallels
+    /// as much as a motion of half an octave.
+    fn default() -> Self {
+        Self::init(Metric::Taxicab)
+            .with_crossing_penalty(6.0)
+            .with_parallel_penalty(6.0)
+    }
+}
+
+/// Counts the pairs of voices whose order changes from one chord to the next.
+pub(crate) fn crossings(from: &Chord, to: &Chord) -> usize {
+    let (from, to) = (from.sounds(), to.sounds());
+    let voices = from.len().min(to.len());
+    let mut count = 0;
+    for one in 0..voices {
+        for two in one + 1..voices {
+            let before = from[one].cmp(&from[two]);
+            let after = to[one].cmp(&to[two]);
+            if before.is_ne() && after.is_ne() && before != after {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Counts the pairs of voices that move in the same direction
+/// from a perfect unison, fifth or octave (or compound) to the same interval.
+pub(crate) fn parallels(from: &Chord, to: &Chord) -> usize {
+    let (from, to) = (from.sounds(), to.sounds());
+    let voices = from.len().min(to.len());
+    let class = |one: &Sound, two: &Sound| (two.range() - one.range()).abs() % 12;
+    let mut count = 0;
+    for one in 0..voices {
+        for two in one + 1..voices {
+            let motion_one = to[one].range() - from[one].range();
+            let motion_two = to[two].range() - from[two].range();
+            let before = class(&from[one], &from[two]);
+            let after = class(&to[one], &to[two]);
+            if motion_one != 0
+                && motion_one.signum() == motion_two.signum()
+                && (before == 0 || before == 7)
+                && before == after
+            {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Gives the notes of a chord for a number of voices, the root being doubled first,
+/// then the other notes from the lowest one.
+fn doubled_notes(chord: &Chord, voices: usize) -> Result<Vec<SpelledNote>, MusicError> {
+    let mut notes: Vec<SpelledNote> = Vec::new();
+    for sound in chord.clone().to_sorted().sounds() {
+        let note = sound.spelled_note();
+        if notes
+            .iter()
+            .all(|other| other.to_usize() != note.to_usize())
+        {
+            notes.push(note);
+        }
+    }
+    if notes.len() > voices {
+        return Err(MusicError::TooManyNotes);
+    }
+    let mut order = notes.clone();
+    if let Some(candidate) = chord.identify().first() {
+        let root = candidate.root().to_usize();
+        if let Some(index) = order.iter().position(|note| note.to_usize() == root) {
+            let root = order.remove(index);
+            order.insert(0, root);
+        }
+    }
+    let mut doubled = notes;
+    for index in 0..voices - doubled.len() {
+        doubled.push(order[index % order.len()]);
+    }
+    Ok(doubled)
+}
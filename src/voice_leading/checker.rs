@@ -0,0 +1,390 @@
+use std::fmt;
+
+use crate::{Chord, ChordQuality, DegreeName, MusicError, Note, Scale, Sound};
+
+/// Rule of common-practice part writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceLeadingRule {
+    /// Two voices move in the same direction from a fifth to a fifth (or compound).
+    ParallelFifths,
+    /// Two voices move in the same direction from an octave or unison to the same interval.
+    ParallelOctaves,
+    /// The outer voices move in the same direction into a fifth, the upper one by leap.
+    HiddenFifths,
+    /// The outer voices move in the same direction into an octave, the upper one by leap.
+    HiddenOctaves,
+    /// A voice sounds above the next higher voice.
+    VoiceCrossing,
+    /// A voice moves beyond the previous sound of a neighbouring voice.
+    VoiceOverlap,
+    /// Two adjacent upper voices are more than an octave apart.
+    Spacing,
+    /// The leading tone of the key, in a dominant or leading-tone chord moving to the tonic
+    /// or submediant chord, does not rise to the tonic in an outer voice
+    /// (only checked in a key, see [`VoiceLeadingChecker::with_scale`]).
+    UnresolvedLeadingTone,
+    /// The seventh of a chord does not move down by step.
+    UnresolvedSeventh,
+    /// A voice leaves its range.
+    OutOfRange,
+}
+
+impl fmt::Display for VoiceLeadingRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            VoiceLeadingRule::ParallelFifths => "parallel fifths",
+            VoiceLeadingRule::ParallelOctaves => "parallel octaves",
+            VoiceLeadingRule::HiddenFifths => "hidden fifths",
+            VoiceLeadingRule::HiddenOctaves => "hidden octaves",
+            VoiceLeadingRule::VoiceCrossing => "voice crossing",
+            VoiceLeadingRule::VoiceOverlap => "voice overlap",
+            VoiceLeadingRule::Spacing => "spacing over an octave",
+            VoiceLeadingRule::UnresolvedLeadingTone => "unresolved leading tone",
+            VoiceLeadingRule::UnresolvedSeventh => "unresolved seventh",
+            VoiceLeadingRule::OutOfRange => "out of range",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Breach of a part-writing rule, located by the index of a chord and the voices involved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    rule: VoiceLeadingRule,
+    chord: usize,
+    voice: usize,
+    other_voice: Option<usize>,
+}
+
+impl Violation {
+    /// Gives the rule that is broken.
+    /// ```
+    /// use music::{Chord, VoiceLeadingChecker, VoiceLeadingRule};
+    /// let chords: Vec<Chord> = ["C3 G3", "D3 A3"].iter().map(|c| c.parse().unwrap()).collect();
+    /// let violations = VoiceLeadingChecker::init(vec![]).check(&chords);
+    /// assert_eq!(violations[0].rule(), VoiceLeadingRule::ParallelFifths);
+    /// ```
+    pub fn rule(&self) -> VoiceLeadingRule {
+        self.rule
+    }
+    /// Gives the index of the chord where the rule is broken: for a motion,
+    /// the chord the voices move to.
+    /// ```
+    /// use music::{Chord, VoiceLeadingChecker};
+    /// let chords: Vec<Chord> = ["C3 E3", "C3 G3", "D3 A3"]
+    ///     .iter()
+    ///     .map(|c| c.parse().unwrap())
+    ///     .collect();
+    /// let violations = VoiceLeadingChecker::init(vec![]).check(&chords);
+    /// assert_eq!(violations[0].chord(), 2);
+    /// ```
+    pub fn chord(&self) -> usize {
+        self.chord
+    }
+    /// Gives the voices involved, from the bass (voice 0),
+    /// the second one being `None` for rules about a single voice.
+    /// ```
+    /// use music::{Chord, VoiceLeadingChecker};
+    /// let chords: Vec<Chord> = ["C3 E3 G3", "D3 F3 A3"]
+    ///     .iter()
+    ///     .map(|c| c.parse().unwrap())
+    ///     .collect();
+    /// let violations = VoiceLeadingChecker::init(vec![]).check(&chords);
+    /// assert_eq!(violations[0].voices(), (0, Some(2)));
+    /// ```
+    pub fn voices(&self) -> (usize, Option<usize>) {
+        (self.voice, self.other_voice)
+    }
+    fn single(rule: VoiceLeadingRule, chord: usize, voice: usize) -> Self {
+        Self {
+            rule,
+            chord,
+            voice,
+            other_voice: None,
+        }
+    }
+    fn pair(rule: VoiceLeadingRule, chord: usize, voice: usize, other_voice: usize) -> Self {
+        Self {
+            rule,
+            chord,
+            voice,
+            other_voice: Some(other_voice),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.other_voice {
+            Some(other) => write!(
+                f,
+                "{} between voices {} and {other} at chord {}",
+                self.rule, self.voice, self.chord
+            ),
+            None => write!(
+                f,
+                "{} in voice {} at chord {}",
+                self.rule, self.voice, self.chord
+            ),
+        }
+    }
+}
+
+/// Analyzer checking a sequence of chords against the rules of common-practice part writing.
+///
+/// The `i`-th sound of each chord belongs to the `i`-th voice, the bass being voice 0,
+/// as in the chords given by [`crate::KraehenbuehlKnuth::harmonize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceLeadingChecker {
+    ranges: Vec<(Sound, Sound)>,
+    scale: Option<Scale>,
+}
+
+impl VoiceLeadingChecker {
+    /// Creates a checker with the lowest and highest sounds of each voice, from the bass.
+    /// Voices without a range are not checked against any range.
+    /// ```
+    /// use music::{Note, Sound, VoiceLeadingChecker};
+    /// let range = (Sound::init(Note::C, 3), Sound::init(Note::C, 5));
+    /// assert_eq!(VoiceLeadingChecker::init(vec![range]).ranges(), &[range]);
+    /// ```
+    pub fn init(ranges: Vec<(Sound, Sound)>) -> Self {
+        Self {
+            ranges,
+            scale: None,
+        }
+    }
+    /// Creates a checker for four-part writing, with the usual ranges of the bass (E2–C4),
+    /// tenor (C3–G4), alto (G3–D5) and soprano (C4–G5).
+    /// ```
+    /// use music::{Note, Sound, VoiceLeadingChecker};
+    /// let checker = VoiceLeadingChecker::four_part();
+    /// assert_eq!(checker.ranges()[0], (Sound::init(Note::E, 2), Sound::init(Note::C, 4)));
+    /// ```
+    pub fn four_part() -> Self {
+        Self::init(vec![
+            (Sound::init(Note::E, 2), Sound::init(Note::C, 4)),
+            (Sound::init(Note::C, 3), Sound::init(Note::G, 4)),
+            (Sound::init(Note::G, 3), Sound::init(Note::D, 5)),
+            (Sound::init(Note::C, 4), Sound::init(Note::G, 5)),
+        ])
+    }
+    /// Gives the lowest and highest sounds of each voice, from the bass.
+    /// ```
+    /// use music::VoiceLeadingChecker;
+    /// assert_eq!(VoiceLeadingChecker::default().ranges().len(), 4);
+    /// ```
+    pub fn ranges(&self) -> &[(Sound, Sound)] {
+        &self.ranges
+    }
+    /// Gives the same checker in the key of a scale, whose leading tone must then resolve.
+    /// ```
+    /// use music::{Note, Scale, ScaleType, VoiceLeadingChecker};
+    /// let scale = Scale::init(Note::D, ScaleType::HarmonicMinor);
+    /// let checker = VoiceLeadingChecker::four_part().with_scale(scale);
+    /// assert_eq!(checker.scale(), Some(scale));
+    /// ```
+    pub fn with_scale(mut self, scale: Scale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+    /// Gives the scale of the key, if any.
+    /// ```
+    /// use music::VoiceLeadingChecker;
+    /// assert_eq!(VoiceLeadingChecker::four_part().scale(), None);
+    /// ```
+    pub fn scale(&self) -> Option<Scale> {
+        self.scale
+    }
+    /// Lists the breaches of the part-writing rules, by chord,
+    /// failing when the chords do not all have the same number of sounds.
+    /// ```
+    /// use music::{Chord, MusicError, VoiceLeadingChecker, VoiceLeadingRule};
+    /// let checker = VoiceLeadingChecker::four_part();
+    /// let chords: Vec<Chord> = ["C3 G3 E4 C5", "D3 A3 F4 D5"]
+    ///     .iter()
+    ///     .map(|chord| chord.parse().unwrap())
+    ///     .collect();
+    /// let violations = checker.try_check(&chords).unwrap();
+    /// let rules: Vec<VoiceLeadingRule> = violations.iter().map(|v| v.rule()).collect();
+    /// assert_eq!(
+    ///     rules,
+    ///     vec![VoiceLeadingRule::ParallelFifths, VoiceLeadingRule::ParallelOctaves]
+    /// );
+    /// assert_eq!(violations[1].to_string(), "parallel octaves between voices 0 and 3 at chord 1");
+    /// let chords = vec!["C3 E3".parse().unwrap(), "C3 E3 G3".parse().unwrap()];
+    /// assert_eq!(checker.try_check(&chords), Err(MusicError::VoiceCountMismatch));
+    /// ```
+    pub fn try_check(&self, chords: &[Chord]) -> Result<Vec<Violation>, MusicError> {
+        if chords.windows(2).any(|pair| pair[0].len() != pair[1].len()) {
+            return Err(MusicError::VoiceCountMismatch);
+        }
+        let mut violations = Vec::new();
+        for (index, chord) in chords.iter().enumerate() {
+            self.check_chord(index, chord, &mut violations);
+            if index > 0 {
+                check_motion(index, &chords[index - 1], chord, &mut violations);
+                let from = &chords[index - 1];
+                check_resolutions(index, from, chord, self.scale.as_ref(), &mut violations);
+            }
+        }
+        Ok(violations)
+    }
+    /// Lists the breaches of the part-writing rules, by chord (see [`VoiceLeadingChecker::try_check`]).
+    /// # Panics
+    /// It panics when the chords do not all have the same number of sounds.
+    /// ```
+    /// use music::{Chord, VoiceLeadingChecker, VoiceLeadingRule};
+    /// let chords: Vec<Chord> = ["G2 B3 D4 F4", "C3 C4 C4 E4"]
+    ///     .iter()
+    ///     .map(|chord| chord.parse().unwrap())
+    ///     .collect();
+    /// let violations = VoiceLeadingChecker::default().check(&chords);
+    /// assert!(violations.is_empty());
+    /// ```
+    pub fn check(&self, chords: &[Chord]) -> Vec<Violation> {
+        self.try_check(chords)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Checks the ranges, crossings and spacing of the voices of a chord.
+    fn check_chord(&self, index: usize, chord: &Chord, violations: &mut Vec<Violation>) {
+        let sounds = chord.sounds();
+        for (voice, (sound, (low, high))) in sounds.iter().zip(self.ranges.iter()).enumerate() {
+            if sound < low || sound > high {
+                violations.push(Violation::single(
+                    VoiceLeadingRule::OutOfRange,
+                    index,
+                    voice,
+                ));
+            }
+        }
+        for voice in 0..sounds.len().saturating_sub(1) {
+            if sounds[voice] > sounds[voice + 1] {
+                let rule = VoiceLeadingRule::VoiceCrossing;
+                violations.push(Violation::pair(rule, index, voice, voice + 1));
+            }
+        }
+        for voice in 1..sounds.len().saturating_sub(1) {
+            if sounds[voice + 1].range() - sounds[voice].range() > 12 {
+                let rule = VoiceLeadingRule::Spacing;
+                violations.push(Violation::pair(rule, index, voice, voice + 1));
+            }
+        }
+    }
+}
+
+impl Default for VoiceLeadingChecker {
+    /// Gives the checker for four-part writing (see [`VoiceLeadingChecker::four_part`]).
+    fn default() -> Self {
+        Self::four_part()
+    }
+}
+
+/// Checks the parallel and hidden perfect intervals and the overlaps
+/// when moving from one chord to the next.
+fn check_motion(index: usize, from: &Chord, to: &Chord, violations: &mut Vec<Violation>) {
+    let (from, to) = (from.sounds(), to.sounds());
+    let voices = from.len();
+    let class = |one: &Sound, two: &Sound| (two.range() - one.range()).abs() % 12;
+    for one in 0..voices {
+        for two in one + 1..voices {
+            let motion_one = to[one].range() - from[one].range();
+            let motion_two = to[two].range() - from[two].range();
+            let before = class(&from[one], &from[two]);
+            let after = class(&to[one], &to[two]);
+            let is_similar = motion_one != 0 && motion_one.signum() == motion_two.signum();
+            if !is_similar || (after != 0 && after != 7) {
+                continue;
+            }
+            let is_outer = one == 0 && two == voices - 1;
+            let rule = match (before == after, after == 7) {
+                (true, true) => VoiceLeadingRule::ParallelFifths,
+                (true, false) => VoiceLeadingRule::ParallelOctaves,
+                (false, _) if !is_outer || motion_two.abs() <= 2 => continue,
+                (false, true) => VoiceLeadingRule::HiddenFifths,
+                (false, false) => VoiceLeadingRule::HiddenOctaves,
+            };
+            violations.push(Violation::pair(rule, index, one, two));
+        }
+    }
+    for voice in 0..voices.saturating_sub(1) {
+        if to[voice + 1] < from[voice] || to[voice] > from[voice + 1] {
+            let rule = VoiceLeadingRule::VoiceOverlap;
+            violations.push(Violation::pair(rule, index, voice, voice + 1));
+        }
+    }
+}
+
+/// Checks that the leading tone of the key (if any) and the sevenths of a chord resolve
+/// in the next chord, the chords being identified with [`Chord::identify`].
+fn check_resolutions(
+    index: usize,
+    from: &Chord,
+    to: &Chord,
+    scale: Option<&Scale>,
+    violations: &mut Vec<Violation>,
+) {
+    let Some(candidate) = from.identify().into_iter().next() else {
+        return;
+    };
+    let (root, quality) = (candidate.root().to_usize() as isize, candidate.quality());
+    let tones = quality.tones();
+    let pitch_class = |sound: &Sound| sound.spelled_note().to_usize() as isize;
+    let voices = from.len();
+    // Chords leaving out their fifth are taken to be in root position.
+    let next_root = match to.identify().first() {
+        Some(candidate) => Some(candidate.root().to_usize() as isize),
+        None => to.sounds().iter().min().map(pitch_class),
+    };
+    // The seventh degree of the key, when it is a leading tone, in a chord of dominant
+    // function (V or vii°) moving to the tonic or submediant chord.
+    let leading_tone = scale.and_then(|scale| {
+        let degree = |pitch_class: isize| scale.degree_of(Note::from_usize(pitch_class as usize));
+        if scale.degree_name(7) != Some(DegreeName::LeadingTone) {
+            return None;
+        }
+        let is_dominant = match degree(root) {
+            Some(5) => tones.contains(&(3, 4)) && !tones.contains(&(7, 11)),
+            Some(7) => matches!(
+                quality,
+                ChordQuality::Diminished
+                    | ChordQuality::DiminishedSeventh
+                    | ChordQuality::HalfDiminishedSeventh
+            ),
+            _ => false,
+        };
+        let resolves = matches!(next_root.and_then(degree), Some(1 | 6));
+        (is_dominant && resolves).then(|| scale.note_at(7).to_usize() as isize)
+    });
+    let (from, to) = (from.sounds(), to.sounds());
+    if let Some(leading_tone) = leading_tone {
+        let mut outer_voices = vec![0, voices - 1];
+        outer_voices.dedup();
+        for voice in outer_voices {
+            let motion = to[voice].range() - from[voice].range();
+            if pitch_class(&from[voice]) == leading_tone && motion != 1 {
+                let rule = VoiceLeadingRule::UnresolvedLeadingTone;
+                violations.push(Violation::single(rule, index, voice));
+            }
+        }
+    }
+    let mut classes_from: Vec<isize> = from.iter().map(pitch_class).collect();
+    let mut classes_to: Vec<isize> = to.iter().map(pitch_class).collect();
+    classes_from.sort();
+    classes_from.dedup();
+    classes_to.sort();
+    classes_to.dedup();
+    let seventh = tones
+        .iter()
+        .find(|(number, _)| *number == 7)
+        .map(|(_, semitones)| (root + semitones).rem_euclid(12));
+    if let Some(seventh) = seventh.filter(|_| classes_from != classes_to) {
+        for voice in 0..voices {
+            let motion = to[voice].range() - from[voice].range();
+            if pitch_class(&from[voice]) == seventh && !(-2..=-1).contains(&motion) {
+                let rule = VoiceLeadingRule::UnresolvedSeventh;
+                violations.push(Violation::single(rule, index, voice));
+            }
+        }
+    }
+}
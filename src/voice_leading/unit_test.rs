@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{crossings, parallels, Metric, VoiceLeading};
+    use crate::{
+        Chord, MusicError, Note, Scale, ScaleType, Sound, VoiceLeadingChecker, VoiceLeadingRule,
+    };
+
+    fn chord(sounds: &str) -> Chord {
+        sounds.parse().unwrap()
+    }
+
+    #[test]
+    fn test_crossings_and_parallels() {
+        let from = chord("C3 G3 C4 E4");
+        assert_eq!(crossings(&from, &chord("C3 E4 C4 G3")), 3);
+        assert_eq!(crossings(&from, &chord("B2 G3 D4 D4")), 0);
+        assert_eq!(parallels(&from, &chord("D3 A3 D4 F4")), 2);
+        // Contrary and oblique motions are not parallel.
+        assert_eq!(parallels(&from, &chord("C3 G3 C4 F4")), 0);
+        assert_eq!(parallels(&chord("C3 G3"), &chord("G2 D4")), 0);
+        assert_eq!(parallels(&chord("C3 G4"), &chord("D3 A4")), 1);
+    }
+
+    #[test]
+    fn test_next_is_optimal() {
+        let voice_leading = VoiceLeading::init(Metric::Taxicab);
+        let from = chord("C4 E4 G4");
+        let next = voice_leading.next(&from, &Chord::from_symbol("Am", 3));
+        assert_eq!(Metric::Taxicab.distance(&from, &next), 8.0);
+        let next = voice_leading
+            .clone()
+            .with_keep_bass(false)
+            .next(&from, &Chord::from_symbol("Am", 3));
+        assert!(next.strict_eq(&chord("C4 E4 A4")));
+        let next = voice_leading.next(&from, &Chord::from_symbol("C", 3));
+        assert!(next.strict_eq(&from));
+    }
+
+    #[test]
+    fn test_penalties() {
+        let from = chord("C3 G3 C4 E4");
+        let to = Chord::from_symbol("Dm", 3);
+        let free = VoiceLeading::init(Metric::Taxicab).next(&from, &to);
+        assert!(parallels(&from, &free) > 0);
+        let strict = VoiceLeading::init(Metric::Taxicab)
+            .with_parallel_penalty(100.0)
+            .next(&from, &to);
+        assert_eq!(parallels(&from, &strict), 0);
+        let crossing = VoiceLeading::init(Metric::Taxicab).with_crossing_penalty(10.0);
+        assert_eq!(crossing.cost(&chord("E4 C4"), &chord("C4 E4")), 18.0);
+        // Crossed voices stay crossed rather than changing their order.
+        let next = crossing.next(&chord("E4 C4"), &chord("D4 F4"));
+        assert!(next.strict_eq(&chord("F4 D4")));
+        // Crossing the voices is the only way to avoid parallel fifths
+        // with the bass kept: the penalties decide.
+        let (from, to) = (chord("C3 G3"), chord("D3 A3"));
+        let strict = VoiceLeading::init(Metric::Taxicab).with_parallel_penalty(100.0);
+        let next = strict.clone().with_crossing_penalty(10.0).next(&from, &to);
+        assert!(next.strict_eq(&chord("A3 D3")));
+        assert_eq!(crossings(&from, &next), 1);
+        let next = strict.with_crossing_penalty(200.0).next(&from, &to);
+        assert!(next.strict_eq(&chord("D3 A3")));
+    }
+
+    #[test]
+    fn test_progression() {
+        let voice_leading = VoiceLeading::default();
+        let symbols = ["C", "Am", "F", "G7", "C"];
+        let mut chords = vec![chord("C3 G3 C4 E4")];
+        chords.extend(
+            symbols[1..]
+                .iter()
+                .map(|symbol| Chord::from_symbol(symbol, 3)),
+        );
+        let voiced = voice_leading.progression(&chords);
+        assert_eq!(voiced.len(), symbols.len());
+        let total: f64 = voiced
+            .windows(2)
+            .map(|pair| voice_leading.cost(&pair[0], &pair[1]))
+            .sum();
+        // Greedy choices cannot beat the dynamic programming.
+        let mut greedy = vec![voiced[0].clone()];
+        for symbol in symbols[1..].iter() {
+            let next =
+                voice_leading.next(&greedy[greedy.len() - 1], &Chord::from_symbol(symbol, 3));
+            greedy.push(next.to_sorted());
+        }
+        let greedy_total: f64 = greedy
+            .windows(2)
+            .map(|pair| voice_leading.cost(&pair[0], &pair[1]))
+            .sum();
+        assert!(total <= greedy_total);
+        for (chord, symbol) in voiced.iter().zip(symbols) {
+            assert_eq!(chord.identify()[0].symbol(), symbol);
+        }
+    }
+
+    #[test]
+    fn test_many_voices() {
+        // The spacing prunes the search, which stays fast with eight voices.
+        let from = chord("C2 G2 C3 E3 G3 C4 E4 G4");
+        let wide =
+            VoiceLeading::default().with_range(Sound::init(Note::C, 2), Sound::init(Note::C, 6));
+        let next = wide.next(&from, &Chord::from_symbol("G7", 3));
+        assert_eq!(next.len(), 8);
+        assert_eq!(next.sounds()[0].note(), Note::G);
+        assert_eq!(next.identify()[0].symbol(), "G7");
+    }
+    #[test]
+    fn test_progression_errors() {
+        let voice_leading = VoiceLeading::default();
+        assert_eq!(
+            voice_leading.try_realize(&["C", "G9"], 4),
+            Err(MusicError::TooManyNotes)
+        );
+        let narrow = voice_leading.with_range(Sound::init(Note::C, 4), Sound::init(Note::D, 4));
+        assert_eq!(
+            narrow.try_progression(&[chord("C4 D4"), chord("E4 G4")]),
+            Err(MusicError::NoVoicing)
+        );
+    }
+
+    fn violations(chords: &[&str]) -> Vec<(VoiceLeadingRule, usize, usize, Option<usize>)> {
+        violations_in(Scale::init(Note::C, ScaleType::Major), chords)
+    }
+
+    fn violations_in(
+        scale: Scale,
+        chords: &[&str],
+    ) -> Vec<(VoiceLeadingRule, usize, usize, Option<usize>)> {
+        let chords: Vec<Chord> = chords.iter().map(|sounds| chord(sounds)).collect();
+        VoiceLeadingChecker::four_part()
+            .with_scale(scale)
+            .check(&chords)
+            .iter()
+            .map(|violation| {
+                let (voice, other_voice) = violation.voices();
+                (violation.rule(), violation.chord(), voice, other_voice)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_checker_motion() {
+        use VoiceLeadingRule::*;
+        assert_eq!(
+            violations(&["C3 G3 E4 C5", "D3 A3 F4 D5"]),
+            vec![
+                (ParallelFifths, 1, 0, Some(1)),
+                (ParallelOctaves, 1, 0, Some(3))
+            ]
+        );
+        // Octaves by contrary motion are not parallel.
+        assert!(violations(&["C3 G3 E4 C5", "G2 D4 G4 G5"]).is_empty());
+        assert_eq!(
+            violations(&["E3 G3 C4 C5", "F3 C4 A4 F5"]),
+            vec![(HiddenOctaves, 1, 0, Some(3))]
+        );
+        // The soprano moving by step makes the octave acceptable.
+        assert!(violations(&["A2 A3 C4 E4", "F3 A3 C4 F4"]).is_empty());
+        assert!(violations(&["E3 G3 C4 G4", "F3 A3 C4 F4"]).is_empty());
+        assert_eq!(
+            violations(&["C3 E3 G3 C4", "C3 F3 A3 C4", "C3 G3 B3 E3"]),
+            vec![
+                (OutOfRange, 2, 3, None),
+                (VoiceCrossing, 2, 2, Some(3)),
+                (VoiceOverlap, 2, 2, Some(3))
+            ]
+        );
+        assert_eq!(
+            violations(&["C3 E3 G3 C4", "C3 C4 G3 C4"]),
+            vec![
+                (VoiceCrossing, 1, 1, Some(2)),
+                (VoiceOverlap, 1, 1, Some(2))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_checker_chords() {
+        use VoiceLeadingRule::*;
+        assert_eq!(violations(&["C2 G3 E4 C5"]), vec![(OutOfRange, 0, 0, None)]);
+        assert_eq!(violations(&["C3 E3 G4 C5"]), vec![(Spacing, 0, 1, Some(2))]);
+        // A gap between the bass and the tenor is allowed.
+        assert!(violations(&["C2 E4 G4 C5"])
+            .iter()
+            .all(|violation| violation.0 != Spacing));
+    }
+
+    #[test]
+    fn test_checker_resolutions() {
+        use VoiceLeadingRule::*;
+        assert!(violations(&["G2 D4 F4 B4", "C3 C4 E4 C5"]).is_empty());
+        assert_eq!(
+            violations(&["G2 D4 F4 B4", "C3 E4 G4 G4"]),
+            vec![
+                (UnresolvedLeadingTone, 1, 3, None),
+                (UnresolvedSeventh, 1, 2, None)
+            ]
+        );
+        assert_eq!(
+            violations(&["B2 D4 G4 D5", "G2 E4 G4 C5"]),
+            vec![(UnresolvedLeadingTone, 1, 0, None)]
+        );
+        // Repeating the same chord does not resolve the seventh.
+        assert!(violations(&["G2 B3 D4 F4", "G2 D4 F4 B4"]).is_empty());
+        // The deceptive cadence resolves the leading tone too.
+        assert_eq!(
+            violations(&["G2 D4 G4 B4", "A2 C4 E4 A4"]),
+            vec![(UnresolvedLeadingTone, 1, 3, None)]
+        );
+        // The third of the tonic chord is not a leading tone in C major, but it is in F major.
+        let tonic_to_subdominant = ["C3 G3 C4 E4", "F3 A3 C4 A4"];
+        assert!(violations(&tonic_to_subdominant).is_empty());
+        let f_major = Scale::init(Note::F, ScaleType::Major);
+        assert_eq!(
+            violations_in(f_major, &tonic_to_subdominant),
+            vec![(UnresolvedLeadingTone, 1, 3, None)]
+        );
+        // Without a key, leading tones are not checked.
+        let chords = [chord("G2 D4 F4 B4"), chord("C3 E4 G4 G4")];
+        let violations = VoiceLeadingChecker::four_part().check(&chords);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule(), UnresolvedSeventh);
+        let error = VoiceLeadingChecker::default().try_check(&[chord("C3 E3"), chord("C3")]);
+        assert_eq!(error, Err(MusicError::VoiceCountMismatch));
+    }
+}